@@ -15,24 +15,202 @@ extern crate alloc;
 
 use alloc::string::String;
 use stylus_sdk::{
-    alloy_primitives::{Address, U256, Uint},
-    alloy_sol_types::sol,
-    evm, msg,
+    alloy_primitives::{fixed_bytes, Address, Bytes, FixedBytes, U256, Uint},
+    alloy_sol_types::{sol, SolCall, SolValue},
+    call::Call,
+    crypto::keccak,
     prelude::*,
+    stylus_core::log,
 };
 
 // ============================================================================
 // CONSTANTS
 // ============================================================================
 
-/// Role identifier for minter role
-pub const MINTER_ROLE: u32 = 0x9f2df0fed2c77648de5860a4cc508cd0818c85b8b8a1ab4ceeef8d981c8956a6;
-/// Role identifier for pauser role
-pub const PAUSER_ROLE: u32 = 0x65d7a28e3265b37a6474929f336521b332cbb1a44ac7f6c0e19d4e9cfe7b8a4d;
-/// Role identifier for admin role (can manage other roles)
-pub const ADMIN_ROLE: u32 = 0xa49807205ce4d355092ef5a8a14f63e0a5e76c1d2932e00e8c0a0f9d7c7e3d5c;
-/// Default admin role constant (hash of null address)
-pub const DEFAULT_ADMIN_ROLE: u32 = 0x0000000000000000000000000000000000000000000000000000000000000000;
+/// Role identifier for minter role: keccak256("MINTER_ROLE")
+pub const MINTER_ROLE: FixedBytes<32> =
+    fixed_bytes!("9f2df0fed2c77648de5860a4cc508cd0818c85b8b8a1ab4ceeef8d981c8956a6");
+/// Role identifier for pauser role: keccak256("PAUSER_ROLE")
+pub const PAUSER_ROLE: FixedBytes<32> =
+    fixed_bytes!("65d7a28e3265b37a6474929f336521b332c1681b933f6cb9f3376673440d862a");
+/// Role identifier for admin role (can manage other roles): keccak256("ADMIN_ROLE")
+pub const ADMIN_ROLE: FixedBytes<32> =
+    fixed_bytes!("a49807205ce4d355092ef5a8a18f56e8913cf4a201fbe287825b095693c21775");
+/// Role identifier for compliance role (manages the blacklist): keccak256("COMPLIANCE_ROLE")
+pub const COMPLIANCE_ROLE: FixedBytes<32> =
+    fixed_bytes!("442a94f1a1fac79af32856af2a64f63648cfa2ef3b98610a5bb7cbec4cee6985");
+/// Default admin role constant (all-zero bytes32, as in OpenZeppelin AccessControl)
+pub const DEFAULT_ADMIN_ROLE: FixedBytes<32> = FixedBytes::ZERO;
+/// Roles built into the contract, reported first by `known_roles`
+pub const BUILTIN_ROLES: [FixedBytes<32>; 5] =
+    [DEFAULT_ADMIN_ROLE, ADMIN_ROLE, MINTER_ROLE, PAUSER_ROLE, COMPLIANCE_ROLE];
+
+/// Configuration keys reported by `ConfigChanged`: keccak256("<SETTING_NAME>")
+pub const CONFIG_SUPPLY_CAP: FixedBytes<32> =
+    fixed_bytes!("fd3bb9edb1738d6eabaeeef4953365562ed5b748e8b82d3bbb5e5ca5f8f3cbe5");
+pub const CONFIG_SUPPLY_CAP_ENABLED: FixedBytes<32> =
+    fixed_bytes!("c7783eec4eb1f2248a3d740640bb450bdc7c2eaa0fc24560dc981b3616ca4c54");
+pub const CONFIG_BLACKLIST_ENABLED: FixedBytes<32> =
+    fixed_bytes!("40ec3ef4425cde19bfbd323535dc90649a5b74cd7ade8d2d66f45773dee1c5b1");
+pub const CONFIG_TRANSFER_RESTRICTIONS_ENABLED: FixedBytes<32> =
+    fixed_bytes!("9e1d71fcca6133a4bb243111c3b7ae04a138e6be544927154148b6f835ae645e");
+pub const CONFIG_MIN_TRANSFER_AMOUNT: FixedBytes<32> =
+    fixed_bytes!("f53963c9e9c72fa24c19b6b35fa360b9046702b5c4caea8438f17dbd7ac4f061");
+pub const CONFIG_MIN_TRANSFER_ENABLED: FixedBytes<32> =
+    fixed_bytes!("b0753acdf493865f1a0018be23f079ac33dd15d8444498365f17c9700ad2307b");
+pub const CONFIG_FEE_BPS: FixedBytes<32> =
+    fixed_bytes!("e4800b4f0724e6ef51e4ac7c00f83590686f3a1152b3c019ca0b9c91f43de9d3");
+pub const CONFIG_FEE_ROUND_UP: FixedBytes<32> =
+    fixed_bytes!("be8c42f5f0794483d5f34c5167b0147e825612bcfaf245270f8c23bfa33ac4a3");
+pub const CONFIG_BURN_ON_DEAD_TRANSFER: FixedBytes<32> =
+    fixed_bytes!("72f5073afb200bf3d8e29cd8df17dcdd72f22a3f975724a2597f051f5effb67f");
+pub const CONFIG_STRICT_APPROVALS_ENABLED: FixedBytes<32> =
+    fixed_bytes!("ca25ba7b5b65e9ea068037c6754770d225d5cacf1dce22da865b5572ebacdfb2");
+pub const CONFIG_TRACK_APPROVALS_ENABLED: FixedBytes<32> =
+    fixed_bytes!("3eab1604240ca46df35e259d81a5d20076d535b19bb4b3547b173d1d9ef332a3");
+pub const CONFIG_SNAPSHOT_ALLOWANCES_ENABLED: FixedBytes<32> =
+    fixed_bytes!("68c411ce5cce649d95365266bf35e3327595285f3840977c545d98539d4e8c71");
+pub const CONFIG_MAX_BATCH_SIZE: FixedBytes<32> =
+    fixed_bytes!("74a9542972c543248fa89845f4df0cb69f53f5f9100479c775749e747e55634e");
+pub const CONFIG_ALLOW_MINT_TO_SELF: FixedBytes<32> =
+    fixed_bytes!("4b6b7e436011325376560def8c664cf30cfebb51da5149215d0cc39cc85bec3e");
+pub const CONFIG_MAX_MINT_PER_TX: FixedBytes<32> =
+    fixed_bytes!("55283384090096bce3f9bdca9b7ae2a5fa64237f071cf267bcf603a43cf5ec28");
+pub const CONFIG_REJECT_ZERO_TRANSFERS: FixedBytes<32> =
+    fixed_bytes!("ee332897bd9ee7bbc120f529a54875e16c96ab4744d8328c979accf409135012");
+pub const CONFIG_WHITELIST_MODE: FixedBytes<32> =
+    fixed_bytes!("5ef174b2f36adef07451c0854f5228335739efc20285fa572578f455533d6897");
+pub const CONFIG_REQUIRE_RECEIVER_ACK: FixedBytes<32> =
+    fixed_bytes!("8e18dd929c35b46bc9d6ff621db0c52be5f950200a050420b16bccc99bede86f");
+pub const CONFIG_GUARDIAN_PAUSE_MAX_DURATION: FixedBytes<32> =
+    fixed_bytes!("17900bd8c100125102e590c59598c45e0455893cd58063ab3840544f42df5018");
+
+/// Default maximum duration of a guardian emergency pause (72 hours in seconds)
+pub const GUARDIAN_PAUSE_DURATION: u64 = 72 * 60 * 60;
+
+/// Number of pause/unpause entries retained in the on-chain pause history
+pub const PAUSE_HISTORY_SIZE: u64 = 32;
+
+/// Basis-point denominator (100% = 10_000 bps)
+pub const BPS_DENOMINATOR: u64 = 10_000;
+/// Maximum transfer fee (10%)
+pub const MAX_FEE_BPS: u64 = 1_000;
+/// Default maximum number of items in a single batch call
+pub const DEFAULT_MAX_BATCH_SIZE: u64 = 256;
+/// Gas kept in reserve by `batch_transfer_resumable` so it can stop cleanly
+pub const RESUMABLE_BATCH_GAS_RESERVE: u64 = 100_000;
+
+/// `can_transfer` reason codes (0 = transfer would succeed)
+pub const TRANSFER_REASON_OK: u8 = 0;
+pub const TRANSFER_REASON_PAUSED: u8 = 1;
+pub const TRANSFER_REASON_ZERO_ADDRESS: u8 = 2;
+pub const TRANSFER_REASON_SENDER_BLACKLISTED: u8 = 3;
+pub const TRANSFER_REASON_RECIPIENT_BLACKLISTED: u8 = 4;
+pub const TRANSFER_REASON_RESTRICTED: u8 = 5;
+pub const TRANSFER_REASON_INSUFFICIENT_BALANCE: u8 = 6;
+pub const TRANSFER_REASON_HOOK_REJECTED: u8 = 7;
+pub const TRANSFER_REASON_BELOW_MINIMUM: u8 = 8;
+pub const TRANSFER_REASON_RECIPIENT_DENIED: u8 = 9;
+pub const TRANSFER_REASON_DECOMMISSIONED: u8 = 10;
+pub const TRANSFER_REASON_ZERO_AMOUNT: u8 = 11;
+pub const TRANSFER_REASON_RECIPIENT_NOT_ACCEPTING: u8 = 12;
+pub const TRANSFER_REASON_OUTFLOW_EXCEEDED: u8 = 13;
+
+/// Origin of a mint, reported as `reason` by the `Minted` event
+pub const MINT_SOURCE_INITIAL: u8 = 0;
+pub const MINT_SOURCE_MANUAL: u8 = 1;
+pub const MINT_SOURCE_AIRDROP: u8 = 2;
+/// Reserved: vesting schedules are pre-funded, so releases are transfers, not mints
+pub const MINT_SOURCE_VESTING: u8 = 3;
+/// Reserved: no flash-mint path exists yet
+pub const MINT_SOURCE_FLASH: u8 = 4;
+
+/// Cause of a burn, reported as `reason` by the `Burned` event
+pub const BURN_REASON_SELF: u8 = 0;
+pub const BURN_REASON_BURN_FROM: u8 = 1;
+/// Reserved: no seize path exists yet
+pub const BURN_REASON_SEIZE: u8 = 2;
+/// Reserved: decommissioning disables mint and transfer but burns nothing itself
+pub const BURN_REASON_DECOMMISSION: u8 = 3;
+/// A transfer to DEAD_ADDRESS routed through burn logic
+pub const BURN_REASON_DEAD_ADDRESS: u8 = 4;
+
+/// Whitelist policies applied while transfer restrictions are enabled
+/// EitherParty: the sender or the recipient must be whitelisted (default)
+pub const WHITELIST_MODE_EITHER_PARTY: u8 = 0;
+/// SenderOnly: the sender must be whitelisted (approved distributors)
+pub const WHITELIST_MODE_SENDER_ONLY: u8 = 1;
+/// BothParties: the sender and the recipient must both be whitelisted
+pub const WHITELIST_MODE_BOTH_PARTIES: u8 = 2;
+
+/// Reason codes returned by `can_mint`
+pub const MINT_REASON_OK: u8 = 0;
+pub const MINT_REASON_NO_ROLE: u8 = 1;
+pub const MINT_REASON_PAUSED: u8 = 2;
+pub const MINT_REASON_CAP_EXCEEDED: u8 = 3;
+pub const MINT_REASON_RATE_LIMITED: u8 = 4;
+pub const MINT_REASON_DISABLED: u8 = 5;
+pub const MINT_REASON_PER_TX_LIMIT: u8 = 6;
+pub const MINT_REASON_ROLE_PAUSED: u8 = 7;
+
+/// Bit positions of the feature flags packed into `config_flags`
+pub const FLAG_PAUSED: u8 = 0;
+pub const FLAG_ALLOWANCES_FROZEN: u8 = 1;
+pub const FLAG_SUPPLY_CAP_ENABLED: u8 = 2;
+pub const FLAG_BURN_ON_DEAD_TRANSFER: u8 = 3;
+pub const FLAG_BLACKLIST_ENABLED: u8 = 4;
+pub const FLAG_SNAPSHOT_ALLOWANCES_ENABLED: u8 = 5;
+pub const FLAG_GUARDIAN_ENABLED: u8 = 6;
+pub const FLAG_ALLOW_MINT_TO_SELF: u8 = 7;
+pub const FLAG_MINTING_RENOUNCED: u8 = 8;
+pub const FLAG_DECOMMISSIONED: u8 = 9;
+pub const FLAG_TRANSFER_RESTRICTIONS_ENABLED: u8 = 10;
+pub const FLAG_REJECT_ZERO_TRANSFERS: u8 = 11;
+pub const FLAG_MIN_TRANSFER_ENABLED: u8 = 12;
+pub const FLAG_FEE_ROUND_UP: u8 = 13;
+pub const FLAG_STRICT_APPROVALS_ENABLED: u8 = 14;
+pub const FLAG_TRACK_APPROVALS_ENABLED: u8 = 15;
+pub const FLAG_MIGRATION_ENDED: u8 = 16;
+pub const FLAG_REQUIRE_RECEIVER_ACK: u8 = 17;
+pub const FLAG_GUARDIAN_PAUSE: u8 = 18;
+
+/// Conventional "dead" address used for burns that keep total supply constant
+pub const DEAD_ADDRESS: Address = Address::new([
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xde, 0xad,
+]);
+
+/// Largest `decimals` accepted by `initialize`; 10^36 still leaves 10^41 of U256 headroom
+pub const MAX_DECIMALS: u8 = 36;
+/// Largest initial supply in whole tokens (10^18, i.e. one quintillion)
+/// A larger value almost always means the amount was scaled by 10^decimals twice
+pub const MAX_INITIAL_SUPPLY_WHOLE_TOKENS: u128 = 1_000_000_000_000_000_000;
+
+/// EIP-712 domain typehash:
+/// keccak256("EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)")
+pub const EIP712_DOMAIN_TYPEHASH: FixedBytes<32> =
+    fixed_bytes!("8b73c3c69bb8fe3d512ecc4cf759cc79239f7b179b0ffacaa9a75d522b39400f");
+/// EIP-2612 permit typehash:
+/// keccak256("Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)")
+pub const PERMIT_TYPEHASH: FixedBytes<32> =
+    fixed_bytes!("6e71edae12b1b97f4d1f60370fef10105fa2faae0126114a169c64845d6126c9");
+/// DAI-style permit typehash:
+/// keccak256("Permit(address holder,address spender,uint256 nonce,uint256 expiry,bool allowed)")
+pub const DAI_PERMIT_TYPEHASH: FixedBytes<32> =
+    fixed_bytes!("ea2aa0a1be11a07ed86d755c93467f4f82362b452371d1ba94d1715123511acb");
+/// Meta-transaction typehash:
+/// keccak256("MetaTx(address from,bytes data,uint256 nonce,uint256 deadline)")
+pub const META_TX_TYPEHASH: FixedBytes<32> =
+    fixed_bytes!("9f482fcad109796cfb6ac84940e04f582950937e8af1a930353cf9786ade9329");
+/// Selector of `transfer(address,uint256)`, dispatchable via `execute_meta_tx`
+pub const TRANSFER_SELECTOR: [u8; 4] = [0xa9, 0x05, 0x9c, 0xbb];
+/// Selector of `approve(address,uint256)`, dispatchable via `execute_meta_tx`
+pub const APPROVE_SELECTOR: [u8; 4] = [0x09, 0x5e, 0xa7, 0xb3];
+/// EIP-712 domain version string
+pub const EIP712_VERSION: &str = "1";
+/// Address of the ecrecover precompile
+const ECRECOVER_PRECOMPILE: Address = Address::new([
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+]);
 
 // ============================================================================
 // ERROR DEFINITIONS
@@ -44,29 +222,61 @@ sol! {
     error InsufficientAllowance(uint256 allowance, uint256 required);
     error ZeroAddress();
     error NotOwner(address caller, address owner);
+    error OwnerRenounced(); // `OwnershipRenounced` is taken by the event
     error AlreadyInitialized();
+    error InvalidMetadata(uint8 decimals);
+    error InitialSupplyTooLarge(uint256 initial_supply, uint256 max_supply);
+    error NotInitialized();
+    error InvalidVersion(uint256 current_version, uint256 new_version);
+    error VersionAlreadyInitialized(uint256 version);
     error ContractPaused();
     error NotContractPaused();
+    error PauseEventIndexOutOfBounds(uint256 index, uint256 count);
+    error AllowancesFrozen();
     error InvalidAmount();
+    error SupplyOverflow(uint256 total_supply, uint256 amount);
+    error BalanceOverflow(address account, uint256 balance, uint256 amount);
+    
+    // Approval Errors
+    error ApproveFromNonZero(uint256 current_allowance, uint256 amount);
+    error SpenderIndexOutOfBounds(uint256 index, uint256 count);
+    error InvalidExpiry(uint256 expiry, uint256 current_time);
+    error AllowanceNotExpired(address owner, address spender, uint256 expiry);
     
     // Supply Cap Errors
     error SupplyCapExceeded(uint256 current_supply, uint256 cap);
     error CannotDecreaseSupplyCap();
+    error CannotSetCapBelowCommitted(uint256 cap, uint256 committed);
     
     // Role-Based Access Control Errors
     error AccessDenied(address account, bytes32 role);
     error InvalidRole(bytes32 role);
     error RoleAlreadyGranted(bytes32 role, address account);
     error RoleAlreadyRevoked(bytes32 role, address account);
+    error RoleNotExpired(bytes32 role, address account, uint256 expiry);
+    error RolePaused(bytes32 role);
     
     // Blacklist Errors
     error AddressBlacklisted(address account);
+    error CannotBlacklistCritical(address account);
     error AddressNotBlacklisted(address account);
     
+    // Transfer Restriction Errors
+    error TransferRestricted(address from, address to);
+    error InvalidWhitelistMode(uint8 mode);
+    error TransferRejectedByHook(address from, address to, uint256 amount);
+    error TransferBelowMinimum(uint256 amount, uint256 min);
+    error TransferDenied(address account);
+    error TransferToNonAcceptingContract(address to);
+    error BalanceCheckFailed(address account, uint256 balance, uint256 min_balance);
+    error AccountOutflowExceeded(address account, uint256 used, uint256 limit);
+    
     // Snapshot Errors
     error SnapshotAlreadyTaken(uint256 snapshot_id);
     error SnapshotNotFound(uint256 snapshot_id);
     error SnapshotInProgress();
+    error HolderIndexOutOfBounds(uint256 index, uint256 count);
+    error FutureLookup(uint256 block_number, uint256 current_block);
     
     // Time-Lock Errors
     error OwnershipTransferPending(address new_owner, uint256 unlock_time);
@@ -77,6 +287,56 @@ sol! {
     // Batch Operation Errors
     error BatchTransferLengthMismatch();
     error BatchApproveLengthMismatch();
+    error BatchTransferFromLengthMismatch();
+    error SeedAllowancesLengthMismatch();
+    error SettleLengthMismatch();
+    error BatchItemFailed(uint256 index, bytes reason);
+    error BatchTooLarge(uint256 len, uint256 max);
+    error BatchIndexOutOfBounds(uint256 index, uint256 len);
+    
+    // Minting Limit Errors
+    error MintRateLimitExceeded(uint256 minted, uint256 limit);
+    error CannotMintToSelf();
+    error MintingDisabled();
+    error MintExceedsPerTxLimit(uint256 amount, uint256 max);
+    error AllocationExceedsCap(uint256 committed, uint256 cap);
+    
+    // Lifecycle Errors
+    error MintingNotRenounced();
+    error TokenDecommissioned();
+    error MigrationClosed();
+    
+    // Permit Errors
+    error PermitExpired(uint256 deadline, uint256 current_time);
+    error InvalidSigner(address signer, address owner);
+    error InvalidNonce(uint256 expected, uint256 provided);
+    error NonceOverflow(address owner);
+    error MetaTxExpired(uint256 deadline, uint256 current_time);
+    error InvalidMetaTxSigner(address signer, address from);
+    error UnsupportedMetaTxCall(bytes4 selector);
+    
+    // Guardian Errors
+    error GuardianAlreadyAdded(address guardian);
+    error NotGuardian(address account);
+    
+    // ETH Recovery Errors
+    error InsufficientEthBalance(uint256 balance, uint256 required);
+    error EthTransferFailed(address to, uint256 amount);
+    
+    // Transfer Fee Errors
+    error FeeTooHigh(uint256 fee_bps, uint256 max_bps);
+    error FeeSplitMismatch(uint256 total_bps, uint256 fee_bps);
+    error FeeSplitLengthMismatch();
+    
+    // Vesting Errors
+    error VestingScheduleExists(address beneficiary);
+    error NoVestingSchedule(address beneficiary);
+    error NothingToWithdraw(address account);
+    
+    // Scheduled Release Errors
+    error ReleaseNotFound(uint256 id);
+    error ReleaseNotReady(uint256 id, uint256 release_time, uint256 current_time);
+    error ReleaseAlreadyExecuted(uint256 id);
 }
 
 #[derive(SolidityError)]
@@ -85,27 +345,89 @@ pub enum ERC20Error {
     InsufficientAllowance(InsufficientAllowance),
     ZeroAddress(ZeroAddress),
     NotOwner(NotOwner),
+    OwnerRenounced(OwnerRenounced),
     AlreadyInitialized(AlreadyInitialized),
+    InvalidMetadata(InvalidMetadata),
+    InitialSupplyTooLarge(InitialSupplyTooLarge),
+    NotInitialized(NotInitialized),
+    InvalidVersion(InvalidVersion),
+    VersionAlreadyInitialized(VersionAlreadyInitialized),
     ContractPaused(ContractPaused),
     NotContractPaused(NotContractPaused),
+    PauseEventIndexOutOfBounds(PauseEventIndexOutOfBounds),
+    AllowancesFrozen(AllowancesFrozen),
     InvalidAmount(InvalidAmount),
+    SupplyOverflow(SupplyOverflow),
+    BalanceOverflow(BalanceOverflow),
+    ApproveFromNonZero(ApproveFromNonZero),
+    SpenderIndexOutOfBounds(SpenderIndexOutOfBounds),
+    InvalidExpiry(InvalidExpiry),
+    AllowanceNotExpired(AllowanceNotExpired),
     SupplyCapExceeded(SupplyCapExceeded),
     CannotDecreaseSupplyCap(CannotDecreaseSupplyCap),
+    CannotSetCapBelowCommitted(CannotSetCapBelowCommitted),
     AccessDenied(AccessDenied),
     InvalidRole(InvalidRole),
     RoleAlreadyGranted(RoleAlreadyGranted),
     RoleAlreadyRevoked(RoleAlreadyRevoked),
+    RoleNotExpired(RoleNotExpired),
+    RolePaused(RolePaused),
     AddressBlacklisted(AddressBlacklisted),
+    CannotBlacklistCritical(CannotBlacklistCritical),
     AddressNotBlacklisted(AddressNotBlacklisted),
+    TransferRestricted(TransferRestricted),
+    InvalidWhitelistMode(InvalidWhitelistMode),
+    TransferRejectedByHook(TransferRejectedByHook),
+    TransferBelowMinimum(TransferBelowMinimum),
+    TransferDenied(TransferDenied),
+    TransferToNonAcceptingContract(TransferToNonAcceptingContract),
+    BalanceCheckFailed(BalanceCheckFailed),
+    AccountOutflowExceeded(AccountOutflowExceeded),
     SnapshotAlreadyTaken(SnapshotAlreadyTaken),
     SnapshotNotFound(SnapshotNotFound),
     SnapshotInProgress(SnapshotInProgress),
+    HolderIndexOutOfBounds(HolderIndexOutOfBounds),
+    FutureLookup(FutureLookup),
     OwnershipTransferPending(OwnershipTransferPending),
     NoPendingOwnershipTransfer(NoPendingOwnershipTransfer),
     OwnershipTransferNotYetUnlockable(OwnershipTransferNotYetUnlockable),
     PendingOwnershipTransferExists(PendingOwnershipTransferExists),
     BatchTransferLengthMismatch(BatchTransferLengthMismatch),
     BatchApproveLengthMismatch(BatchApproveLengthMismatch),
+    BatchTransferFromLengthMismatch(BatchTransferFromLengthMismatch),
+    SeedAllowancesLengthMismatch(SeedAllowancesLengthMismatch),
+    SettleLengthMismatch(SettleLengthMismatch),
+    BatchItemFailed(BatchItemFailed),
+    BatchTooLarge(BatchTooLarge),
+    BatchIndexOutOfBounds(BatchIndexOutOfBounds),
+    MintRateLimitExceeded(MintRateLimitExceeded),
+    CannotMintToSelf(CannotMintToSelf),
+    MintingDisabled(MintingDisabled),
+    MintExceedsPerTxLimit(MintExceedsPerTxLimit),
+    AllocationExceedsCap(AllocationExceedsCap),
+    MintingNotRenounced(MintingNotRenounced),
+    TokenDecommissioned(TokenDecommissioned),
+    MigrationClosed(MigrationClosed),
+    PermitExpired(PermitExpired),
+    InvalidSigner(InvalidSigner),
+    InvalidNonce(InvalidNonce),
+    NonceOverflow(NonceOverflow),
+    MetaTxExpired(MetaTxExpired),
+    InvalidMetaTxSigner(InvalidMetaTxSigner),
+    UnsupportedMetaTxCall(UnsupportedMetaTxCall),
+    GuardianAlreadyAdded(GuardianAlreadyAdded),
+    NotGuardian(NotGuardian),
+    InsufficientEthBalance(InsufficientEthBalance),
+    EthTransferFailed(EthTransferFailed),
+    FeeTooHigh(FeeTooHigh),
+    FeeSplitMismatch(FeeSplitMismatch),
+    FeeSplitLengthMismatch(FeeSplitLengthMismatch),
+    VestingScheduleExists(VestingScheduleExists),
+    NoVestingSchedule(NoVestingSchedule),
+    NothingToWithdraw(NothingToWithdraw),
+    ReleaseNotFound(ReleaseNotFound),
+    ReleaseNotReady(ReleaseNotReady),
+    ReleaseAlreadyExecuted(ReleaseAlreadyExecuted),
 }
 
 // ============================================================================
@@ -116,16 +438,26 @@ sol! {
     // ERC-20 Standard Events
     event Transfer(address indexed from, address indexed to, uint256 amount);
     event Approval(address indexed owner, address indexed spender, uint256 amount);
+    event AllowanceExpirySet(address indexed owner, address indexed spender, uint256 expiry);
+    event TransferWithMemo(address indexed from, address indexed to, uint256 amount, bytes32 memo);
     
     // Additional Events for Access Control
     event OwnershipTransferred(address indexed previous_owner, address indexed new_owner);
+    event OwnershipRenounced(address indexed previous_owner, uint256 timestamp);
+    event MintingRenounced(address account);
+    event Decommissioned(address account, uint256 timestamp);
+    event MigrationEnded(address account, uint256 timestamp);
     event Paused(address account);
     event Unpaused(address account);
+    event AllowancesFrozenUpdated(bool frozen, address account);
+    event Reinitialized(uint256 old_version, uint256 new_version);
+    event ConfigChanged(bytes32 indexed key, uint256 old_value, uint256 new_value, address operator);
     
     // Role-Based Access Control Events
     event RoleGranted(bytes32 indexed role, address indexed account, address indexed sender);
     event RoleRevoked(bytes32 indexed role, address indexed account, address indexed sender);
     event RoleAdminChanged(bytes32 indexed role, bytes32 indexed previous_admin_role, bytes32 indexed new_admin_role);
+    event RolePauseUpdated(bytes32 indexed role, bool paused, address operator);
     
     // Supply Cap Events
     event SupplyCapUpdated(uint256 old_cap, uint256 new_cap);
@@ -133,10 +465,12 @@ sol! {
     // Blacklist Events
     event AddressBlacklisted(address indexed account, address indexed operator, uint256 timestamp);
     event AddressUnblacklisted(address indexed account, address indexed operator, uint256 timestamp);
+    event BlacklistScheduled(address indexed account, address indexed operator, uint256 effective_time);
     
     // Snapshot Events
     event SnapshotTaken(uint256 indexed snapshot_id, uint256 timestamp, uint256 total_supply);
     event SnapshotApplied(uint256 indexed snapshot_id, address indexed account, uint256 balance, uint256 total_supply);
+    event SnapshotBatchRecorded(uint256 indexed snapshot_id, uint256 start, uint256 count);
     
     // Time-Lock Events
     event OwnershipTransferInitiated(address indexed owner, address indexed new_owner, uint256 unlock_time);
@@ -146,10 +480,52 @@ sol! {
     // Emergency Events
     event EmergencyAdminChanged(address indexed old_admin, address indexed new_admin);
     event GuardianUpdated(address indexed old_guardian, address indexed new_guardian);
+    event GuardianAdded(address indexed guardian);
+    event GuardianRemoved(address indexed guardian);
+    event EthWithdrawn(address indexed to, uint256 amount);
+    
+    // Transfer Hook Events
+    event TransferHookUpdated(address indexed old_hook, address indexed new_hook);
+    event TransferDenyUpdated(address indexed account, bool denied);
+    event PrivilegedUpdated(address indexed account, bool privileged);
+    event ReceiverRegistered(address indexed account);
     
     // Monitoring Events
     event LargeTransfer(address indexed from, address indexed to, uint256 amount, uint256 timestamp);
-    event MintExceedsCap(uint256 amount, uint256 current_supply, uint256 cap);
+    event MintExceedsCap(address indexed minter, uint256 amount, uint256 current_supply, uint256 cap);
+    event Minted(address indexed to, uint256 amount, uint8 reason, address operator);
+    event Burned(address indexed from, uint256 amount, uint8 reason, address operator);
+    event MintAllocationSet(address indexed minter, uint256 amount);
+    
+    // Permit Events
+    event NoncesInvalidated(address indexed account, uint256 new_nonce);
+    event MetaTxExecuted(address indexed from, address indexed relayer, uint256 nonce, bytes4 selector);
+    
+    // Transfer Fee Events
+    event TransferFeeUpdated(uint256 fee_bps, address indexed fee_recipient);
+    event FeeSplitsUpdated(uint256 count);
+    event FeesClaimed(address indexed account, uint256 amount);
+    
+    // Vesting Events
+    event VestingScheduleCreated(address indexed beneficiary, uint256 amount, uint256 start, uint256 duration);
+    event TokensReleased(address indexed beneficiary, uint256 amount);
+    event ReleaseDeferred(address indexed beneficiary, uint256 amount);
+    event PendingWithdrawn(address indexed beneficiary, uint256 amount);
+    
+    // Scheduled Release Events
+    event ReleaseScheduled(uint256 indexed id, address indexed to, uint256 amount, uint256 release_time);
+    event ReleaseExecuted(uint256 indexed id, address indexed to, uint256 amount);
+}
+
+// ============================================================================
+// EXTERNAL INTERFACES
+// ============================================================================
+
+sol! {
+    // External transfer policy (KYC oracle, sanctions screen, ...)
+    interface ITransferHook {
+        function checkTransfer(address from, address to, uint256 amount) external view returns (bool);
+    }
 }
 
 // ============================================================================
@@ -174,7 +550,7 @@ sol_storage! {
         address owner;
         
         // Pausable State
-        bool paused;
+        bool paused; // deprecated: moved to config_flags (FLAG_PAUSED) by reinitialize
         
         // ============================================================================
         // PRODUCTION FEATURES STORAGE
@@ -182,15 +558,15 @@ sol_storage! {
         
         // Supply Cap
         uint256 supply_cap;
-        bool supply_cap_enabled;
+        bool supply_cap_enabled; // deprecated: moved to config_flags (FLAG_SUPPLY_CAP_ENABLED)
         
         // Role-Based Access Control
         mapping(bytes32 => mapping(address => bool)) roles;
-        mapping(bytes32 => address) role_admins;
+        mapping(bytes32 => bytes32) role_admins;
         
         // Blacklist
         mapping(address => bool) blacklisted;
-        bool blacklist_enabled;
+        bool blacklist_enabled; // deprecated: moved to config_flags (FLAG_BLACKLIST_ENABLED)
         
         // Snapshot System
         uint256 next_snapshot_id;
@@ -206,8 +582,8 @@ sol_storage! {
         address emergency_admin;
         
         // Guardian (for emergency pause by trusted third party)
-        address guardian;
-        bool guardian_enabled;
+        address guardian; // legacy single guardian, also a member of `guardians`
+        bool guardian_enabled; // deprecated: moved to config_flags (FLAG_GUARDIAN_ENABLED)
         
         // Mint Limits (rate limiting)
         mapping(address => uint256) minted_amounts;
@@ -217,20 +593,186 @@ sol_storage! {
         
         // Transfer Hooks (for future extensibility)
         mapping(address => bool) transfer_whitelist;
-        bool transfer_restrictions_enabled;
+        bool transfer_restrictions_enabled; // deprecated: moved to config_flags (FLAG_TRANSFER_RESTRICTIONS_ENABLED)
         
         // Version tracking for upgrades
         uint256 contract_version;
         
         // Initialization timestamp (for tracking)
         uint256 initialized_at;
+        
+        // ============================================================================
+        // APPENDED STORAGE
+        // Upgrades keep every slot above in place; new fields are only added at the end
+        // ============================================================================
+        
+        // Holder Registry (append-only, iterated by snapshot_all)
+        address[] holders;
+        mapping(address => bool) is_holder;
+        
+        // Amount minted in the current minting period
+        uint256 minted_in_period;
+        
+        // Permit (EIP-2612) nonces
+        mapping(address => uint256) nonces;
+        
+        // Versions already run through reinitialize
+        mapping(uint256 => bool) initialized_versions;
+        
+        // External transfer policy hook (Address::ZERO disables it)
+        address transfer_hook;
+        
+        // Scheduled blacklisting (0 = effective immediately)
+        mapping(address => uint256) blacklist_effective_at;
+        
+        // Display decimals (0 = report the real decimals)
+        uint8 display_decimals;
+        
+        // Timed pause (0 = paused until explicitly unpaused)
+        uint256 pause_expiry;
+        
+        // Token Vesting (tokens held in custody by the contract, released linearly)
+        mapping(address => VestingSchedule) vesting_schedules;
+        mapping(address => uint256) pending_withdrawals; // released but undeliverable
+        
+        // Minimum Transfer Amount (dust protection, whitelisted senders exempt)
+        uint256 min_transfer_amount;
+        
+        // Approval Registry (opt-in enumeration of spenders per owner)
+        mapping(address => address[]) approved_spenders;
+        mapping(address => mapping(address => uint256)) spender_positions; // 1-based, 0 = not listed
+        
+        // Pause history (ring buffer of PAUSE_HISTORY_SIZE entries)
+        mapping(uint256 => PauseEvent) pause_events;
+        uint256 pause_event_total; // entries ever recorded
+        
+        // Guardian set
+        mapping(address => bool) guardians;
+        uint256 guardian_count;
+        
+        // Time-Bounded Allowances (0 = never expires)
+        mapping(address => mapping(address => uint256)) allowance_expiry;
+        
+        // Custom roles registered via set_role_admin
+        bytes32[] custom_roles;
+        mapping(bytes32 => bool) is_custom_role;
+        
+        // Transfer Fees (fee_bps of each transfer, optionally split among recipients)
+        uint256 fee_bps;
+        address fee_recipient;
+        address[] fee_split_recipients;
+        uint256[] fee_split_bps;
+        
+        // Tokens held for vesting schedules and pending withdrawals
+        uint256 vesting_custody;
+        
+        // Per-Account Outflow Limits (0 limit = unlimited)
+        mapping(address => OutflowLimit) outflow_limits;
+        
+        // Protocol-level deny list (non-recoverable recipients)
+        mapping(address => bool) transfer_denied;
+        
+        // Role expiry (0 = permanent)
+        mapping(bytes32 => mapping(address => uint256)) role_expiry;
+        
+        // Batch Limits (0 = DEFAULT_MAX_BATCH_SIZE)
+        uint256 max_batch_size;
+        
+        // System addresses exempt from transfer policy (not compliance)
+        mapping(address => bool) privileged;
+        
+        // Total supply history (one checkpoint per block with a mint or burn)
+        mapping(uint256 => SupplyCheckpoint) supply_checkpoints;
+        uint256 supply_checkpoint_count;
+        
+        // Accrued fees, pulled via claim_fees
+        mapping(address => uint256) fee_balances;
+        uint256 fee_custody; // contract-held tokens backing fee_balances
+        
+        // accept_ownership also moves the core roles
+        bool pending_handover;
+        
+        // Per-transaction mint cap (0 = unlimited)
+        uint256 max_mint_per_tx;
+        
+        // Feature flags packed into one slot (bit positions are the FLAG_* constants)
+        uint256 config_flags;
+        
+        // Whitelist policy (WHITELIST_MODE_*)
+        uint8 whitelist_mode;
+        
+        // Paused roles (holders keep the role but cannot exercise it)
+        mapping(bytes32 => bool) role_paused;
+        
+        // State counters
+        uint256 role_entry_count; // (role, account) grants currently stored
+        uint256 blacklisted_count; // includes scheduled entries
+        
+        // Contracts that opted in via register_as_receiver
+        mapping(address => bool) accepts_token;
+        
+        // Scheduled Releases of the contract's own balance back into circulation
+        mapping(uint256 => ScheduledRelease) scheduled_releases;
+        uint256 release_count;
+        uint256 release_custody; // contract-held tokens earmarked for unexecuted releases
+        
+        // Mint allocations (promised amounts not yet minted)
+        mapping(address => uint256) mint_allocations;
+        uint256 total_allocated_mint; // sum of mint_allocations
+        
+        // Guardian pause window (0 = GUARDIAN_PAUSE_DURATION)
+        uint256 guardian_pause_max_duration;
+        
+        // Cumulative amount destroyed by internal_burn
+        uint256 total_burned;
     }
     
-    // Snapshot structure
+    // Snapshot structure (new members are appended after the original three)
     struct Snapshot {
         uint256 timestamp;
         uint256 total_supply;
         mapping(address => uint256) balances;
+        mapping(address => bool) recorded;
+        mapping(address => mapping(address => uint256)) allowances;
+        mapping(address => mapping(address => bool)) allowance_recorded;
+        bool finalized;
+    }
+    
+    // Per-account outflow limit and usage in the current window
+    struct OutflowLimit {
+        uint256 limit;
+        uint256 window;
+        uint256 window_start;
+        uint256 used;
+    }
+    
+    // Total supply as of a block
+    struct SupplyCheckpoint {
+        uint256 from_block;
+        uint256 value;
+    }
+    
+    // Pause history entry
+    struct PauseEvent {
+        bool paused;
+        address actor;
+        uint256 timestamp;
+    }
+    
+    // Vesting schedule structure
+    struct VestingSchedule {
+        uint256 total_amount;
+        uint256 released;
+        uint256 start;
+        uint256 duration;
+    }
+    
+    // Pending release of contract-held tokens
+    struct ScheduledRelease {
+        address to;
+        uint256 amount;
+        uint256 release_time;
+        bool executed;
     }
 }
 
@@ -238,14 +780,28 @@ sol_storage! {
 // HELPER FUNCTIONS
 // ============================================================================
 
-/// Convert u32 role constant to bytes32 for events
-fn bytes32_from_u32(role: u32) -> [u8; 32] {
-    let mut bytes = [0u8; 32];
-    bytes[31] = (role & 0xFF) as u8;
-    bytes[30] = ((role >> 8) & 0xFF) as u8;
-    bytes[29] = ((role >> 16) & 0xFF) as u8;
-    bytes[28] = ((role >> 24) & 0xFF) as u8;
-    bytes
+/// Wrap a per-item batch error so the failing index is recoverable from the revert data
+fn batch_item_failed(index: usize, error: ERC20Error) -> ERC20Error {
+    let reason: alloc::vec::Vec<u8> = error.into();
+    ERC20Error::BatchItemFailed(BatchItemFailed {
+        index: U256::from(index),
+        reason: Bytes::from(reason),
+    })
+}
+
+/// Returns whether a TRANSFER_REASON_* code is a transfer policy restriction on the
+/// parties, as opposed to a pause, a bad argument or an accounting failure
+fn is_restriction_reason(reason: u8) -> bool {
+    matches!(
+        reason,
+        TRANSFER_REASON_SENDER_BLACKLISTED
+            | TRANSFER_REASON_RECIPIENT_BLACKLISTED
+            | TRANSFER_REASON_RESTRICTED
+            | TRANSFER_REASON_HOOK_REJECTED
+            | TRANSFER_REASON_BELOW_MINIMUM
+            | TRANSFER_REASON_RECIPIENT_DENIED
+            | TRANSFER_REASON_RECIPIENT_NOT_ACCEPTING
+    )
 }
 
 /// Convert bytes32 to Address (for internal use)
@@ -275,6 +831,155 @@ impl ERC20Token {
         token_decimals: u8,
         initial_supply: U256,
         initial_owner: Address,
+    ) -> Result<(), ERC20Error> {
+        self.initialize_state(token_name, token_symbol, token_decimals, initial_owner)?;
+        self.check_initial_supply(initial_supply)?;
+        
+        // Mint initial supply to owner (respecting supply cap if enabled)
+        if initial_supply > U256::ZERO {
+            // Check supply cap if enabled
+            if self.flag(FLAG_SUPPLY_CAP_ENABLED) && initial_supply > self.supply_cap.get() {
+                return Err(ERC20Error::SupplyCapExceeded(SupplyCapExceeded {
+                    current_supply: U256::ZERO,
+                    cap: self.supply_cap.get(),
+                }));
+            }
+            
+            self.balances.setter(initial_owner).set(initial_supply);
+            self.set_total_supply(initial_supply);
+            self.track_holder(initial_owner);
+            
+            // Emit Transfer event from zero address (mint)
+            log(self.vm(), Transfer {
+                from: Address::ZERO,
+                to: initial_owner,
+                amount: initial_supply,
+            });
+            self.emit_minted(initial_owner, initial_supply, MINT_SOURCE_INITIAL);
+        }
+        
+        self.complete_initialization(initial_owner);
+        
+        Ok(())
+    }
+    
+    /// Initialize the token and split the initial supply across several recipients
+    /// Mints each allocation atomically (one Transfer from zero per recipient)
+    /// Can only be called once
+    pub fn initialize_with_allocations(
+        &mut self,
+        token_name: String,
+        token_symbol: String,
+        token_decimals: u8,
+        recipients: alloc::vec::Vec<Address>,
+        amounts: alloc::vec::Vec<U256>,
+        initial_owner: Address,
+    ) -> Result<(), ERC20Error> {
+        if recipients.len() != amounts.len() {
+            return Err(ERC20Error::BatchTransferLengthMismatch(BatchTransferLengthMismatch {}));
+        }
+        
+        self.initialize_state(token_name, token_symbol, token_decimals, initial_owner)?;
+        
+        // Mint each allocation
+        let mut total = U256::ZERO;
+        for (i, recipient) in recipients.into_iter().enumerate() {
+            let amount = amounts[i];
+            
+            if recipient == Address::ZERO {
+                return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
+            }
+            
+            if amount == U256::ZERO {
+                continue;
+            }
+            
+            let balance = self.balances.get(recipient);
+            let new_balance = balance.checked_add(amount)
+                .ok_or(ERC20Error::BalanceOverflow(BalanceOverflow {
+                    account: recipient,
+                    balance,
+                    amount,
+                }))?;
+            total = total.checked_add(amount)
+                .ok_or(ERC20Error::SupplyOverflow(SupplyOverflow {
+                    total_supply: total,
+                    amount,
+                }))?;
+            
+            self.balances.setter(recipient).set(new_balance);
+            self.track_holder(recipient);
+            
+            // Emit Transfer event from zero address (mint)
+            log(self.vm(), Transfer {
+                from: Address::ZERO,
+                to: recipient,
+                amount,
+            });
+            self.emit_minted(recipient, amount, MINT_SOURCE_INITIAL);
+        }
+        
+        self.check_initial_supply(total)?;
+        
+        // Check supply cap if enabled
+        if self.flag(FLAG_SUPPLY_CAP_ENABLED) && total > self.supply_cap.get() {
+            return Err(ERC20Error::SupplyCapExceeded(SupplyCapExceeded {
+                current_supply: U256::ZERO,
+                cap: self.supply_cap.get(),
+            }));
+        }
+        
+        self.set_total_supply(total);
+        
+        self.complete_initialization(initial_owner);
+        
+        Ok(())
+    }
+    
+    /// Initialize the token and wire up emergency coverage in the same transaction
+    /// `guardian` and `emergency_admin` are optional (pass Address::ZERO to skip)
+    /// Can only be called once
+    pub fn initialize_full(
+        &mut self,
+        token_name: String,
+        token_symbol: String,
+        token_decimals: u8,
+        initial_supply: U256,
+        initial_owner: Address,
+        guardian: Address,
+        emergency_admin: Address,
+    ) -> Result<(), ERC20Error> {
+        self.initialize(token_name, token_symbol, token_decimals, initial_supply, initial_owner)?;
+        
+        if guardian != Address::ZERO {
+            self.add_guardian_member(guardian);
+            self.guardian.set(guardian);
+            
+            log(self.vm(), GuardianUpdated {
+                old_guardian: Address::ZERO,
+                new_guardian: guardian,
+            });
+        }
+        
+        if emergency_admin != Address::ZERO {
+            self.emergency_admin.set(emergency_admin);
+            
+            log(self.vm(), EmergencyAdminChanged {
+                old_admin: Address::ZERO,
+                new_admin: emergency_admin,
+            });
+        }
+        
+        Ok(())
+    }
+    
+    /// Internal function validating init parameters and configuring all production features
+    fn initialize_state(
+        &mut self,
+        token_name: String,
+        token_symbol: String,
+        token_decimals: u8,
+        initial_owner: Address,
     ) -> Result<(), ERC20Error> {
         // Check if already initialized
         if self.initialized.get() {
@@ -286,9 +991,11 @@ impl ERC20Token {
             return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
         }
         
-        // Validate decimals
-        if token_decimals == 0 {
-            return Err(ERC20Error::InvalidAmount(InvalidAmount {}));
+        // Validate decimals (1..=MAX_DECIMALS)
+        if token_decimals == 0 || token_decimals > MAX_DECIMALS {
+            return Err(ERC20Error::InvalidMetadata(InvalidMetadata {
+                decimals: token_decimals,
+            }));
         }
         
         // Set metadata
@@ -304,17 +1011,20 @@ impl ERC20Token {
         self.role_admins.setter(ADMIN_ROLE).set(ADMIN_ROLE);
         self.role_admins.setter(MINTER_ROLE).set(ADMIN_ROLE);
         self.role_admins.setter(PAUSER_ROLE).set(ADMIN_ROLE);
+        self.role_admins.setter(COMPLIANCE_ROLE).set(ADMIN_ROLE);
         
-        // Grant admin role to initial owner
-        self.roles.setter(ADMIN_ROLE).setter(initial_owner).set(true);
+        // Grant default admin and admin roles to initial owner
+        self.set_role_member(DEFAULT_ADMIN_ROLE, initial_owner, true);
+        self.set_role_member(ADMIN_ROLE, initial_owner, true);
         
-        // Grant minter and pauser roles to initial owner
-        self.roles.setter(MINTER_ROLE).setter(initial_owner).set(true);
-        self.roles.setter(PAUSER_ROLE).setter(initial_owner).set(true);
+        // Grant minter, pauser, and compliance roles to initial owner
+        self.set_role_member(MINTER_ROLE, initial_owner, true);
+        self.set_role_member(PAUSER_ROLE, initial_owner, true);
+        self.set_role_member(COMPLIANCE_ROLE, initial_owner, true);
         
         // Initialize supply cap (disabled by default, can be enabled later)
         self.supply_cap.set(U256::MAX);
-        self.supply_cap_enabled.set(false);
+        self.set_flag(FLAG_SUPPLY_CAP_ENABLED, false);
         
         // Initialize snapshot system
         self.next_snapshot_id.set(U256::from(1));
@@ -328,69 +1038,79 @@ impl ERC20Token {
         self.minting_period_duration.set(U256::ZERO);
         
         // Initialize blacklist (disabled by default)
-        self.blacklist_enabled.set(false);
+        self.set_flag(FLAG_BLACKLIST_ENABLED, false);
         
         // Initialize transfer restrictions (disabled by default)
-        self.transfer_restrictions_enabled.set(false);
+        self.set_flag(FLAG_TRANSFER_RESTRICTIONS_ENABLED, false);
         
         // Initialize emergency features (disabled by default)
-        self.guardian_enabled.set(false);
+        self.set_flag(FLAG_GUARDIAN_ENABLED, false);
         
         // Set contract version
         self.contract_version.set(U256::from(1));
+        self.initialized_versions.setter(U256::from(1)).set(true);
         
         // Set initialization timestamp
-        self.initialized_at.set(U256::from(msg::epoch()));
+        self.initialized_at.set(self.current_time());
         
-        // Mint initial supply to owner (respecting supply cap if enabled)
-        if initial_supply > U256::ZERO {
-            // Check supply cap if enabled
-            if self.supply_cap_enabled.get() && initial_supply > self.supply_cap.get() {
-                return Err(ERC20Error::SupplyCapExceeded(SupplyCapExceeded {
-                    current_supply: U256::ZERO,
-                    cap: self.supply_cap.get(),
-                }));
-            }
-            
-            self.balances.setter(initial_owner).set(initial_supply);
-            self.total_supply.set(initial_supply);
-            
-            // Emit Transfer event from zero address (mint)
-            evm::log(Transfer {
-                from: Address::ZERO,
-                to: initial_owner,
-                amount: initial_supply,
-            });
+        Ok(())
+    }
+    
+    /// Internal function rejecting an initial supply above MAX_INITIAL_SUPPLY_WHOLE_TOKENS
+    /// whole tokens; must run after `initialize_state` has stored the decimals
+    fn check_initial_supply(&self, initial_supply: U256) -> Result<(), ERC20Error> {
+        let max_supply = U256::from(MAX_INITIAL_SUPPLY_WHOLE_TOKENS)
+            .saturating_mul(self.decimals_factor()?);
+        if initial_supply > max_supply {
+            return Err(ERC20Error::InitialSupplyTooLarge(InitialSupplyTooLarge {
+                initial_supply,
+                max_supply,
+            }));
         }
         
+        Ok(())
+    }
+    
+    /// Internal function marking the contract initialized and emitting setup events
+    fn complete_initialization(&mut self, initial_owner: Address) {
         // Mark as initialized
         self.initialized.set(true);
         
         // Emit events
-        evm::log(OwnershipTransferred {
+        log(self.vm(), OwnershipTransferred {
             previous_owner: Address::ZERO,
             new_owner: initial_owner,
         });
         
-        evm::log(RoleGranted {
-            role: bytes32_from_u32(ADMIN_ROLE),
+        log(self.vm(), RoleGranted {
+            role: DEFAULT_ADMIN_ROLE,
             account: initial_owner,
             sender: initial_owner,
         });
         
-        evm::log(RoleGranted {
-            role: bytes32_from_u32(MINTER_ROLE),
+        log(self.vm(), RoleGranted {
+            role: ADMIN_ROLE,
             account: initial_owner,
             sender: initial_owner,
         });
         
-        evm::log(RoleGranted {
-            role: bytes32_from_u32(PAUSER_ROLE),
+        log(self.vm(), RoleGranted {
+            role: MINTER_ROLE,
             account: initial_owner,
             sender: initial_owner,
         });
         
-        Ok(())
+        log(self.vm(), RoleGranted {
+            role: PAUSER_ROLE,
+            account: initial_owner,
+            sender: initial_owner,
+        });
+        
+        log(self.vm(), RoleGranted {
+            role: COMPLIANCE_ROLE,
+            account: initial_owner,
+            sender: initial_owner,
+        });
     }
     
     // ========================================================================
@@ -407,11 +1127,48 @@ impl ERC20Token {
         Ok(self.symbol.get_string())
     }
     
-    /// Returns the number of decimals the token uses
+    /// Returns the number of decimals the token reports
+    /// Returns the display override if set, otherwise the real decimals.
+    /// Accounting math always uses the real decimals
     pub fn decimals(&self) -> Result<u8, ERC20Error> {
+        let display_decimals = self.display_decimals.get().to_le_bytes::<1>()[0];
+        if display_decimals != 0 {
+            return Ok(display_decimals);
+        }
         Ok(self.decimals.get().to_le_bytes::<1>()[0])
     }
     
+    /// Sets the display decimals override reported by `decimals()`
+    /// Pass 0 to report the real decimals again. Can only be called by owner
+    pub fn set_display_decimals(&mut self, display_decimals: u8) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        self.display_decimals.set(Uint::<8, 1>::from(display_decimals));
+        Ok(true)
+    }
+    
+    /// Converts a raw amount to whole tokens (`raw / 10^decimals`, rounding down)
+    pub fn to_whole_tokens(&self, raw: U256) -> Result<U256, ERC20Error> {
+        Ok(raw / self.decimals_factor()?)
+    }
+    
+    /// Converts whole tokens to a raw amount (`whole * 10^decimals`)
+    /// Reverts with InvalidAmount on overflow
+    pub fn from_whole_tokens(&self, whole: U256) -> Result<U256, ERC20Error> {
+        whole
+            .checked_mul(self.decimals_factor()?)
+            .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))
+    }
+    
+    /// Internal function returning `10^decimals` using the stored decimals
+    fn decimals_factor(&self) -> Result<U256, ERC20Error> {
+        let decimals = self.decimals.get().to_le_bytes::<1>()[0];
+        U256::from(10)
+            .checked_pow(U256::from(decimals))
+            .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))
+    }
+    
     // ========================================================================
     // ERC-20 CORE METHODS
     // ========================================================================
@@ -426,13 +1183,41 @@ impl ERC20Token {
         Ok(self.balances.get(owner))
     }
     
+    /// Returns the balance of each of `accounts`, in order
+    pub fn balances_of(&self, accounts: alloc::vec::Vec<Address>) -> Result<alloc::vec::Vec<U256>, ERC20Error> {
+        Ok(accounts
+            .into_iter()
+            .map(|account| self.balances.get(account))
+            .collect())
+    }
+    
+    /// Returns the circulating supply:
+    /// `total_supply - balance_of(DEAD_ADDRESS) - balance_of(contract)`
+    pub fn circulating_supply(&self) -> Result<U256, ERC20Error> {
+        Ok(self
+            .total_supply
+            .get()
+            .saturating_sub(self.balances.get(DEAD_ADDRESS))
+            .saturating_sub(self.balances.get(self.vm().contract_address())))
+    }
+    
     /// Transfers `amount` tokens to address `to`
     /// Returns true on success, reverts on failure
     pub fn transfer(&mut self, to: Address, amount: U256) -> Result<bool, ERC20Error> {
-        let from = msg::sender();
+        self.require_initialized()?;
         
-        // Check if contract is paused
-        if self.paused.get() {
+        self.transfer_as(self.vm().msg_sender(), to, amount)
+    }
+    
+    /// Internal function moving `amount` tokens from `from` to `to` as if `from` called `transfer`
+    fn transfer_as(&mut self, from: Address, to: Address, amount: U256) -> Result<bool, ERC20Error> {
+        // Decommission, pause and recipient checks are repeated here because zero-amount
+        // transfers return before reaching internal_transfer
+        if self.flag(FLAG_DECOMMISSIONED) {
+            return Err(ERC20Error::TokenDecommissioned(TokenDecommissioned {}));
+        }
+        
+        if self.is_paused() {
             return Err(ERC20Error::ContractPaused(ContractPaused {}));
         }
         
@@ -441,10 +1226,14 @@ impl ERC20Token {
             return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
         }
         
-        // Allow zero amount transfers (ERC-20 compatible)
+        // Allow zero amount transfers (ERC-20 compatible) unless rejected by config
         if amount == U256::ZERO {
+            if self.flag(FLAG_REJECT_ZERO_TRANSFERS) {
+                return Err(ERC20Error::InvalidAmount(InvalidAmount {}));
+            }
+            
             // Still emit event for zero transfers
-            evm::log(Transfer {
+            log(self.vm(), Transfer {
                 from,
                 to,
                 amount: U256::ZERO,
@@ -461,18 +1250,26 @@ impl ERC20Token {
     /// Approves `spender` to spend `amount` tokens on behalf of caller
     /// Returns true on success, reverts on failure
     pub fn approve(&mut self, spender: Address, amount: U256) -> Result<bool, ERC20Error> {
-        let owner = msg::sender();
+        self.require_initialized()?;
         
+        self.approve_as(self.vm().msg_sender(), spender, amount)
+    }
+    
+    /// Internal function setting `owner`'s allowance as if `owner` called `approve`
+    fn approve_as(&mut self, owner: Address, spender: Address, amount: U256) -> Result<bool, ERC20Error> {
         // Validate spender address (recommended best practice)
         if spender == Address::ZERO {
             return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
         }
         
-        // Set allowance
-        self.allowances.setter(owner).setter(spender).set(amount);
+        self.check_strict_approval(owner, spender, amount)?;
+        
+        // Set allowance (a plain approval never expires)
+        self.set_allowance(owner, spender, amount);
+        self.set_allowance_expiry(owner, spender, U256::ZERO);
         
         // Emit Approval event
-        evm::log(Approval {
+        log(self.vm(), Approval {
             owner,
             spender,
             amount,
@@ -481,24 +1278,122 @@ impl ERC20Token {
         Ok(true)
     }
     
-    /// Returns the amount which `spender` is still allowed to withdraw from `owner`
-    pub fn allowance(&self, owner: Address, spender: Address) -> Result<U256, ERC20Error> {
-        Ok(self.allowances.getter(owner).get(spender))
+    /// Approves `spender` for an infinite (U256::MAX) allowance
+    /// Infinite allowances are not decremented when spent
+    pub fn approve_max(&mut self, spender: Address) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.approve(spender, U256::MAX)
     }
     
-    /// Transfers `amount` tokens from address `from` to address `to`
-    /// The caller must have allowance for `from`'s tokens of at least `amount`
-    /// Returns true on success, reverts on failure
-    pub fn transfer_from(
-        &mut self,
-        from: Address,
-        to: Address,
-        amount: U256,
-    ) -> Result<bool, ERC20Error> {
-        let spender = msg::sender();
+    /// Sets the caller's allowance for `spender` to zero regardless of its current value
+    /// Also clears any expiry and registry entry; safe to call when already zero
+    pub fn revoke_approval(&mut self, spender: Address) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
         
-        // Check if contract is paused
-        if self.paused.get() {
+        let owner = self.vm().msg_sender();
+        
+        self.set_allowance(owner, spender, U256::ZERO);
+        
+        log(self.vm(), Approval {
+            owner,
+            spender,
+            amount: U256::ZERO,
+        });
+        
+        Ok(true)
+    }
+    
+    /// Returns whether `spender` holds an infinite allowance over `owner`'s tokens
+    pub fn is_infinite_allowance(&self, owner: Address, spender: Address) -> Result<bool, ERC20Error> {
+        Ok(self.effective_allowance(owner, spender) == U256::MAX)
+    }
+    
+    /// Returns true if a direct `approve` from `owner` to `spender` with a new nonzero
+    /// value would be exposed to the allowance front-running race (current allowance
+    /// is nonzero); wallets should then suggest `increase_allowance`/`decrease_allowance`
+    pub fn approve_would_race(&self, owner: Address, spender: Address) -> Result<bool, ERC20Error> {
+        Ok(self.effective_allowance(owner, spender) != U256::ZERO)
+    }
+    
+    /// Sets the allowance of `spender` to zero and then to `amount` in one call
+    /// Emits two Approval events, matching the common zero-then-set pattern
+    pub fn safe_approve(&mut self, spender: Address, amount: U256) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        let owner = self.vm().msg_sender();
+        
+        if spender == Address::ZERO {
+            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
+        }
+        
+        // Reset to zero first
+        self.set_allowance(owner, spender, U256::ZERO);
+        log(self.vm(), Approval {
+            owner,
+            spender,
+            amount: U256::ZERO,
+        });
+        
+        // Then set the requested amount
+        self.set_allowance(owner, spender, amount);
+        log(self.vm(), Approval {
+            owner,
+            spender,
+            amount,
+        });
+        
+        Ok(true)
+    }
+    
+    /// Returns the amount which `spender` is still allowed to withdraw from `owner`
+    /// An expired time-bounded allowance is reported as zero
+    pub fn allowance(&self, owner: Address, spender: Address) -> Result<U256, ERC20Error> {
+        Ok(self.effective_allowance(owner, spender))
+    }
+    
+    /// Returns the allowance `owner` granted to each of `spenders`, in order
+    pub fn allowances_for(&self, owner: Address, spenders: alloc::vec::Vec<Address>) -> Result<alloc::vec::Vec<U256>, ERC20Error> {
+        Ok(spenders
+            .into_iter()
+            .map(|spender| self.effective_allowance(owner, spender))
+            .collect())
+    }
+    
+    /// Returns the amount `spender` can actually move from `owner` right now:
+    /// `min(allowance, balance_of(owner))`, or zero if the owner is blacklisted
+    pub fn spendable_allowance(&self, owner: Address, spender: Address) -> Result<U256, ERC20Error> {
+        // No transfer from a blacklisted owner could succeed
+        if self.flag(FLAG_BLACKLIST_ENABLED) && self.is_blacklist_active(owner) {
+            return Ok(U256::ZERO);
+        }
+        
+        let allowance = self.effective_allowance(owner, spender);
+        let balance = self.balances.get(owner);
+        
+        Ok(allowance.min(balance))
+    }
+    
+    /// Transfers `amount` tokens from address `from` to address `to`
+    /// The caller must have allowance for `from`'s tokens of at least `amount`
+    /// Returns true on success, reverts on failure
+    pub fn transfer_from(
+        &mut self,
+        from: Address,
+        to: Address,
+        amount: U256,
+    ) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        let spender = self.vm().msg_sender();
+        
+        // Zero-amount transfers return early, so check decommission here too
+        if self.flag(FLAG_DECOMMISSIONED) {
+            return Err(ERC20Error::TokenDecommissioned(TokenDecommissioned {}));
+        }
+        
+        // Check if contract is paused
+        if self.is_paused() {
             return Err(ERC20Error::ContractPaused(ContractPaused {}));
         }
         
@@ -507,9 +1402,13 @@ impl ERC20Token {
             return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
         }
         
-        // Allow zero amount transfers (ERC-20 compatible)
+        // Allow zero amount transfers (ERC-20 compatible) unless rejected by config
         if amount == U256::ZERO {
-            evm::log(Transfer {
+            if self.flag(FLAG_REJECT_ZERO_TRANSFERS) {
+                return Err(ERC20Error::InvalidAmount(InvalidAmount {}));
+            }
+            
+            log(self.vm(), Transfer {
                 from,
                 to,
                 amount: U256::ZERO,
@@ -517,30 +1416,12 @@ impl ERC20Token {
             return Ok(true);
         }
         
-        // Check and update allowance
-        let current_allowance = self.allowances.getter(from).get(spender);
-        
-        // Check for sufficient allowance
-        if current_allowance < amount {
-            return Err(ERC20Error::InsufficientAllowance(
-                InsufficientAllowance {
-                    allowance: current_allowance,
-                    required: amount,
-                },
-            ));
-        }
-        
-        // Decrease allowance using checked subtraction
-        let new_allowance = current_allowance
-            .checked_sub(amount)
-            .ok_or(ERC20Error::InsufficientAllowance(
-                InsufficientAllowance {
-                    allowance: current_allowance,
-                    required: amount,
-                },
-            ))?;
+        // Authorization order: recipient validity -> blacklist -> allowance -> balance,
+        // so a restricted party is reported as such and never consumes allowance
+        self.check_restrictions(from, to)?;
         
-        self.allowances.setter(from).setter(spender).set(new_allowance);
+        // Check and update allowance
+        self.spend_allowance(from, spender, amount)?;
         
         // Execute transfer
         self.internal_transfer(from, to, amount)?;
@@ -548,989 +1429,4169 @@ impl ERC20Token {
         Ok(true)
     }
     
-    // ========================================================================
-    // INTERNAL TRANSFER METHOD
-    // ========================================================================
+    /// Transfers `amount` tokens to address `to` with an attached reference `memo`
+    /// Emits the standard Transfer event plus TransferWithMemo for off-chain reconciliation
+    /// Returns true on success, reverts on failure
+    pub fn transfer_with_memo(
+        &mut self,
+        to: Address,
+        amount: U256,
+        memo: FixedBytes<32>,
+    ) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        let from = self.vm().msg_sender();
+        
+        // Perform the standard transfer (all checks apply)
+        self.transfer(to, amount)?;
+        
+        // Emit memo event for payment reconciliation
+        log(self.vm(), TransferWithMemo {
+            from,
+            to,
+            amount,
+            memo,
+        });
+        
+        Ok(true)
+    }
     
-    /// Internal function to execute token transfer
-    fn internal_transfer(
+    /// Transfers `amount` tokens to `to` and reverts unless the recipient's resulting
+    /// balance is at least `min_recipient_balance_after`
+    /// Guarantees net delivery when transfers may be reduced by fees or burns
+    pub fn transfer_checked(
         &mut self,
-        from: Address,
         to: Address,
         amount: U256,
-    ) -> Result<(), ERC20Error> {
-        let from_balance = self.balances.get(from);
+        min_recipient_balance_after: U256,
+    ) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
         
-        // Check sufficient balance
-        if from_balance < amount {
-            return Err(ERC20Error::InsufficientBalance(InsufficientBalance {
-                balance: from_balance,
-                required: amount,
+        // Perform the standard transfer (all checks apply)
+        self.transfer(to, amount)?;
+        
+        let balance = self.balances.get(to);
+        if balance < min_recipient_balance_after {
+            return Err(ERC20Error::BalanceCheckFailed(BalanceCheckFailed {
+                account: to,
+                balance,
+                min_balance: min_recipient_balance_after,
             }));
         }
         
-        // Update balances with checked arithmetic
-        let new_from_balance = from_balance
-            .checked_sub(amount)
-            .ok_or(ERC20Error::InsufficientBalance(InsufficientBalance {
-                balance: from_balance,
-                required: amount,
-            }))?;
-        
-        let to_balance = self.balances.get(to);
-        let new_to_balance = to_balance
-            .checked_add(amount)
-            .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))?;
+        Ok(true)
+    }
+    
+    /// Transfers the caller's entire current balance to `to`
+    /// Avoids racing incoming transfers when computing the balance off-chain
+    pub fn transfer_all(&mut self, to: Address) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
         
-        self.balances.setter(from).set(new_from_balance);
-        self.balances.setter(to).set(new_to_balance);
+        let amount = self.balances.get(self.vm().msg_sender());
+        self.transfer(to, amount)
+    }
+    
+    /// Transfers `balance * bps / 10000` of the caller's current balance to `to`
+    pub fn transfer_bps(&mut self, to: Address, bps: U256) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
         
-        // Emit transfer event
-        evm::log(Transfer { from, to, amount });
+        if bps > U256::from(BPS_DENOMINATOR) {
+            return Err(ERC20Error::InvalidAmount(InvalidAmount {}));
+        }
         
-        Ok(())
+        let (amount, _) = Self::mul_bps(self.balances.get(self.vm().msg_sender()), bps);
+        self.transfer(to, amount)
+    }
+    
+    /// Internal function computing `amount * bps / BPS_DENOMINATOR` rounded down, and whether it was inexact
+    /// Splits `amount` around the denominator so no intermediate product overflows for `bps <= BPS_DENOMINATOR`
+    fn mul_bps(amount: U256, bps: U256) -> (U256, bool) {
+        let denominator = U256::from(BPS_DENOMINATOR);
+        let rest = (amount % denominator) * bps;
+        ((amount / denominator) * bps + rest / denominator, rest % denominator != U256::ZERO)
     }
     
     // ========================================================================
-    // SAFE ALLOWANCE METHODS (Mitigates race condition)
+    // PERMIT (EIP-2612)
     // ========================================================================
     
-    /// Atomically increases the allowance granted to `spender` by the caller
-    /// Mitigates the allowance race condition vulnerability
-    pub fn increase_allowance(
+    /// Returns the current permit nonce for `owner`
+    pub fn nonces(&self, owner: Address) -> Result<U256, ERC20Error> {
+        Ok(self.nonces.get(owner))
+    }
+    
+    /// Returns the EIP-712 domain separator used for permit signatures
+    #[selector(name = "DOMAIN_SEPARATOR")]
+    pub fn domain_separator(&self) -> Result<FixedBytes<32>, ERC20Error> {
+        Ok(self.compute_domain_separator())
+    }
+    
+    /// Sets `value` as the allowance of `spender` over `owner`'s tokens via a signed approval
+    /// The signature must be over the EIP-712 Permit struct with the owner's current nonce
+    pub fn permit(
         &mut self,
+        owner: Address,
         spender: Address,
-        delta: U256,
+        value: U256,
+        deadline: U256,
+        v: u8,
+        r: FixedBytes<32>,
+        s: FixedBytes<32>,
     ) -> Result<bool, ERC20Error> {
-        let owner = msg::sender();
+        self.require_initialized()?;
         
-        // Validate spender address
-        if spender == Address::ZERO {
+        let current_time = self.current_time();
+        if current_time > deadline {
+            return Err(ERC20Error::PermitExpired(PermitExpired {
+                deadline,
+                current_time,
+            }));
+        }
+        
+        if owner == Address::ZERO || spender == Address::ZERO {
             return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
         }
         
-        // Get current allowance
-        let current_allowance = self.allowances.getter(owner).get(spender);
+        let nonce = self.nonces.get(owner);
+        let struct_hash = keccak(
+            (PERMIT_TYPEHASH, owner, spender, value, nonce, deadline).abi_encode(),
+        );
+        let digest = self.typed_data_hash(struct_hash);
         
-        // Calculate new allowance with overflow check
-        let new_allowance = current_allowance
-            .checked_add(delta)
-            .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))?;
+        let signer = self.recover_signer(digest, v, r, s);
+        if signer == Address::ZERO || signer != owner {
+            return Err(ERC20Error::InvalidSigner(InvalidSigner { signer, owner }));
+        }
         
-        // Set new allowance
-        self.allowances.setter(owner).setter(spender).set(new_allowance);
+        // Consume the nonce before updating state
+        self.use_nonce(owner, nonce)?;
         
-        // Emit Approval event
-        evm::log(Approval {
+        self.set_allowance(owner, spender, value);
+        self.set_allowance_expiry(owner, spender, U256::ZERO);
+        
+        log(self.vm(), Approval {
             owner,
             spender,
-            amount: new_allowance,
+            amount: value,
         });
         
         Ok(true)
     }
     
-    /// Atomically decreases the allowance granted to `spender` by the caller
-    /// Mitigates the allowance race condition vulnerability
-    pub fn decrease_allowance(
+    /// DAI-style permit: sets the allowance of `spender` to `U256::MAX` if `allowed`, else to zero
+    /// Shares nonces and the domain separator with `permit`; `expiry == 0` never expires
+    #[selector(name = "permit")]
+    pub fn permit_allowed(
         &mut self,
+        holder: Address,
         spender: Address,
-        delta: U256,
+        nonce: U256,
+        expiry: U256,
+        allowed: bool,
+        v: u8,
+        r: FixedBytes<32>,
+        s: FixedBytes<32>,
     ) -> Result<bool, ERC20Error> {
-        let owner = msg::sender();
+        self.require_initialized()?;
         
-        // Validate spender address
-        if spender == Address::ZERO {
+        let current_time = self.current_time();
+        if expiry != U256::ZERO && current_time > expiry {
+            return Err(ERC20Error::PermitExpired(PermitExpired {
+                deadline: expiry,
+                current_time,
+            }));
+        }
+        
+        if holder == Address::ZERO || spender == Address::ZERO {
             return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
         }
         
-        // Get current allowance
-        let current_allowance = self.allowances.getter(owner).get(spender);
+        let expected = self.nonces.get(holder);
+        if nonce != expected {
+            return Err(ERC20Error::InvalidNonce(InvalidNonce {
+                expected,
+                provided: nonce,
+            }));
+        }
         
-        // Check for sufficient allowance
-        if current_allowance < delta {
-            return Err(ERC20Error::InsufficientAllowance(
-                InsufficientAllowance {
-                    allowance: current_allowance,
-                    required: delta,
-                },
-            ));
+        let struct_hash = keccak(
+            (DAI_PERMIT_TYPEHASH, holder, spender, nonce, expiry, allowed).abi_encode(),
+        );
+        let digest = self.typed_data_hash(struct_hash);
+        
+        let signer = self.recover_signer(digest, v, r, s);
+        if signer == Address::ZERO || signer != holder {
+            return Err(ERC20Error::InvalidSigner(InvalidSigner { signer, owner: holder }));
         }
         
-        // Calculate new allowance with underflow check
-        let new_allowance = current_allowance
-            .checked_sub(delta)
-            .ok_or(ERC20Error::InsufficientAllowance(
-                InsufficientAllowance {
-                    allowance: current_allowance,
-                    required: delta,
-                },
-            ))?;
+        // Consume the nonce before updating state
+        self.use_nonce(holder, nonce)?;
         
-        // Set new allowance
-        self.allowances.setter(owner).setter(spender).set(new_allowance);
+        let amount = if allowed { U256::MAX } else { U256::ZERO };
+        self.set_allowance(holder, spender, amount);
+        self.set_allowance_expiry(holder, spender, U256::ZERO);
         
-        // Emit Approval event
-        evm::log(Approval {
-            owner,
+        log(self.vm(), Approval {
+            owner: holder,
             spender,
-            amount: new_allowance,
+            amount,
         });
         
         Ok(true)
     }
     
-    // ========================================================================
-    // MINTABLE FUNCTIONALITY (Owner Only)
-    // ========================================================================
-    
-    /// Mints `amount` tokens to address `to`
-    /// Can only be called by the owner
-    pub fn mint(&mut self, to: Address, amount: U256) -> Result<bool, ERC20Error> {
-        // Check ownership
-        self.only_owner()?;
+    /// Executes a `transfer` or `approve` on behalf of `from`, who signed it off-chain
+    /// The signature must be over the EIP-712 MetaTx struct with `from`'s current nonce,
+    /// shared with permit; the relayer (caller) pays the gas
+    pub fn execute_meta_tx(
+        &mut self,
+        from: Address,
+        call_data: Bytes,
+        nonce: U256,
+        deadline: U256,
+        v: u8,
+        r: FixedBytes<32>,
+        s: FixedBytes<32>,
+    ) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
         
-        // Check if contract is paused
-        if self.paused.get() {
-            return Err(ERC20Error::ContractPaused(ContractPaused {}));
+        let current_time = self.current_time();
+        if current_time > deadline {
+            return Err(ERC20Error::MetaTxExpired(MetaTxExpired {
+                deadline,
+                current_time,
+            }));
         }
         
-        // Validate recipient address
-        if to == Address::ZERO {
+        if from == Address::ZERO {
             return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
         }
         
-        // Skip if amount is zero
-        if amount == U256::ZERO {
-            return Ok(true);
+        let expected = self.nonces.get(from);
+        if nonce != expected {
+            return Err(ERC20Error::InvalidNonce(InvalidNonce {
+                expected,
+                provided: nonce,
+            }));
         }
         
-        // Update recipient balance with overflow check
-        let current_balance = self.balances.get(to);
-        let new_balance = current_balance
-            .checked_add(amount)
-            .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))?;
-        
-        self.balances.setter(to).set(new_balance);
+        let struct_hash = keccak(
+            (META_TX_TYPEHASH, from, keccak(&call_data), nonce, deadline).abi_encode(),
+        );
+        let digest = self.typed_data_hash(struct_hash);
         
-        // Update total supply with overflow check
-        let current_supply = self.total_supply.get();
-        let new_supply = current_supply
-            .checked_add(amount)
-            .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))?;
+        let signer = self.recover_signer(digest, v, r, s);
+        if signer == Address::ZERO || signer != from {
+            return Err(ERC20Error::InvalidMetaTxSigner(InvalidMetaTxSigner { signer, from }));
+        }
         
-        self.total_supply.set(new_supply);
+        if call_data.len() < 4 {
+            return Err(ERC20Error::UnsupportedMetaTxCall(UnsupportedMetaTxCall {
+                selector: FixedBytes::ZERO,
+            }));
+        }
+        let selector = FixedBytes::<4>::from_slice(&call_data[..4]);
+        let unsupported = || ERC20Error::UnsupportedMetaTxCall(UnsupportedMetaTxCall { selector });
+        let (target, amount) = <(Address, U256)>::abi_decode(&call_data[4..], true)
+            .map_err(|_| unsupported())?;
+        
+        // Consume the nonce before dispatching
+        self.use_nonce(from, nonce)?;
+        
+        match selector.0 {
+            TRANSFER_SELECTOR => self.transfer_as(from, target, amount)?,
+            APPROVE_SELECTOR => self.approve_as(from, target, amount)?,
+            _ => return Err(unsupported()),
+        };
         
-        // Emit Transfer event from zero address (mint)
-        evm::log(Transfer {
-            from: Address::ZERO,
-            to,
-            amount,
+        log(self.vm(), MetaTxExecuted {
+            from,
+            relayer: self.vm().msg_sender(),
+            nonce,
+            selector,
         });
         
         Ok(true)
     }
     
-    // ========================================================================
-    // BURNABLE FUNCTIONALITY
-    // ========================================================================
-    
-    /// Burns `amount` tokens from the caller's account
-    pub fn burn(&mut self, amount: U256) -> Result<bool, ERC20Error> {
-        let from = msg::sender();
+    /// Invalidates all outstanding permit signatures of the caller
+    /// Bumps the caller's nonce by `increment`, which must exceed the number of
+    /// signatures that may be outstanding (e.g. after a key leak)
+    pub fn invalidate_nonces(&mut self, increment: U256) -> Result<U256, ERC20Error> {
+        self.require_initialized()?;
         
-        // Check if contract is paused
-        if self.paused.get() {
-            return Err(ERC20Error::ContractPaused(ContractPaused {}));
-        }
+        let account = self.vm().msg_sender();
         
-        // Skip if amount is zero
-        if amount == U256::ZERO {
-            return Ok(true);
+        if increment == U256::ZERO {
+            return Err(ERC20Error::InvalidAmount(InvalidAmount {}));
         }
         
-        // Check balance
-        let current_balance = self.balances.get(from);
-        if current_balance < amount {
-            return Err(ERC20Error::InsufficientBalance(InsufficientBalance {
-                balance: current_balance,
-                required: amount,
-            }));
-        }
+        let new_nonce = self.nonces.get(account)
+            .checked_add(increment)
+            .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))?;
         
-        // Update balance with underflow check
-        let new_balance = current_balance
-            .checked_sub(amount)
-            .ok_or(ERC20Error::InsufficientBalance(InsufficientBalance {
-                balance: current_balance,
+        self.nonces.setter(account).set(new_nonce);
+        
+        log(self.vm(), NoncesInvalidated {
+            account,
+            new_nonce,
+        });
+        
+        Ok(new_nonce)
+    }
+    
+    /// Internal function advancing `owner`'s nonce past `nonce`
+    /// A nonce pushed to U256::MAX by `invalidate_nonces` can never be consumed
+    fn use_nonce(&mut self, owner: Address, nonce: U256) -> Result<(), ERC20Error> {
+        let next = nonce.checked_add(U256::from(1))
+            .ok_or(ERC20Error::NonceOverflow(NonceOverflow { owner }))?;
+        self.nonces.setter(owner).set(next);
+        Ok(())
+    }
+    
+    /// Internal function recovering the signer of `hash` via the ecrecover precompile
+    /// Returns Address::ZERO for malformed signatures
+    fn recover_signer(&self, hash: FixedBytes<32>, v: u8, r: FixedBytes<32>, s: FixedBytes<32>) -> Address {
+        let input = (hash, U256::from(v), r, s).abi_encode();
+        
+        match self.vm().static_call(&Call::new(), ECRECOVER_PRECOMPILE, &input) {
+            Ok(data) if data.len() == 32 => {
+                let bytes: [u8; 32] = data[..].try_into().unwrap_or([0u8; 32]);
+                bytes32_to_address(&bytes)
+            }
+            _ => Address::ZERO,
+        }
+    }
+    
+    /// Internal function computing the EIP-712 domain separator
+    fn compute_domain_separator(&self) -> FixedBytes<32> {
+        let name_hash = keccak(self.name.get_string().as_bytes());
+        let version_hash = keccak(EIP712_VERSION.as_bytes());
+        
+        keccak(
+            (
+                EIP712_DOMAIN_TYPEHASH,
+                name_hash,
+                version_hash,
+                U256::from(self.vm().chain_id()),
+                self.vm().contract_address(),
+            )
+                .abi_encode(),
+        )
+    }
+    
+    /// Internal function computing the EIP-712 digest for a struct hash
+    fn typed_data_hash(&self, struct_hash: FixedBytes<32>) -> FixedBytes<32> {
+        let mut data = alloc::vec::Vec::with_capacity(66);
+        data.extend_from_slice(&[0x19, 0x01]);
+        data.extend_from_slice(self.compute_domain_separator().as_slice());
+        data.extend_from_slice(struct_hash.as_slice());
+        keccak(data)
+    }
+    
+    // ========================================================================
+    // INTERNAL TRANSFER METHOD
+    // ========================================================================
+    
+    /// Internal function to execute token transfer
+    /// Single enforcement point for every enabled transfer feature (pause, zero recipient,
+    /// blacklist, whitelist, deny list, receiver acknowledgement, minimum, hook, outflow
+    /// limits, fees, monitoring); checked in the same order as `transfer_failure_reason`
+    fn internal_transfer(
+        &mut self,
+        from: Address,
+        to: Address,
+        amount: U256,
+    ) -> Result<(), ERC20Error> {
+        // Decommissioned tokens can only be burned
+        if self.flag(FLAG_DECOMMISSIONED) {
+            return Err(ERC20Error::TokenDecommissioned(TokenDecommissioned {}));
+        }
+        
+        // Check if contract is paused
+        if self.is_paused() {
+            return Err(ERC20Error::ContractPaused(ContractPaused {}));
+        }
+        
+        // Validate recipient address
+        if to == Address::ZERO {
+            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
+        }
+        
+        // Check blacklist and transfer restrictions
+        self.check_restrictions(from, to)?;
+        
+        // Reject recipients on the protocol deny list (independent of the blacklist)
+        if self.transfer_denied.get(to) {
+            return Err(ERC20Error::TransferDenied(TransferDenied { account: to }));
+        }
+        
+        // Privileged system addresses (routers, bridges) bypass minimums, outflow limits,
+        // fees and burn-on-dead-transfer; the blacklist, deny list and hook still apply
+        let privileged = self.privileged.get(from) || self.privileged.get(to);
+        
+        // Contracts must opt in to receive tokens when receiver acknowledgement is required
+        if !privileged && !self.is_accepting_receiver(to) {
+            return Err(ERC20Error::TransferToNonAcceptingContract(TransferToNonAcceptingContract { to }));
+        }
+        
+        // Reject dust transfers below the configured minimum
+        if !privileged && self.is_below_minimum(from, amount) {
+            return Err(ERC20Error::TransferBelowMinimum(TransferBelowMinimum {
+                amount,
+                min: self.min_transfer_amount.get(),
+            }));
+        }
+        
+        // Consult the external transfer policy hook if one is set
+        let hook = self.transfer_hook.get();
+        if hook != Address::ZERO {
+            let approved = self.hook_approves(hook, from, to, amount);
+            if !approved {
+                return Err(ERC20Error::TransferRejectedByHook(TransferRejectedByHook {
+                    from,
+                    to,
+                    amount,
+                }));
+            }
+        }
+        
+        // Route transfers to the dead address through burn logic when enabled
+        if !privileged && to == DEAD_ADDRESS && self.flag(FLAG_BURN_ON_DEAD_TRANSFER) {
+            return self.internal_burn(from, amount, BURN_REASON_DEAD_ADDRESS);
+        }
+        
+        let from_balance = self.balances.get(from);
+        
+        // Check sufficient balance
+        if from_balance < amount {
+            return Err(ERC20Error::InsufficientBalance(InsufficientBalance {
+                balance: from_balance,
+                required: amount,
+            }));
+        }
+        
+        // Self-transfers leave balances untouched but still emit the event
+        if from == to {
+            log(self.vm(), Transfer { from, to, amount });
+            return Ok(());
+        }
+        
+        // Enforce the sender's outflow limit (if any)
+        if !privileged {
+            self.consume_outflow(from, amount)?;
+        }
+        
+        // Update balances with checked arithmetic
+        let new_from_balance = from_balance
+            .checked_sub(amount)
+            .ok_or(ERC20Error::InsufficientBalance(InsufficientBalance {
+                balance: from_balance,
+                required: amount,
+            }))?;
+        
+        // Debit the sender once for the full amount
+        self.checkpoint_balance(from);
+        self.balances.setter(from).set(new_from_balance);
+        
+        // Route the transfer fee (zero when no fee is configured, either party is privileged,
+        // or tokens move in or out of the contract's own custody: vesting, releases, fees)
+        let this = self.vm().contract_address();
+        let fee = if privileged || from == this || to == this {
+            U256::ZERO
+        } else {
+            self.collect_transfer_fee(from, amount)?
+        };
+        let net_amount = amount - fee;
+        
+        self.credit_balance(to, net_amount)?;
+        
+        // Emit transfer event
+        log(self.vm(), Transfer {
+            from,
+            to,
+            amount: net_amount,
+        });
+        
+        // Log large transfers for monitoring
+        let large_threshold = U256::from(100_000_000_000_000_000_000_000u128); // 100K tokens with 18 decimals
+        if amount >= large_threshold {
+            log(self.vm(), LargeTransfer {
+                from,
+                to,
+                amount,
+                timestamp: self.current_time(),
+            });
+        }
+        
+        Ok(())
+    }
+    
+    /// Internal function adding `amount` to `account`'s balance
+    fn credit_balance(&mut self, account: Address, amount: U256) -> Result<(), ERC20Error> {
+        let balance = self.balances.get(account);
+        let new_balance = balance.checked_add(amount)
+            .ok_or(ERC20Error::BalanceOverflow(BalanceOverflow {
+                account,
+                balance,
+                amount,
+            }))?;
+        
+        self.checkpoint_balance(account);
+        self.balances.setter(account).set(new_balance);
+        self.track_holder(account);
+        
+        Ok(())
+    }
+    
+    // ========================================================================
+    // SAFE ALLOWANCE METHODS (Mitigates race condition)
+    // ========================================================================
+    
+    /// Atomically increases the allowance granted to `spender` by the caller
+    /// Mitigates the allowance race condition vulnerability
+    /// An expired allowance counts as zero; the increase starts from zero and clears the expiry
+    pub fn increase_allowance(
+        &mut self,
+        spender: Address,
+        delta: U256,
+    ) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        let owner = self.vm().msg_sender();
+        
+        // Validate spender address
+        if spender == Address::ZERO {
+            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
+        }
+        
+        // Get current allowance (zero once expired)
+        let expired = self.allowance_expired(owner, spender);
+        let current_allowance = self.effective_allowance(owner, spender);
+        
+        // Calculate new allowance with overflow check
+        let new_allowance = current_allowance
+            .checked_add(delta)
+            .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))?;
+        
+        // Set new allowance, dropping the lapsed expiry
+        self.set_allowance(owner, spender, new_allowance);
+        if expired {
+            self.set_allowance_expiry(owner, spender, U256::ZERO);
+        }
+        
+        // Emit Approval event
+        log(self.vm(), Approval {
+            owner,
+            spender,
+            amount: new_allowance,
+        });
+        
+        Ok(true)
+    }
+    
+    /// Atomically decreases the allowance granted to `spender` by the caller
+    /// Mitigates the allowance race condition vulnerability
+    /// An expired allowance counts as zero, so any nonzero decrease reverts
+    pub fn decrease_allowance(
+        &mut self,
+        spender: Address,
+        delta: U256,
+    ) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        let owner = self.vm().msg_sender();
+        
+        // Validate spender address
+        if spender == Address::ZERO {
+            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
+        }
+        
+        // Get current allowance (zero once expired)
+        let current_allowance = self.effective_allowance(owner, spender);
+        
+        // Check for sufficient allowance
+        if current_allowance < delta {
+            return Err(ERC20Error::InsufficientAllowance(
+                InsufficientAllowance {
+                    allowance: current_allowance,
+                    required: delta,
+                },
+            ));
+        }
+        
+        // Calculate new allowance with underflow check
+        let new_allowance = current_allowance
+            .checked_sub(delta)
+            .ok_or(ERC20Error::InsufficientAllowance(
+                InsufficientAllowance {
+                    allowance: current_allowance,
+                    required: delta,
+                },
+            ))?;
+        
+        // Set new allowance
+        self.set_allowance(owner, spender, new_allowance);
+        
+        // Emit Approval event
+        log(self.vm(), Approval {
+            owner,
+            spender,
+            amount: new_allowance,
+        });
+        
+        Ok(true)
+    }
+    
+    /// Returns whether strict approvals (nonzero to nonzero `approve` rejected) are enabled
+    pub fn strict_approvals_enabled(&self) -> Result<bool, ERC20Error> {
+        Ok(self.flag(FLAG_STRICT_APPROVALS_ENABLED))
+    }
+    
+    /// Enables or disables strict approvals
+    /// Can only be called by owner
+    pub fn set_strict_approvals_enabled(&mut self, enabled: bool) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        let old_value = self.flag(FLAG_STRICT_APPROVALS_ENABLED);
+        self.set_flag(FLAG_STRICT_APPROVALS_ENABLED, enabled);
+        self.emit_config_changed(CONFIG_STRICT_APPROVALS_ENABLED, U256::from(old_value), U256::from(enabled));
+        Ok(true)
+    }
+    
+    /// Internal function enforcing the approve-through-zero rule in strict mode
+    /// `increase_allowance`/`decrease_allowance` are not affected
+    fn check_strict_approval(&self, owner: Address, spender: Address, amount: U256) -> Result<(), ERC20Error> {
+        if !self.flag(FLAG_STRICT_APPROVALS_ENABLED) || amount == U256::ZERO {
+            return Ok(());
+        }
+        
+        let current_allowance = self.effective_allowance(owner, spender);
+        if current_allowance != U256::ZERO {
+            return Err(ERC20Error::ApproveFromNonZero(ApproveFromNonZero {
+                current_allowance,
+                amount,
+            }));
+        }
+        
+        Ok(())
+    }
+    
+    /// Internal function to set an allowance, keeping snapshots and the spender registry in sync
+    /// Zeroing an allowance also clears its expiry
+    fn set_allowance(&mut self, owner: Address, spender: Address, amount: U256) {
+        self.checkpoint_allowance(owner, spender);
+        self.allowances.setter(owner).setter(spender).set(amount);
+        self.track_spender(owner, spender, amount);
+        
+        if amount == U256::ZERO {
+            self.allowance_expiry.setter(owner).setter(spender).set(U256::ZERO);
+        }
+    }
+    
+    // ========================================================================
+    // TIME-BOUNDED ALLOWANCES
+    // ========================================================================
+    
+    /// Returns the expiry of `spender`'s allowance over `owner`'s tokens (0 = never expires)
+    pub fn allowance_expiry(&self, owner: Address, spender: Address) -> Result<U256, ERC20Error> {
+        Ok(self.allowance_expiry.getter(owner).get(spender))
+    }
+    
+    /// Approves `spender` to spend `amount` tokens of the caller until `expiry`
+    /// After `expiry` the allowance is treated as zero and can be cleaned up by anyone
+    pub fn approve_with_expiry(
+        &mut self,
+        spender: Address,
+        amount: U256,
+        expiry: U256,
+    ) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        let owner = self.vm().msg_sender();
+        
+        if spender == Address::ZERO {
+            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
+        }
+        
+        let current_time = self.current_time();
+        if expiry <= current_time {
+            return Err(ERC20Error::InvalidExpiry(InvalidExpiry {
+                expiry,
+                current_time,
+            }));
+        }
+        
+        self.check_strict_approval(owner, spender, amount)?;
+        
+        self.set_allowance(owner, spender, amount);
+        self.set_allowance_expiry(owner, spender, expiry);
+        
+        log(self.vm(), Approval {
+            owner,
+            spender,
+            amount,
+        });
+        
+        Ok(true)
+    }
+    
+    /// Atomically increases `spender`'s allowance by `delta` and sets its expiry to `expiry`
+    /// An expired allowance counts as zero, so the increase starts from zero
+    pub fn increase_allowance_with_expiry(
+        &mut self,
+        spender: Address,
+        delta: U256,
+        expiry: U256,
+    ) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        let owner = self.vm().msg_sender();
+        
+        if spender == Address::ZERO {
+            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
+        }
+        
+        let current_time = self.current_time();
+        if expiry <= current_time {
+            return Err(ERC20Error::InvalidExpiry(InvalidExpiry {
+                expiry,
+                current_time,
+            }));
+        }
+        
+        let new_allowance = self.effective_allowance(owner, spender)
+            .checked_add(delta)
+            .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))?;
+        
+        self.set_allowance(owner, spender, new_allowance);
+        self.set_allowance_expiry(owner, spender, expiry);
+        
+        log(self.vm(), Approval {
+            owner,
+            spender,
+            amount: new_allowance,
+        });
+        
+        Ok(true)
+    }
+    
+    /// Zeroes an allowance whose expiry has passed
+    /// Callable by anyone so keepers can tidy up stale approvals
+    pub fn revoke_expired_allowance(&mut self, owner: Address, spender: Address) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        let expiry = self.allowance_expiry.getter(owner).get(spender);
+        if expiry == U256::ZERO || self.current_time() < expiry {
+            return Err(ERC20Error::AllowanceNotExpired(AllowanceNotExpired {
+                owner,
+                spender,
+                expiry,
+            }));
+        }
+        
+        self.set_allowance(owner, spender, U256::ZERO);
+        
+        log(self.vm(), Approval {
+            owner,
+            spender,
+            amount: U256::ZERO,
+        });
+        
+        Ok(true)
+    }
+    
+    /// Internal function returning the allowance, or zero once it has expired
+    fn effective_allowance(&self, owner: Address, spender: Address) -> U256 {
+        if self.allowance_expired(owner, spender) {
+            return U256::ZERO;
+        }
+        self.allowances.getter(owner).get(spender)
+    }
+    
+    /// Internal function checking whether an allowance had an expiry that has passed
+    fn allowance_expired(&self, owner: Address, spender: Address) -> bool {
+        let expiry = self.allowance_expiry.getter(owner).get(spender);
+        expiry != U256::ZERO && self.current_time() >= expiry
+    }
+    
+    /// Internal function to set an allowance expiry, logging changes
+    fn set_allowance_expiry(&mut self, owner: Address, spender: Address, expiry: U256) {
+        if self.allowance_expiry.getter(owner).get(spender) == expiry {
+            return;
+        }
+        
+        self.allowance_expiry.setter(owner).setter(spender).set(expiry);
+        
+        log(self.vm(), AllowanceExpirySet {
+            owner,
+            spender,
+            expiry,
+        });
+    }
+    
+    /// Internal function to check and decrement `spender`'s allowance over `owner`'s tokens
+    /// A U256::MAX allowance is treated as infinite and is never decremented, so
+    /// `transfer_from` and `burn_from` leave it unchanged
+    fn spend_allowance(&mut self, owner: Address, spender: Address, amount: U256) -> Result<(), ERC20Error> {
+        // Emergency freeze: no allowance can be spent, whatever the pause state
+        if self.flag(FLAG_ALLOWANCES_FROZEN) {
+            return Err(ERC20Error::AllowancesFrozen(AllowancesFrozen {}));
+        }
+        
+        let current_allowance = self.effective_allowance(owner, spender);
+        
+        // Check for sufficient allowance
+        if current_allowance < amount {
+            return Err(ERC20Error::InsufficientAllowance(
+                InsufficientAllowance {
+                    allowance: current_allowance,
+                    required: amount,
+                },
+            ));
+        }
+        
+        // An infinite (U256::MAX) allowance is never decremented
+        if current_allowance == U256::MAX {
+            return Ok(());
+        }
+        
+        // Decrease allowance using checked subtraction
+        let new_allowance = current_allowance
+            .checked_sub(amount)
+            .ok_or(ERC20Error::InsufficientAllowance(
+                InsufficientAllowance {
+                    allowance: current_allowance,
+                    required: amount,
+                },
+            ))?;
+        
+        self.set_allowance(owner, spender, new_allowance);
+        
+        Ok(())
+    }
+    
+    // ========================================================================
+    // APPROVAL REGISTRY
+    // ========================================================================
+    
+    /// Returns whether spender enumeration is maintained
+    pub fn track_approvals_enabled(&self) -> Result<bool, ERC20Error> {
+        Ok(self.flag(FLAG_TRACK_APPROVALS_ENABLED))
+    }
+    
+    /// Enables or disables spender enumeration
+    /// Only allowances set while enabled are listed
+    pub fn set_track_approvals_enabled(&mut self, enabled: bool) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        let old_value = self.flag(FLAG_TRACK_APPROVALS_ENABLED);
+        self.set_flag(FLAG_TRACK_APPROVALS_ENABLED, enabled);
+        self.emit_config_changed(CONFIG_TRACK_APPROVALS_ENABLED, U256::from(old_value), U256::from(enabled));
+        Ok(true)
+    }
+    
+    /// Returns the number of listed spenders with a nonzero allowance from `owner`
+    pub fn spender_count(&self, owner: Address) -> Result<U256, ERC20Error> {
+        Ok(U256::from(self.approved_spenders.getter(owner).len()))
+    }
+    
+    /// Returns the listed spender at `index` for `owner`
+    /// Use with `spender_count` and `allowance` to enumerate active approvals
+    pub fn spender_at(&self, owner: Address, index: U256) -> Result<Address, ERC20Error> {
+        let spenders = self.approved_spenders.getter(owner);
+        let count = U256::from(spenders.len());
+        if index >= count {
+            return Err(ERC20Error::SpenderIndexOutOfBounds(SpenderIndexOutOfBounds {
+                index,
+                count,
+            }));
+        }
+        
+        Ok(spenders.get(index.to::<usize>()).unwrap_or(Address::ZERO))
+    }
+    
+    /// Internal function listing `spender` on a nonzero allowance and pruning it on zero
+    /// Pruning runs even when tracking is disabled so the list never goes stale
+    fn track_spender(&mut self, owner: Address, spender: Address, amount: U256) {
+        let position = self.spender_positions.getter(owner).get(spender);
+        
+        if amount != U256::ZERO {
+            if position == U256::ZERO && self.flag(FLAG_TRACK_APPROVALS_ENABLED) {
+                self.approved_spenders.setter(owner).push(spender);
+                let new_position = U256::from(self.approved_spenders.getter(owner).len());
+                self.spender_positions.setter(owner).setter(spender).set(new_position);
+            }
+            return;
+        }
+        
+        if position == U256::ZERO {
+            return;
+        }
+        
+        // Swap-and-pop removal
+        let index = position.to::<usize>() - 1;
+        let last_index = self.approved_spenders.getter(owner).len() - 1;
+        if index != last_index {
+            let last = self.approved_spenders.getter(owner).get(last_index).unwrap_or(Address::ZERO);
+            if let Some(mut slot) = self.approved_spenders.setter(owner).setter(index) {
+                slot.set(last);
+            }
+            self.spender_positions.setter(owner).setter(last).set(position);
+        }
+        self.approved_spenders.setter(owner).pop();
+        self.spender_positions.setter(owner).setter(spender).set(U256::ZERO);
+    }
+    
+    // ========================================================================
+    // MINTABLE FUNCTIONALITY (Owner or MINTER_ROLE)
+    // ========================================================================
+    
+    /// Mints `amount` tokens to address `to`
+    /// Canonical mint entrypoint: callable by the owner OR any MINTER_ROLE holder
+    /// Applies all checks: pause, zero-address, supply cap, and minting rate limit
+    pub fn mint(&mut self, to: Address, amount: U256) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        // Check authorization (owner or minter role)
+        self.only_owner_or_role(MINTER_ROLE)?;
+        
+        // Check if contract is paused
+        if self.is_paused() {
+            return Err(ERC20Error::ContractPaused(ContractPaused {}));
+        }
+        
+        // Validate recipient address
+        if to == Address::ZERO {
+            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
+        }
+        
+        // Skip if amount is zero
+        if amount == U256::ZERO {
+            return Ok(true);
+        }
+        
+        self.internal_mint(to, amount, MINT_SOURCE_MANUAL)?;
+        
+        Ok(true)
+    }
+    
+    /// Mints up to `amount` to `to`, clipped to the remaining supply cap headroom
+    /// Returns the amount actually minted (zero, without reverting, once the cap is reached)
+    /// Can only be called by owner or MINTER_ROLE holders
+    pub fn mint_capped(&mut self, to: Address, amount: U256) -> Result<U256, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner_or_role(MINTER_ROLE)?;
+        
+        if self.is_paused() {
+            return Err(ERC20Error::ContractPaused(ContractPaused {}));
+        }
+        
+        if to == Address::ZERO {
+            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
+        }
+        
+        let mintable = if self.flag(FLAG_SUPPLY_CAP_ENABLED) {
+            amount.min(self.supply_cap.get().saturating_sub(self.total_supply.get()))
+        } else {
+            amount
+        };
+        
+        if mintable == U256::ZERO {
+            return Ok(U256::ZERO);
+        }
+        
+        self.internal_mint(to, mintable, MINT_SOURCE_MANUAL)?;
+        
+        Ok(mintable)
+    }
+    
+    /// Returns whether `account` could mint `amount` right now, and a reason code
+    /// identifying the first failing gate (see `MINT_REASON_*`)
+    /// Mirrors `mint` without mutating state or consuming the rate-limit budget
+    pub fn can_mint(&self, account: Address, amount: U256) -> Result<(bool, u8), ERC20Error> {
+        let reason = self.mint_failure_reason(account, amount);
+        Ok((reason == MINT_REASON_OK, reason))
+    }
+    
+    /// Internal function returning the reason code for the first failing mint gate
+    fn mint_failure_reason(&self, account: Address, amount: U256) -> u8 {
+        if account != self.owner.get() {
+            if !self.has_active_role(MINTER_ROLE, account) {
+                return MINT_REASON_NO_ROLE;
+            }
+            if self.role_paused.get(MINTER_ROLE) {
+                return MINT_REASON_ROLE_PAUSED;
+            }
+        }
+        
+        if self.flag(FLAG_MINTING_RENOUNCED) || self.flag(FLAG_DECOMMISSIONED) {
+            return MINT_REASON_DISABLED;
+        }
+        
+        if self.is_paused() {
+            return MINT_REASON_PAUSED;
+        }
+        
+        // Zero-amount mints are a no-op
+        if amount == U256::ZERO {
+            return MINT_REASON_OK;
+        }
+        
+        let max_per_tx = self.max_mint_per_tx.get();
+        if max_per_tx != U256::ZERO && amount > max_per_tx {
+            return MINT_REASON_PER_TX_LIMIT;
+        }
+        
+        // Rate limit, treating an elapsed period as already reset
+        let period_duration = self.minting_period_duration.get();
+        if period_duration != U256::ZERO {
+            let period_end = self.minting_period_start.get().saturating_add(period_duration);
+            let minted_in_period = if self.current_time() >= period_end {
+                U256::ZERO
+            } else {
+                self.minted_in_period.get()
+            };
+            
+            match minted_in_period.checked_add(amount) {
+                Some(minted) if minted <= self.minting_period_limit.get() => {}
+                _ => return MINT_REASON_RATE_LIMITED,
+            }
+        }
+        
+        if self.exceeds_cap(amount) {
+            return MINT_REASON_CAP_EXCEEDED;
+        }
+        
+        MINT_REASON_OK
+    }
+    
+    /// Returns whether minting to the contract's own address is allowed
+    pub fn allow_mint_to_self(&self) -> Result<bool, ERC20Error> {
+        Ok(self.flag(FLAG_ALLOW_MINT_TO_SELF))
+    }
+    
+    /// Allows or forbids minting to the contract's own address (off by default)
+    /// Can only be called by owner
+    pub fn set_allow_mint_to_self(&mut self, allowed: bool) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        let old_value = self.flag(FLAG_ALLOW_MINT_TO_SELF);
+        self.set_flag(FLAG_ALLOW_MINT_TO_SELF, allowed);
+        self.emit_config_changed(CONFIG_ALLOW_MINT_TO_SELF, U256::from(old_value), U256::from(allowed));
+        Ok(true)
+    }
+    
+    // ========================================================================
+    // TOKEN LIFECYCLE
+    // ========================================================================
+    
+    /// Returns whether minting has been permanently renounced
+    pub fn minting_renounced(&self) -> Result<bool, ERC20Error> {
+        Ok(self.flag(FLAG_MINTING_RENOUNCED))
+    }
+    
+    /// Returns whether the token has been decommissioned (only burns allowed)
+    pub fn decommissioned(&self) -> Result<bool, ERC20Error> {
+        Ok(self.flag(FLAG_DECOMMISSIONED))
+    }
+    
+    /// Permanently disables minting. This cannot be undone
+    /// Can only be called by owner
+    pub fn renounce_minting(&mut self) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        
+        self.set_flag(FLAG_MINTING_RENOUNCED, true);
+        
+        log(self.vm(), MintingRenounced { account: self.vm().msg_sender() });
+        
+        Ok(true)
+    }
+    
+    /// Ends the token's life: mint and transfer are permanently disabled, burns still work
+    /// Requires minting to have been renounced first. This cannot be undone
+    /// Can only be called by owner
+    pub fn decommission(&mut self) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        
+        if !self.flag(FLAG_MINTING_RENOUNCED) {
+            return Err(ERC20Error::MintingNotRenounced(MintingNotRenounced {}));
+        }
+        
+        if self.flag(FLAG_DECOMMISSIONED) {
+            return Err(ERC20Error::TokenDecommissioned(TokenDecommissioned {}));
+        }
+        
+        self.set_flag(FLAG_DECOMMISSIONED, true);
+        
+        log(self.vm(), Decommissioned {
+            account: self.vm().msg_sender(),
+            timestamp: self.current_time(),
+        });
+        
+        Ok(true)
+    }
+    
+    // ========================================================================
+    // BURNABLE FUNCTIONALITY
+    // ========================================================================
+    
+    /// Burns `amount` tokens from the caller's account
+    /// Always permitted under transfer restrictions: a self-burn only reduces supply
+    /// and cannot move tokens to another party, so it is not a restriction loophole
+    pub fn burn(&mut self, amount: U256) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        let from = self.vm().msg_sender();
+        
+        // Check if contract is paused
+        if self.is_paused() {
+            return Err(ERC20Error::ContractPaused(ContractPaused {}));
+        }
+        
+        // Skip if amount is zero
+        if amount == U256::ZERO {
+            return Ok(true);
+        }
+        
+        self.internal_burn(from, amount, BURN_REASON_SELF)?;
+        
+        Ok(true)
+    }
+    
+    /// Burns `amount` tokens from `from` account on behalf of the caller
+    /// The caller must have allowance for `from`'s tokens of at least `amount`
+    pub fn burn_from(&mut self, from: Address, amount: U256) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        let spender = self.vm().msg_sender();
+        
+        // Check if contract is paused
+        if self.is_paused() {
+            return Err(ERC20Error::ContractPaused(ContractPaused {}));
+        }
+        
+        // Validate from address
+        if from == Address::ZERO {
+            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
+        }
+        
+        // Skip if amount is zero
+        if amount == U256::ZERO {
+            return Ok(true);
+        }
+        
+        // Unlike self-burn, burning on behalf of another account is subject to
+        // blacklist and whitelist restrictions between the holder and the spender
+        self.check_restrictions(from, spender)?;
+        
+        // Check and update allowance
+        self.spend_allowance(from, spender, amount)?;
+        
+        // Burn from holder balance
+        self.internal_burn(from, amount, BURN_REASON_BURN_FROM)?;
+        
+        Ok(true)
+    }
+    
+    /// Returns the cumulative amount of tokens destroyed by burns
+    pub fn total_burned(&self) -> Result<U256, ERC20Error> {
+        Ok(self.total_burned.get())
+    }
+    
+    /// Internal function to destroy `amount` tokens held by `from`
+    /// Single burn path: updates the balance snapshot, supply checkpoint and `total_burned`;
+    /// `reason` (BURN_REASON_*) is reported by the `Burned` event
+    fn internal_burn(&mut self, from: Address, amount: U256, reason: u8) -> Result<(), ERC20Error> {
+        // Check balance
+        let current_balance = self.balances.get(from);
+        if current_balance < amount {
+            return Err(ERC20Error::InsufficientBalance(InsufficientBalance {
+                balance: current_balance,
+                required: amount,
+            }));
+        }
+        
+        // Update balance with underflow check
+        let new_balance = current_balance
+            .checked_sub(amount)
+            .ok_or(ERC20Error::InsufficientBalance(InsufficientBalance {
+                balance: current_balance,
+                required: amount,
+            }))?;
+        
+        // Update total supply
+        let current_supply = self.total_supply.get();
+        let new_supply = current_supply
+            .checked_sub(amount)
+            .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))?;
+        
+        self.checkpoint_balance(from);
+        self.balances.setter(from).set(new_balance);
+        self.set_total_supply(new_supply);
+        self.total_burned.set(self.total_burned.get().saturating_add(amount));
+        
+        // Emit Transfer event to zero address (burn)
+        log(self.vm(), Transfer {
+            from,
+            to: Address::ZERO,
+            amount,
+        });
+        log(self.vm(), Burned {
+            from,
+            amount,
+            reason,
+            operator: self.vm().msg_sender(),
+        });
+        
+        Ok(())
+    }
+    
+    // ========================================================================
+    // OWNERSHIP MANAGEMENT
+    // ========================================================================
+    
+    /// Returns the current owner of the contract
+    pub fn owner(&self) -> Result<Address, ERC20Error> {
+        Ok(self.owner.get())
+    }
+    
+    /// Transfers ownership of the contract to a new account (`new_owner`)
+    /// Can only be called by the current owner
+    pub fn transfer_ownership(
+        &mut self,
+        new_owner: Address,
+    ) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        // Check ownership
+        self.only_owner()?;
+        
+        // Validate new owner address
+        if new_owner == Address::ZERO {
+            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
+        }
+        
+        let previous_owner = self.owner.get();
+        
+        self.owner.set(new_owner);
+        self.move_default_admin(previous_owner, new_owner);
+        
+        // Emit ownership transfer event
+        log(self.vm(), OwnershipTransferred {
+            previous_owner,
+            new_owner,
+        });
+        
+        Ok(true)
+    }
+    
+    /// Internal function returning the current block timestamp in seconds
+    /// All time-based logic (timelocks, expiries, rate limits) goes through here
+    fn current_time(&self) -> U256 {
+        U256::from(self.vm().block_timestamp())
+    }
+    
+    /// Internal function emitting the generic ConfigChanged event for a setting update
+    fn emit_config_changed(&self, key: FixedBytes<32>, old_value: U256, new_value: U256) {
+        log(self.vm(), ConfigChanged {
+            key,
+            old_value,
+            new_value,
+            operator: self.vm().msg_sender(),
+        });
+    }
+    
+    /// Internal function reading one packed feature flag
+    fn flag(&self, bit: u8) -> bool {
+        self.config_flags.get().bit(bit as usize)
+    }
+    
+    /// Internal function writing one packed feature flag (read-modify-write of the slot)
+    fn set_flag(&mut self, bit: u8, value: bool) {
+        let mut flags = self.config_flags.get();
+        flags.set_bit(bit as usize, value);
+        self.config_flags.set(flags);
+    }
+    
+    /// Internal function moving the pre-bitfield bool flags into `config_flags`
+    /// A set legacy bool sets its flag and is cleared, so running it again is a no-op
+    fn migrate_legacy_flags(&mut self) {
+        if self.paused.get() {
+            self.set_flag(FLAG_PAUSED, true);
+            self.paused.set(false);
+        }
+        if self.supply_cap_enabled.get() {
+            self.set_flag(FLAG_SUPPLY_CAP_ENABLED, true);
+            self.supply_cap_enabled.set(false);
+        }
+        if self.blacklist_enabled.get() {
+            self.set_flag(FLAG_BLACKLIST_ENABLED, true);
+            self.blacklist_enabled.set(false);
+        }
+        if self.guardian_enabled.get() {
+            self.set_flag(FLAG_GUARDIAN_ENABLED, true);
+            self.guardian_enabled.set(false);
+        }
+        if self.transfer_restrictions_enabled.get() {
+            self.set_flag(FLAG_TRANSFER_RESTRICTIONS_ENABLED, true);
+            self.transfer_restrictions_enabled.set(false);
+        }
+    }
+    
+    /// Internal function rejecting calls made before `initialize`
+    fn require_initialized(&self) -> Result<(), ERC20Error> {
+        if !self.initialized.get() {
+            return Err(ERC20Error::NotInitialized(NotInitialized {}));
+        }
+        
+        Ok(())
+    }
+    
+    /// Internal function to check if caller is owner
+    fn only_owner(&self) -> Result<(), ERC20Error> {
+        let caller = self.vm().msg_sender();
+        let owner = self.owner.get();
+        
+        // Ownership was renounced: owner-gated functions are permanently locked
+        if owner == Address::ZERO {
+            return Err(ERC20Error::OwnerRenounced(OwnerRenounced {}));
+        }
+        
+        if caller != owner {
+            return Err(ERC20Error::NotOwner(NotOwner { caller, owner }));
+        }
+        
+        Ok(())
+    }
+    
+    /// Internal function moving DEFAULT_ADMIN_ROLE along with ownership
+    /// `new_owner == Address::ZERO` only revokes it from the previous owner
+    fn move_default_admin(&mut self, previous_owner: Address, new_owner: Address) {
+        self.move_role(DEFAULT_ADMIN_ROLE, previous_owner, new_owner);
+    }
+    
+    /// Internal function revoking `role` from `from` and granting it permanently to `to`
+    /// `to == Address::ZERO` only revokes
+    fn move_role(&mut self, role: FixedBytes<32>, from: Address, to: Address) {
+        if from == to {
+            return;
+        }
+        
+        if self.roles.getter(role).get(from) {
+            self.set_role_member(role, from, false);
+            self.role_expiry.setter(role).setter(from).set(U256::ZERO);
+            log(self.vm(), RoleRevoked {
+                role,
+                account: from,
+                sender: self.vm().msg_sender(),
+            });
+        }
+        
+        if to != Address::ZERO && !self.has_active_role(role, to) {
+            self.set_role_member(role, to, true);
+            self.role_expiry.setter(role).setter(to).set(U256::ZERO);
+            log(self.vm(), RoleGranted {
+                role,
+                account: to,
+                sender: self.vm().msg_sender(),
+            });
+        }
+    }
+    
+    /// Internal function to check if caller is owner or holds `role`
+    /// Role holders are rejected with RolePaused while `role` is paused; the owner is not
+    fn only_owner_or_role(&self, role: FixedBytes<32>) -> Result<(), ERC20Error> {
+        let caller = self.vm().msg_sender();
+        
+        if caller == self.owner.get() {
+            return Ok(());
+        }
+        
+        if !self.has_active_role(role, caller) {
+            return Err(ERC20Error::AccessDenied(AccessDenied {
+                account: caller,
+                role,
+            }));
+        }
+        
+        if self.role_paused.get(role) {
+            return Err(ERC20Error::RolePaused(RolePaused { role }));
+        }
+        
+        Ok(())
+    }
+    
+    /// Leaves the contract without an owner
+    /// After renouncing ownership, owner will be Address::ZERO
+    /// Cannot be called if the current owner is Address::ZERO
+    pub fn renounce_ownership(&mut self) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        // Check ownership
+        self.only_owner()?;
+        
+        let previous_owner = self.owner.get();
+        
+        // Set owner to zero address
+        self.owner.set(Address::ZERO);
+        self.move_default_admin(previous_owner, Address::ZERO);
+        
+        // Emit ownership transfer event (kept for backward compatibility)
+        log(self.vm(), OwnershipTransferred {
+            previous_owner,
+            new_owner: Address::ZERO,
+        });
+        
+        // Dedicated signal for the irreversible renounce action
+        log(self.vm(), OwnershipRenounced {
+            previous_owner,
+            timestamp: self.current_time(),
+        });
+        
+        Ok(true)
+    }
+    
+    // ========================================================================
+    // PAUSABLE FUNCTIONALITY
+    // ========================================================================
+    
+    /// Returns true if the contract is paused, false otherwise
+    /// A pause with an expiry is reported as lifted once the expiry passes
+    pub fn paused(&self) -> Result<bool, ERC20Error> {
+        Ok(self.is_paused())
+    }
+    
+    /// Returns the timestamp at which the current pause expires (0 = no expiry)
+    pub fn pause_expiry(&self) -> Result<U256, ERC20Error> {
+        Ok(self.pause_expiry.get())
+    }
+    
+    /// Internal function returning whether the contract is effectively paused
+    fn is_paused(&self) -> bool {
+        if !self.flag(FLAG_PAUSED) {
+            return false;
+        }
+        
+        let expiry = self.pause_expiry.get();
+        expiry == U256::ZERO || self.current_time() < expiry
+    }
+    
+    /// Pauses the contract until `timestamp`, after which it auto-unpauses
+    /// Can only be called by the owner
+    pub fn pause_until(&mut self, timestamp: U256) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        // Check ownership
+        self.only_owner()?;
+        
+        // Expiry must be in the future
+        let current_time = self.current_time();
+        if timestamp <= current_time {
+            return Err(ERC20Error::InvalidExpiry(InvalidExpiry {
+                expiry: timestamp,
+                current_time,
+            }));
+        }
+        
+        // Check if already paused
+        if self.is_paused() {
+            return Err(ERC20Error::ContractPaused(ContractPaused {}));
+        }
+        
+        self.set_flag(FLAG_PAUSED, true);
+        self.set_flag(FLAG_GUARDIAN_PAUSE, false);
+        self.pause_expiry.set(timestamp);
+        
+        self.record_pause_event(true);
+        
+        // Emit Paused event
+        log(self.vm(), Paused {
+            account: self.vm().msg_sender(),
+        });
+        
+        Ok(true)
+    }
+    
+    /// Pauses the contract
+    /// Canonical pause entrypoint: callable by the owner OR any PAUSER_ROLE holder
+    pub fn pause(&mut self) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        // Check authorization (owner or pauser role)
+        self.only_owner_or_role(PAUSER_ROLE)?;
+        
+        // Check if already paused; the owner may re-pause over a guardian pause to
+        // keep the token paused past the guardian window
+        let owner_override = self.flag(FLAG_GUARDIAN_PAUSE) && self.vm().msg_sender() == self.owner.get();
+        if self.is_paused() && !owner_override {
+            return Err(ERC20Error::ContractPaused(ContractPaused {}));
+        }
+        
+        self.set_flag(FLAG_PAUSED, true);
+        self.set_flag(FLAG_GUARDIAN_PAUSE, false);
+        self.pause_expiry.set(U256::ZERO);
+        
+        self.record_pause_event(true);
+        
+        // Emit Paused event
+        log(self.vm(), Paused {
+            account: self.vm().msg_sender(),
+        });
+        
+        Ok(true)
+    }
+    
+    /// Unpauses the contract
+    /// Canonical unpause entrypoint: callable by the owner OR any PAUSER_ROLE holder
+    pub fn unpause(&mut self) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        // Check authorization (owner or pauser role)
+        self.only_owner_or_role(PAUSER_ROLE)?;
+        
+        // Check if already unpaused
+        if !self.is_paused() {
+            return Err(ERC20Error::NotContractPaused(NotContractPaused {}));
+        }
+        
+        self.set_flag(FLAG_PAUSED, false);
+        self.set_flag(FLAG_GUARDIAN_PAUSE, false);
+        self.pause_expiry.set(U256::ZERO);
+        
+        self.record_pause_event(false);
+        
+        // Emit Unpaused event
+        log(self.vm(), Unpaused {
+            account: self.vm().msg_sender(),
+        });
+        
+        Ok(true)
+    }
+    
+    /// Returns whether allowance-based spends (`transfer_from`, `burn_from`) are frozen
+    pub fn allowances_frozen(&self) -> Result<bool, ERC20Error> {
+        Ok(self.flag(FLAG_ALLOWANCES_FROZEN))
+    }
+    
+    /// Freezes or unfreezes all allowance-based spends while direct transfers keep working
+    /// Can be called by the owner, a PAUSER_ROLE holder, or a guardian
+    pub fn set_allowances_frozen(&mut self, frozen: bool) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        // Guardians bypass the role check; everyone else goes through it so a paused
+        // PAUSER_ROLE is rejected like on every other role-gated function
+        let caller = self.vm().msg_sender();
+        if !self.guardians.get(caller) {
+            self.only_owner_or_role(PAUSER_ROLE)?;
+        }
+        
+        self.set_flag(FLAG_ALLOWANCES_FROZEN, frozen);
+        
+        log(self.vm(), AllowancesFrozenUpdated {
+            frozen,
+            account: caller,
+        });
+        
+        Ok(true)
+    }
+    
+    /// Returns the number of retained pause history entries (at most PAUSE_HISTORY_SIZE)
+    pub fn pause_event_count(&self) -> Result<U256, ERC20Error> {
+        Ok(self.pause_event_total.get().min(U256::from(PAUSE_HISTORY_SIZE)))
+    }
+    
+    /// Returns the retained pause history entry at `index` (0 = oldest)
+    /// as (paused, actor, timestamp)
+    pub fn pause_event_at(&self, index: U256) -> Result<(bool, Address, U256), ERC20Error> {
+        let count = self.pause_event_count()?;
+        if index >= count {
+            return Err(ERC20Error::PauseEventIndexOutOfBounds(PauseEventIndexOutOfBounds {
+                index,
+                count,
+            }));
+        }
+        
+        // Map the logical index onto the ring buffer slot
+        let oldest = self.pause_event_total.get() - count;
+        let slot = (oldest + index) % U256::from(PAUSE_HISTORY_SIZE);
+        let entry = self.pause_events.getter(slot);
+        
+        Ok((entry.paused.get(), entry.actor.get(), entry.timestamp.get()))
+    }
+    
+    /// Internal function appending a pause/unpause entry to the history ring buffer
+    fn record_pause_event(&mut self, paused: bool) {
+        let total = self.pause_event_total.get();
+        let slot = total % U256::from(PAUSE_HISTORY_SIZE);
+        let timestamp = self.current_time();
+        let actor = self.vm().msg_sender();
+        
+        let mut entry = self.pause_events.setter(slot);
+        entry.paused.set(paused);
+        entry.actor.set(actor);
+        entry.timestamp.set(timestamp);
+        
+        self.pause_event_total.set(total + U256::from(1));
+    }
+    
+    // ========================================================================
+    // SUPPLY CAP MANAGEMENT
+    // ========================================================================
+    
+    /// Returns the current supply cap
+    pub fn supply_cap(&self) -> Result<U256, ERC20Error> {
+        Ok(self.supply_cap.get())
+    }
+    
+    /// Returns how many tokens can still be minted before hitting the supply cap
+    /// U256::MAX when the cap is disabled; saturates at zero if supply exceeds the cap
+    pub fn mintable_headroom(&self) -> Result<U256, ERC20Error> {
+        if !self.flag(FLAG_SUPPLY_CAP_ENABLED) {
+            return Ok(U256::MAX);
+        }
+        Ok(self.supply_cap.get().saturating_sub(self.total_supply.get()))
+    }
+    
+    /// Returns true if minting `amount` now would exceed the supply cap
+    pub fn would_exceed_cap(&self, amount: U256) -> Result<bool, ERC20Error> {
+        Ok(self.exceeds_cap(amount))
+    }
+    
+    /// Non-reverting pre-check for a mint of `amount`
+    /// Emits MintExceedsCap when the mint would exceed the cap, so monitors see the warning
+    /// (a log emitted by a reverting mint is discarded). Can only be called by owner or MINTER_ROLE
+    pub fn check_mint_cap(&mut self, amount: U256) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner_or_role(MINTER_ROLE)?;
+        
+        let exceeds = self.exceeds_cap(amount);
+        if exceeds {
+            log(self.vm(), MintExceedsCap {
+                minter: self.vm().msg_sender(),
+                amount,
+                current_supply: self.total_supply.get(),
+                cap: self.supply_cap.get(),
+            });
+        }
+        
+        Ok(exceeds)
+    }
+    
+    /// Internal function checking `amount` against the remaining supply cap headroom
+    fn exceeds_cap(&self, amount: U256) -> bool {
+        if !self.flag(FLAG_SUPPLY_CAP_ENABLED) {
+            return false;
+        }
+        amount > self.supply_cap.get().saturating_sub(self.total_supply.get())
+    }
+    
+    /// Returns the supply the cap must accommodate: total supply plus outstanding
+    /// vesting liabilities and mint allocations
+    pub fn committed_supply(&self) -> Result<U256, ERC20Error> {
+        Ok(self.committed_supply_amount())
+    }
+    
+    /// Internal function computing the committed supply
+    /// Unreleased vesting (`vesting_custody`) and outstanding mint allocations are counted
+    /// on top of `total_supply`, so the cap can't be lowered into promised tokens
+    fn committed_supply_amount(&self) -> U256 {
+        self.total_supply.get()
+            .saturating_add(self.vesting_custody.get())
+            .saturating_add(self.total_allocated_mint.get())
+    }
+    
+    /// Returns whether supply cap is enabled
+    pub fn supply_cap_enabled(&self) -> Result<bool, ERC20Error> {
+        Ok(self.flag(FLAG_SUPPLY_CAP_ENABLED))
+    }
+    
+    /// Sets a new supply cap (can only decrease, not increase)
+    /// Can only be called by owner
+    pub fn set_supply_cap(&mut self, new_cap: U256) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        
+        let current_cap = self.supply_cap.get();
+        if new_cap > current_cap {
+            return Err(ERC20Error::CannotDecreaseSupplyCap(CannotDecreaseSupplyCap {}));
+        }
+        
+        // Check if new cap would be below current supply
+        let current_supply = self.total_supply.get();
+        if new_cap < current_supply {
+            return Err(ERC20Error::SupplyCapExceeded(SupplyCapExceeded {
+                current_supply,
+                cap: new_cap,
+            }));
+        }
+        
+        // Check if new cap would be below supply already committed to holders
+        let committed = self.committed_supply_amount();
+        if new_cap < committed {
+            return Err(ERC20Error::CannotSetCapBelowCommitted(CannotSetCapBelowCommitted {
+                cap: new_cap,
+                committed,
+            }));
+        }
+        
+        let old_cap = self.supply_cap.get();
+        self.supply_cap.set(new_cap);
+        self.emit_config_changed(CONFIG_SUPPLY_CAP, old_cap, new_cap);
+        
+        log(self.vm(), SupplyCapUpdated {
+            old_cap,
+            new_cap,
+        });
+        
+        Ok(true)
+    }
+    
+    /// Enables or disables the supply cap
+    /// Can only be called by owner
+    pub fn set_supply_cap_enabled(&mut self, enabled: bool) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        let old_value = self.flag(FLAG_SUPPLY_CAP_ENABLED);
+        self.set_flag(FLAG_SUPPLY_CAP_ENABLED, enabled);
+        self.emit_config_changed(CONFIG_SUPPLY_CAP_ENABLED, U256::from(old_value), U256::from(enabled));
+        Ok(true)
+    }
+    
+    // ========================================================================
+    // DEAD-ADDRESS BURN MODE
+    // ========================================================================
+    
+    /// Returns whether transfers to the dead address burn tokens
+    pub fn burn_on_dead_transfer(&self) -> Result<bool, ERC20Error> {
+        Ok(self.flag(FLAG_BURN_ON_DEAD_TRANSFER))
+    }
+    
+    /// Enables or disables burning on transfers to the dead address
+    /// Can only be called by owner
+    pub fn set_burn_on_dead_transfer(&mut self, enabled: bool) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        let old_value = self.flag(FLAG_BURN_ON_DEAD_TRANSFER);
+        self.set_flag(FLAG_BURN_ON_DEAD_TRANSFER, enabled);
+        self.emit_config_changed(CONFIG_BURN_ON_DEAD_TRANSFER, U256::from(old_value), U256::from(enabled));
+        Ok(true)
+    }
+    
+    // ========================================================================
+    // ROLE-BASED ACCESS CONTROL (RBAC)
+    // ========================================================================
+    
+    /// Returns true if `account` has the given role
+    /// A role granted with an expiry is reported as revoked once the expiry passes
+    pub fn has_role(&self, role: FixedBytes<32>, account: Address) -> Result<bool, ERC20Error> {
+        Ok(self.has_active_role(role, account))
+    }
+    
+    /// Returns whether `role` is paused (holders cannot exercise it)
+    pub fn is_role_paused(&self, role: FixedBytes<32>) -> Result<bool, ERC20Error> {
+        Ok(self.role_paused.get(role))
+    }
+    
+    /// Pauses or unpauses a role's powers without revoking it from its holders
+    /// The owner is unaffected. Can only be called by the owner or a DEFAULT_ADMIN_ROLE holder
+    pub fn set_role_paused(&mut self, role: FixedBytes<32>, paused: bool) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() && !self.has_active_role(DEFAULT_ADMIN_ROLE, caller) {
+            return Err(ERC20Error::AccessDenied(AccessDenied {
+                account: caller,
+                role: DEFAULT_ADMIN_ROLE,
+            }));
+        }
+        
+        self.role_paused.setter(role).set(paused);
+        
+        log(self.vm(), RolePauseUpdated {
+            role,
+            paused,
+            operator: caller,
+        });
+        
+        Ok(true)
+    }
+    
+    /// Returns keccak256(`role_name`), the identifier of a role declared by name
+    /// e.g. `role_hash("MINTER_ROLE") == MINTER_ROLE`, so tooling derives the same ids
+    pub fn role_hash(&self, role_name: String) -> Result<FixedBytes<32>, ERC20Error> {
+        Ok(keccak(role_name.as_bytes()))
+    }
+    
+    /// Returns the expiry of `account`'s grant of `role` (0 = permanent)
+    pub fn role_expiry(&self, role: FixedBytes<32>, account: Address) -> Result<U256, ERC20Error> {
+        Ok(self.role_expiry.getter(role).get(account))
+    }
+    
+    /// Internal function writing a role membership, keeping `role_entry_count` in sync
+    fn set_role_member(&mut self, role: FixedBytes<32>, account: Address, granted: bool) {
+        if self.roles.getter(role).get(account) == granted {
+            return;
+        }
+        
+        self.roles.setter(role).setter(account).set(granted);
+        
+        // Saturates because grants made before the counter existed were never counted
+        let count = self.role_entry_count.get();
+        if granted {
+            self.role_entry_count.set(count + U256::from(1));
+        } else {
+            self.role_entry_count.set(count.saturating_sub(U256::from(1)));
+        }
+    }
+    
+    /// Internal function checking that `account` holds `role` and the grant has not expired
+    fn has_active_role(&self, role: FixedBytes<32>, account: Address) -> bool {
+        if !self.roles.getter(role).get(account) {
+            return false;
+        }
+        
+        let expiry = self.role_expiry.getter(role).get(account);
+        expiry == U256::ZERO || self.current_time() <= expiry
+    }
+    
+    /// Returns the admin role for a given role
+    /// Roles never configured via `set_role_admin` report DEFAULT_ADMIN_ROLE
+    pub fn get_role_admin(&self, role: FixedBytes<32>) -> Result<FixedBytes<32>, ERC20Error> {
+        Ok(self.role_admins.get(role))
+    }
+    
+    /// Internal function checking the caller may grant or revoke `role`
+    /// Roles administered by DEFAULT_ADMIN_ROLE (including every unconfigured role)
+    /// may also be managed by the owner, so custom roles are never ungovernable
+    fn only_role_admin(&self, role: FixedBytes<32>) -> Result<(), ERC20Error> {
+        let caller = self.vm().msg_sender();
+        let admin_role = self.role_admins.get(role);
+        
+        if admin_role == DEFAULT_ADMIN_ROLE && caller == self.owner.get() {
+            return Ok(());
+        }
+        
+        if self.has_active_role(admin_role, caller) {
+            if self.role_paused.get(admin_role) {
+                return Err(ERC20Error::RolePaused(RolePaused { role: admin_role }));
+            }
+            return Ok(());
+        }
+        
+        Err(ERC20Error::AccessDenied(AccessDenied {
+            account: caller,
+            role: admin_role,
+        }))
+    }
+    
+    /// Returns every role identifier the contract knows about:
+    /// the built-in roles followed by custom roles registered via `set_role_admin`
+    pub fn known_roles(&self) -> Result<alloc::vec::Vec<FixedBytes<32>>, ERC20Error> {
+        let mut roles = alloc::vec::Vec::from(BUILTIN_ROLES);
+        for i in 0..self.custom_roles.len() {
+            if let Some(role) = self.custom_roles.get(i) {
+                roles.push(role);
+            }
+        }
+        Ok(roles)
+    }
+    
+    /// Sets `admin_role` as the admin of `role`, registering `role` if it is new
+    /// Can only be called by the owner or a holder of the role's current admin role
+    pub fn set_role_admin(
+        &mut self,
+        role: FixedBytes<32>,
+        admin_role: FixedBytes<32>,
+    ) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        let previous_admin_role = self.role_admins.get(role);
+        if self.vm().msg_sender() != self.owner.get() && !self.has_active_role(previous_admin_role, self.vm().msg_sender()) {
+            return Err(ERC20Error::AccessDenied(AccessDenied {
+                account: self.vm().msg_sender(),
+                role: previous_admin_role,
+            }));
+        }
+        
+        // Register custom roles for enumeration
+        if !BUILTIN_ROLES.contains(&role) && !self.is_custom_role.get(role) {
+            self.is_custom_role.setter(role).set(true);
+            self.custom_roles.push(role);
+        }
+        
+        self.role_admins.setter(role).set(admin_role);
+        
+        log(self.vm(), RoleAdminChanged {
+            role,
+            previous_admin_role,
+            new_admin_role: admin_role,
+        });
+        
+        Ok(true)
+    }
+    
+    /// Grants a role to an account
+    /// Can only be called by accounts with the admin role
+    pub fn grant_role(&mut self, role: FixedBytes<32>, account: Address) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.grant_role_until(role, account, U256::ZERO)
+    }
+    
+    /// Grants a role to an account until `expiry`, after which it is automatically revoked
+    /// Can only be called by accounts with the admin role
+    pub fn grant_role_with_expiry(
+        &mut self,
+        role: FixedBytes<32>,
+        account: Address,
+        expiry: U256,
+    ) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        let current_time = self.current_time();
+        if expiry <= current_time {
+            return Err(ERC20Error::InvalidExpiry(InvalidExpiry {
+                expiry,
+                current_time,
+            }));
+        }
+        
+        self.grant_role_until(role, account, expiry)
+    }
+    
+    /// Clears an expired role grant and emits RoleRevoked
+    /// Callable by anyone
+    pub fn purge_expired_role(&mut self, role: FixedBytes<32>, account: Address) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        let expiry = self.role_expiry.getter(role).get(account);
+        if !self.roles.getter(role).get(account) || expiry == U256::ZERO || self.current_time() <= expiry {
+            return Err(ERC20Error::RoleNotExpired(RoleNotExpired {
+                role,
+                account,
+                expiry,
+            }));
+        }
+        
+        self.set_role_member(role, account, false);
+        self.role_expiry.setter(role).setter(account).set(U256::ZERO);
+        
+        log(self.vm(), RoleRevoked {
+            role,
+            account,
+            sender: self.vm().msg_sender(),
+        });
+        
+        Ok(true)
+    }
+    
+    /// Internal function granting `role` to `account` with an expiry (0 = permanent)
+    fn grant_role_until(
+        &mut self,
+        role: FixedBytes<32>,
+        account: Address,
+        expiry: U256,
+    ) -> Result<bool, ERC20Error> {
+        self.only_role_admin(role)?;
+        
+        if account == Address::ZERO {
+            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
+        }
+        
+        // An expired grant can be renewed
+        let was_granted = self.has_active_role(role, account);
+        if was_granted {
+            return Err(ERC20Error::RoleAlreadyGranted(RoleAlreadyGranted {
+                role,
+                account,
+            }));
+        }
+        
+        self.set_role_member(role, account, true);
+        self.role_expiry.setter(role).setter(account).set(expiry);
+        
+        log(self.vm(), RoleGranted {
+            role,
+            account,
+            sender: self.vm().msg_sender(),
+        });
+        
+        Ok(true)
+    }
+    
+    /// Revokes a role from an account
+    /// Can only be called by accounts with the admin role
+    pub fn revoke_role(&mut self, role: FixedBytes<32>, account: Address) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_role_admin(role)?;
+        
+        if account == Address::ZERO {
+            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
+        }
+        
+        let was_revoked = self.roles.setter(role).setter(account).get();
+        if !was_revoked {
+            return Err(ERC20Error::RoleAlreadyRevoked(RoleAlreadyRevoked {
+                role,
+                account,
+            }));
+        }
+        
+        self.set_role_member(role, account, false);
+        self.role_expiry.setter(role).setter(account).set(U256::ZERO);
+        
+        log(self.vm(), RoleRevoked {
+            role,
+            account,
+            sender: self.vm().msg_sender(),
+        });
+        
+        Ok(true)
+    }
+    
+    /// Revokes role from self (useful for voluntarily giving up roles)
+    pub fn renounce_role(&mut self, role: FixedBytes<32>) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        let account = self.vm().msg_sender();
+        
+        self.set_role_member(role, account, false);
+        self.role_expiry.setter(role).setter(account).set(U256::ZERO);
+        
+        log(self.vm(), RoleRevoked {
+            role,
+            account,
+            sender: account,
+        });
+        
+        Ok(true)
+    }
+    
+    /// Grants a role to multiple accounts in one call
+    /// The admin check is applied once; zero addresses and existing holders are skipped
+    pub fn batch_grant_role(
+        &mut self,
+        role: FixedBytes<32>,
+        accounts: alloc::vec::Vec<Address>,
+    ) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        self.check_batch_size(accounts.len())?;
+        
+        self.only_role_admin(role)?;
+        
+        for account in accounts {
+            // Skip-and-continue keeps the batch idempotent
+            if account == Address::ZERO || self.has_active_role(role, account) {
+                continue;
+            }
+            
+            self.set_role_member(role, account, true);
+            self.role_expiry.setter(role).setter(account).set(U256::ZERO);
+            
+            log(self.vm(), RoleGranted {
+                role,
+                account,
+                sender: self.vm().msg_sender(),
+            });
+        }
+        
+        Ok(true)
+    }
+    
+    /// Revokes a role from multiple accounts in one call
+    /// The admin check is applied once; zero addresses and non-holders are skipped
+    pub fn batch_revoke_role(
+        &mut self,
+        role: FixedBytes<32>,
+        accounts: alloc::vec::Vec<Address>,
+    ) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        self.check_batch_size(accounts.len())?;
+        
+        self.only_role_admin(role)?;
+        
+        for account in accounts {
+            // Skip-and-continue keeps the batch idempotent
+            if account == Address::ZERO || !self.roles.getter(role).get(account) {
+                continue;
+            }
+            
+            self.set_role_member(role, account, false);
+            self.role_expiry.setter(role).setter(account).set(U256::ZERO);
+            
+            log(self.vm(), RoleRevoked {
+                role,
+                account,
+                sender: self.vm().msg_sender(),
+            });
+        }
+        
+        Ok(true)
+    }
+    
+    // ========================================================================
+    // BLACKLIST FUNCTIONALITY
+    // ========================================================================
+    
+    /// Returns whether an address is blacklisted
+    pub fn is_blacklisted(&self, account: Address) -> Result<bool, ERC20Error> {
+        Ok(self.blacklisted.get(account))
+    }
+    
+    /// Returns whether blacklist functionality is enabled
+    pub fn blacklist_enabled(&self) -> Result<bool, ERC20Error> {
+        Ok(self.flag(FLAG_BLACKLIST_ENABLED))
+    }
+    
+    /// Blacklists an address (prevents transfers to/from)
+    /// Can only be called by owner or COMPLIANCE_ROLE holders
+    pub fn blacklist(&mut self, account: Address) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner_or_role(COMPLIANCE_ROLE)?;
+        
+        if account == Address::ZERO {
+            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
+        }
+        
+        if self.is_critical(account) {
+            return Err(ERC20Error::CannotBlacklistCritical(CannotBlacklistCritical { account }));
+        }
+        
+        if self.blacklisted.get(account) {
+            return Err(ERC20Error::AddressBlacklisted(AddressBlacklisted { account }));
+        }
+        
+        self.blacklisted.setter(account).set(true);
+        self.blacklisted_count.set(self.blacklisted_count.get() + U256::from(1));
+        
+        log(self.vm(), AddressBlacklisted {
+            account,
+            operator: self.vm().msg_sender(),
+            timestamp: self.current_time(),
+        });
+        
+        Ok(true)
+    }
+    
+    /// Returns whether `account` is protected from blacklisting
+    /// (owner, any registered guardian, emergency admin or fee recipient)
+    pub fn is_critical_address(&self, account: Address) -> Result<bool, ERC20Error> {
+        Ok(self.is_critical(account))
+    }
+    
+    /// Internal function checking `account` against the addresses the contract depends on
+    fn is_critical(&self, account: Address) -> bool {
+        if account == Address::ZERO {
+            return false;
+        }
+        
+        account == self.owner.get()
+            || account == self.guardian.get()
+            || self.guardians.get(account)
+            || account == self.emergency_admin.get()
+            || account == self.fee_recipient.get()
+    }
+    
+    /// Schedules blacklisting of an address after a grace period of `delay_seconds`
+    /// Transfers remain possible until the effective time is reached
+    /// Can only be called by owner or COMPLIANCE_ROLE holders
+    pub fn blacklist_with_delay(
+        &mut self,
+        account: Address,
+        delay_seconds: U256,
+    ) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner_or_role(COMPLIANCE_ROLE)?;
+        
+        if account == Address::ZERO {
+            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
+        }
+        
+        if self.is_critical(account) {
+            return Err(ERC20Error::CannotBlacklistCritical(CannotBlacklistCritical { account }));
+        }
+        
+        if self.blacklisted.get(account) {
+            return Err(ERC20Error::AddressBlacklisted(AddressBlacklisted { account }));
+        }
+        
+        let effective_time = self.current_time()
+            .checked_add(delay_seconds)
+            .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))?;
+        
+        self.blacklisted.setter(account).set(true);
+        self.blacklisted_count.set(self.blacklisted_count.get() + U256::from(1));
+        self.blacklist_effective_at.setter(account).set(effective_time);
+        
+        log(self.vm(), BlacklistScheduled {
+            account,
+            operator: self.vm().msg_sender(),
+            effective_time,
+        });
+        
+        Ok(true)
+    }
+    
+    /// Returns the timestamp from which a blacklisting takes effect (0 if immediate or none)
+    pub fn blacklist_effective_at(&self, account: Address) -> Result<U256, ERC20Error> {
+        Ok(self.blacklist_effective_at.get(account))
+    }
+    
+    /// Internal function returning whether a blacklisting is currently in effect
+    fn is_blacklist_active(&self, account: Address) -> bool {
+        self.blacklisted.get(account)
+            && self.current_time() >= self.blacklist_effective_at.get(account)
+    }
+    
+    /// Removes an address from blacklist
+    /// Can only be called by owner or COMPLIANCE_ROLE holders
+    pub fn unblacklist(&mut self, account: Address) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner_or_role(COMPLIANCE_ROLE)?;
+        
+        if !self.blacklisted.get(account) {
+            return Err(ERC20Error::AddressNotBlacklisted(AddressNotBlacklisted { account }));
+        }
+        
+        // Also cancels a scheduled (not yet effective) blacklisting
+        self.blacklisted.setter(account).set(false);
+        // Saturates because entries made before the counter existed were never counted
+        self.blacklisted_count.set(self.blacklisted_count.get().saturating_sub(U256::from(1)));
+        self.blacklist_effective_at.setter(account).set(U256::ZERO);
+        
+        log(self.vm(), AddressUnblacklisted {
+            account,
+            operator: self.vm().msg_sender(),
+            timestamp: self.current_time(),
+        });
+        
+        Ok(true)
+    }
+    
+    /// Enables or disables blacklist functionality
+    /// Can only be called by owner or COMPLIANCE_ROLE holders
+    pub fn set_blacklist_enabled(&mut self, enabled: bool) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner_or_role(COMPLIANCE_ROLE)?;
+        let old_value = self.flag(FLAG_BLACKLIST_ENABLED);
+        self.set_flag(FLAG_BLACKLIST_ENABLED, enabled);
+        self.emit_config_changed(CONFIG_BLACKLIST_ENABLED, U256::from(old_value), U256::from(enabled));
+        Ok(true)
+    }
+    
+    // ========================================================================
+    // SNAPSHOT FUNCTIONALITY
+    // ========================================================================
+    
+    /// Returns the current snapshot ID (0 if no snapshot in progress)
+    pub fn current_snapshot_id(&self) -> Result<U256, ERC20Error> {
+        Ok(self.current_snapshot_id.get())
+    }
+    
+    /// Returns the next available snapshot ID
+    pub fn next_snapshot_id(&self) -> Result<U256, ERC20Error> {
+        Ok(self.next_snapshot_id.get())
+    }
+    
+    /// Starts a new snapshot
+    /// Can only be called by owner
+    pub fn snapshot(&mut self) -> Result<U256, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        
+        // Cannot start a new snapshot if one is already in progress
+        if self.current_snapshot_id.get() != U256::ZERO {
+            return Err(ERC20Error::SnapshotInProgress(SnapshotInProgress {}));
+        }
+        
+        let snapshot_id = self.next_snapshot_id.get();
+        self.current_snapshot_id.set(snapshot_id);
+        
+        // Record snapshot metadata; the supply is fixed here, while balances are
+        // checkpointed on their first change or in batches via snapshot_all
+        let timestamp = self.current_time();
+        let total_supply = self.total_supply.get();
+        let mut snapshot = self.snapshots.setter(snapshot_id);
+        snapshot.timestamp.set(timestamp);
+        snapshot.total_supply.set(total_supply);
+        
+        log(self.vm(), SnapshotTaken {
+            snapshot_id,
+            timestamp,
+            total_supply,
+        });
+        
+        Ok(snapshot_id)
+    }
+    
+    /// Finalizes a snapshot (called after all balances are recorded)
+    pub fn finalize_snapshot(&mut self) -> Result<U256, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        
+        let snapshot_id = self.current_snapshot_id.get();
+        if snapshot_id == U256::ZERO {
+            return Err(ERC20Error::SnapshotNotFound(SnapshotNotFound { snapshot_id }));
+        }
+        
+        self.snapshots.setter(snapshot_id).finalized.set(true);
+        
+        // Increment next snapshot ID
+        self.next_snapshot_id.set(snapshot_id.checked_add(U256::from(1))
+            .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))?);
+        
+        // Clear current snapshot
+        self.current_snapshot_id.set(U256::ZERO);
+        
+        Ok(snapshot_id)
+    }
+    
+    /// Returns the recorded metadata of a snapshot as (timestamp, total_supply, finalized)
+    /// An in-progress snapshot is reported with `finalized == false`
+    pub fn get_snapshot(&self, snapshot_id: U256) -> Result<(U256, U256, bool), ERC20Error> {
+        let snapshot = self.snapshots.getter(snapshot_id);
+        let timestamp = snapshot.timestamp.get();
+        if timestamp == U256::ZERO {
+            return Err(ERC20Error::SnapshotNotFound(SnapshotNotFound { snapshot_id }));
+        }
+        
+        Ok((timestamp, snapshot.total_supply.get(), snapshot.finalized.get()))
+    }
+    
+    /// Returns the balance at a specific snapshot
+    pub fn balance_of_at(&self, account: Address, snapshot_id: U256) -> Result<U256, ERC20Error> {
+        if snapshot_id >= self.next_snapshot_id.get() {
+            return Err(ERC20Error::SnapshotNotFound(SnapshotNotFound { snapshot_id }));
+        }
+        
+        // Return the recorded balance if the account was checkpointed,
+        // otherwise fall back to the current balance
+        let snapshot = self.snapshots.getter(snapshot_id);
+        if snapshot.recorded.get(account) {
+            return Ok(snapshot.balances.get(account));
+        }
+        Ok(self.balances.get(account))
+    }
+    
+    /// Returns the total supply at a specific snapshot
+    pub fn total_supply_at(&self, snapshot_id: U256) -> Result<U256, ERC20Error> {
+        if snapshot_id >= self.next_snapshot_id.get() {
+            return Err(ERC20Error::SnapshotNotFound(SnapshotNotFound { snapshot_id }));
+        }
+        
+        // Return the recorded supply if the snapshot was started,
+        // otherwise fall back to the current supply
+        let snapshot = self.snapshots.getter(snapshot_id);
+        if snapshot.timestamp.get() != U256::ZERO {
+            return Ok(snapshot.total_supply.get());
+        }
+        Ok(self.total_supply.get())
+    }
+    
+    /// Returns `account`'s pro-rata share of `total_reward` by its balance at `snapshot_id`:
+    /// `balance_of_at * total_reward / total_supply_at` (rounded down; zero for an empty snapshot)
+    pub fn claimable_at_snapshot(
+        &self,
+        account: Address,
+        snapshot_id: U256,
+        total_reward: U256,
+    ) -> Result<U256, ERC20Error> {
+        let balance = self.balance_of_at(account, snapshot_id)?;
+        let total_supply = self.total_supply_at(snapshot_id)?;
+        if total_supply == U256::ZERO {
+            return Ok(U256::ZERO);
+        }
+        
+        let weighted = balance
+            .checked_mul(total_reward)
+            .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))?;
+        Ok(weighted / total_supply)
+    }
+    
+    /// Returns the allowance of `spender` over `owner`'s tokens at a specific snapshot
+    /// Falls back to the current allowance if it was not changed while the snapshot was open
+    pub fn allowance_of_at(
+        &self,
+        owner: Address,
+        spender: Address,
+        snapshot_id: U256,
+    ) -> Result<U256, ERC20Error> {
+        if snapshot_id >= self.next_snapshot_id.get() {
+            return Err(ERC20Error::SnapshotNotFound(SnapshotNotFound { snapshot_id }));
+        }
+        
+        let snapshot = self.snapshots.getter(snapshot_id);
+        if snapshot.allowance_recorded.getter(owner).get(spender) {
+            return Ok(snapshot.allowances.getter(owner).get(spender));
+        }
+        Ok(self.allowances.getter(owner).get(spender))
+    }
+    
+    /// Returns whether allowances are checkpointed into the active snapshot
+    pub fn snapshot_allowances_enabled(&self) -> Result<bool, ERC20Error> {
+        Ok(self.flag(FLAG_SNAPSHOT_ALLOWANCES_ENABLED))
+    }
+    
+    /// Enables or disables allowance checkpointing (off by default to save gas)
+    pub fn set_snapshot_allowances_enabled(&mut self, enabled: bool) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        let old_value = self.flag(FLAG_SNAPSHOT_ALLOWANCES_ENABLED);
+        self.set_flag(FLAG_SNAPSHOT_ALLOWANCES_ENABLED, enabled);
+        self.emit_config_changed(CONFIG_SNAPSHOT_ALLOWANCES_ENABLED, U256::from(old_value), U256::from(enabled));
+        Ok(true)
+    }
+    
+    /// Internal function recording the pre-change allowance into the active snapshot
+    /// Only the first change during a snapshot is recorded
+    fn checkpoint_allowance(&mut self, owner: Address, spender: Address) {
+        let snapshot_id = self.current_snapshot_id.get();
+        if !self.flag(FLAG_SNAPSHOT_ALLOWANCES_ENABLED) || snapshot_id == U256::ZERO {
+            return;
+        }
+        
+        if self.snapshots.getter(snapshot_id).allowance_recorded.getter(owner).get(spender) {
+            return;
+        }
+        
+        let allowance = self.allowances.getter(owner).get(spender);
+        let mut snapshot = self.snapshots.setter(snapshot_id);
+        snapshot.allowances.setter(owner).setter(spender).set(allowance);
+        snapshot.allowance_recorded.setter(owner).setter(spender).set(true);
+    }
+    
+    /// Records the balances of holders `[start, start + count)` into the active snapshot
+    /// Allows a complete snapshot of a large holder set over several transactions
+    /// Returns the number of holders recorded in this batch
+    pub fn snapshot_all(&mut self, start: U256, count: U256) -> Result<U256, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        
+        let snapshot_id = self.current_snapshot_id.get();
+        if snapshot_id == U256::ZERO {
+            return Err(ERC20Error::SnapshotNotFound(SnapshotNotFound { snapshot_id }));
+        }
+        
+        let holder_count = U256::from(self.holders.len());
+        if start >= holder_count {
+            return Err(ERC20Error::HolderIndexOutOfBounds(HolderIndexOutOfBounds {
+                index: start,
+                count: holder_count,
+            }));
+        }
+        
+        // Clamp the batch to the end of the registry
+        let end = start.saturating_add(count).min(holder_count);
+        let start_index = start.to::<usize>();
+        let end_index = end.to::<usize>();
+        
+        for i in start_index..end_index {
+            let account = self.holders.get(i).unwrap_or(Address::ZERO);
+            self.checkpoint_balance(account);
+        }
+        
+        let recorded = end - start;
+        log(self.vm(), SnapshotBatchRecorded {
+            snapshot_id,
+            start,
+            count: recorded,
+        });
+        
+        Ok(recorded)
+    }
+    
+    /// Internal function recording `account`'s balance into the active snapshot
+    /// Must run before any balance change; only the first (pre-change) value is kept,
+    /// so mints, burns and transfers during an open snapshot don't corrupt history
+    fn checkpoint_balance(&mut self, account: Address) {
+        let snapshot_id = self.current_snapshot_id.get();
+        if snapshot_id == U256::ZERO || self.snapshots.getter(snapshot_id).recorded.get(account) {
+            return;
+        }
+        
+        let balance = self.balances.get(account);
+        let mut snapshot = self.snapshots.setter(snapshot_id);
+        snapshot.balances.setter(account).set(balance);
+        snapshot.recorded.setter(account).set(true);
+    }
+    
+    /// Validates a snapshot by summing the recorded balances of `accounts`
+    /// Returns true if the sum does not exceed the snapshot's recorded total supply;
+    /// over the full holder set the two match, so a false result flags an inconsistent snapshot.
+    /// Only finalized snapshots can be validated; duplicate accounts are counted once
+    pub fn validate_snapshot(
+        &self,
+        snapshot_id: U256,
+        accounts: alloc::vec::Vec<Address>,
+    ) -> Result<bool, ERC20Error> {
+        if snapshot_id == U256::ZERO || snapshot_id >= self.next_snapshot_id.get() {
+            return Err(ERC20Error::SnapshotNotFound(SnapshotNotFound { snapshot_id }));
+        }
+        self.check_batch_size(accounts.len())?;
+        
+        let snapshot = self.snapshots.getter(snapshot_id);
+        if snapshot.timestamp.get() == U256::ZERO {
+            return Err(ERC20Error::SnapshotNotFound(SnapshotNotFound { snapshot_id }));
+        }
+        
+        let mut sum = U256::ZERO;
+        for (i, account) in accounts.iter().enumerate() {
+            if accounts[..i].contains(account) {
+                continue;
+            }
+            
+            // Only balances actually checkpointed into the snapshot count
+            if snapshot.recorded.get(*account) {
+                sum = sum.saturating_add(snapshot.balances.get(*account));
+            }
+        }
+        
+        Ok(sum <= snapshot.total_supply.get())
+    }
+    
+    // ========================================================================
+    // HOLDER REGISTRY
+    // ========================================================================
+    
+    /// Returns the number of addresses that have ever held tokens
+    pub fn holder_count(&self) -> Result<U256, ERC20Error> {
+        Ok(U256::from(self.holders.len()))
+    }
+    
+    /// Returns storage-growth counters for monitoring as
+    /// (holders, snapshots, blacklisted_count, role_entries)
+    /// `holders` is append-only; `blacklisted_count` includes scheduled entries
+    /// On upgraded deployments both counters start at zero and exclude entries made before the upgrade
+    pub fn state_stats(&self) -> Result<(U256, U256, U256, U256), ERC20Error> {
+        let snapshots = self.next_snapshot_id.get().saturating_sub(U256::from(1));
+        Ok((
+            U256::from(self.holders.len()),
+            snapshots,
+            self.blacklisted_count.get(),
+            self.role_entry_count.get(),
+        ))
+    }
+    
+    /// Returns the holder at `index` in the registry
+    pub fn get_holder(&self, index: U256) -> Result<Address, ERC20Error> {
+        let holder_count = U256::from(self.holders.len());
+        if index >= holder_count {
+            return Err(ERC20Error::HolderIndexOutOfBounds(HolderIndexOutOfBounds {
+                index,
+                count: holder_count,
+            }));
+        }
+        Ok(self.holders.get(index.to::<usize>()).unwrap_or(Address::ZERO))
+    }
+    
+    /// Returns (sum of `accounts`' balances, total supply) for off-chain reconciliation
+    /// Over the full holder set (see `holder_count`/`get_holder`) the two must match;
+    /// duplicate accounts are counted each time they appear
+    pub fn verify_supply_subset(&self, accounts: alloc::vec::Vec<Address>) -> Result<(U256, U256), ERC20Error> {
+        let summed = accounts
+            .into_iter()
+            .fold(U256::ZERO, |acc, account| acc.saturating_add(self.balances.get(account)));
+        Ok((summed, self.total_supply.get()))
+    }
+    
+    /// Internal function to register an account in the holder registry
+    /// Registration is append-only so iteration order is deterministic
+    fn track_holder(&mut self, account: Address) {
+        if account == Address::ZERO || self.is_holder.get(account) {
+            return;
+        }
+        self.is_holder.setter(account).set(true);
+        self.holders.push(account);
+    }
+    
+    // ========================================================================
+    // TIME-LOCKED OWNERSHIP TRANSFER
+    // ========================================================================
+    
+    /// Returns the pending owner (Address::ZERO if none)
+    pub fn pending_owner(&self) -> Result<Address, ERC20Error> {
+        Ok(self.pending_owner.get())
+    }
+    
+    /// Returns the unlock time for pending ownership transfer
+    pub fn ownership_unlock_time(&self) -> Result<U256, ERC20Error> {
+        Ok(self.ownership_unlock_time.get())
+    }
+    
+    /// Returns the ownership transfer delay
+    pub fn ownership_transfer_delay(&self) -> Result<U256, ERC20Error> {
+        Ok(self.ownership_transfer_delay.get())
+    }
+    
+    /// Returns the pending owner, the unlock timestamp, and whether the transfer is claimable now
+    /// Claimable means a pending owner exists and the time-lock has elapsed
+    pub fn pending_ownership_info(&self) -> Result<(Address, U256, bool), ERC20Error> {
+        let pending_owner = self.pending_owner.get();
+        let unlock_time = self.ownership_unlock_time.get();
+        let claimable = pending_owner != Address::ZERO
+            && self.current_time() >= unlock_time;
+        
+        Ok((pending_owner, unlock_time, claimable))
+    }
+    
+    /// Returns every privileged change awaiting action, for governance dashboards:
+    /// (pending_owner, owner_unlock, pending_guardian, pending_supply_cap_change_exists)
+    /// Guardian and supply cap changes apply immediately, so those slots are always zero/false
+    pub fn pending_changes(&self) -> Result<(Address, U256, Address, bool), ERC20Error> {
+        let pending_owner = self.pending_owner.get();
+        let owner_unlock = if pending_owner == Address::ZERO {
+            U256::ZERO
+        } else {
+            self.ownership_unlock_time.get()
+        };
+        
+        Ok((pending_owner, owner_unlock, Address::ZERO, false))
+    }
+    
+    /// Initiates ownership transfer to a new account
+    /// The new owner must accept ownership after the time-lock period
+    pub fn initiate_ownership_transfer(
+        &mut self,
+        new_owner: Address,
+    ) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        
+        if new_owner == Address::ZERO {
+            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
+        }
+        
+        // Cancel any pending transfer first
+        if self.pending_owner.get() != Address::ZERO {
+            self.cancel_ownership_transfer()?;
+        }
+        
+        let current_time = self.current_time();
+        let unlock_time = current_time.checked_add(self.ownership_transfer_delay.get())
+            .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))?;
+        
+        self.pending_owner.set(new_owner);
+        self.ownership_unlock_time.set(unlock_time);
+        self.pending_handover.set(false);
+        
+        log(self.vm(), OwnershipTransferInitiated {
+            owner: self.owner.get(),
+            new_owner,
+            unlock_time,
+        });
+        
+        Ok(true)
+    }
+    
+    /// Initiates a full project handover to `new_owner` through the time-locked flow
+    /// On `accept_ownership`, ownership and DEFAULT_ADMIN, ADMIN, MINTER, PAUSER and
+    /// COMPLIANCE roles move from the current owner to `new_owner` in one transaction
+    /// Can only be called by owner
+    pub fn handover(&mut self, new_owner: Address) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.initiate_ownership_transfer(new_owner)?;
+        self.pending_handover.set(true);
+        
+        Ok(true)
+    }
+    
+    /// Returns whether the pending ownership transfer is a full handover
+    pub fn pending_handover(&self) -> Result<bool, ERC20Error> {
+        Ok(self.pending_handover.get())
+    }
+    
+    /// Accepts ownership transfer (called by pending owner after time-lock)
+    pub fn accept_ownership(&mut self) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        let pending_owner = self.pending_owner.get();
+        if pending_owner == Address::ZERO {
+            return Err(ERC20Error::NoPendingOwnershipTransfer(NoPendingOwnershipTransfer {}));
+        }
+        
+        if self.vm().msg_sender() != pending_owner {
+            return Err(ERC20Error::NotOwner(NotOwner {
+                caller: self.vm().msg_sender(),
+                owner: pending_owner,
+            }));
+        }
+        
+        let current_time = self.current_time();
+        let unlock_time = self.ownership_unlock_time.get();
+        if current_time < unlock_time {
+            return Err(ERC20Error::OwnershipTransferNotYetUnlockable(
+                OwnershipTransferNotYetUnlockable {
+                    current_time,
+                    unlock_time,
+                },
+            ));
+        }
+        
+        let previous_owner = self.owner.get();
+        self.owner.set(pending_owner);
+        self.pending_owner.set(Address::ZERO);
+        self.ownership_unlock_time.set(U256::ZERO);
+        self.move_default_admin(previous_owner, pending_owner);
+        
+        // A handover also moves every core role to the new owner
+        if self.pending_handover.get() {
+            self.pending_handover.set(false);
+            for role in [ADMIN_ROLE, MINTER_ROLE, PAUSER_ROLE, COMPLIANCE_ROLE] {
+                self.move_role(role, previous_owner, pending_owner);
+            }
+        }
+        
+        log(self.vm(), OwnershipTransferExecuted {
+            previous_owner,
+            new_owner: pending_owner,
+        });
+        
+        log(self.vm(), OwnershipTransferred {
+            previous_owner,
+            new_owner: pending_owner,
+        });
+        
+        Ok(true)
+    }
+    
+    /// Cancels a pending ownership transfer
+    pub fn cancel_ownership_transfer(&mut self) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        
+        let pending_owner = self.pending_owner.get();
+        if pending_owner == Address::ZERO {
+            return Err(ERC20Error::NoPendingOwnershipTransfer(NoPendingOwnershipTransfer {}));
+        }
+        
+        let cancelled_owner = pending_owner;
+        self.pending_owner.set(Address::ZERO);
+        self.ownership_unlock_time.set(U256::ZERO);
+        self.pending_handover.set(false);
+        
+        log(self.vm(), OwnershipTransferCancelled {
+            owner: self.owner.get(),
+            new_owner: cancelled_owner,
+        });
+        
+        Ok(true)
+    }
+    
+    /// Sets the ownership transfer delay
+    pub fn set_ownership_transfer_delay(&mut self, delay_seconds: U256) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        self.ownership_transfer_delay.set(delay_seconds);
+        Ok(true)
+    }
+    
+    // ========================================================================
+    // EMERGENCY FEATURES
+    // ========================================================================
+    
+    /// Returns the emergency admin address
+    pub fn emergency_admin(&self) -> Result<Address, ERC20Error> {
+        Ok(self.emergency_admin.get())
+    }
+    
+    /// Returns the guardian set via `set_guardian` (legacy single-guardian getter)
+    pub fn guardian(&self) -> Result<Address, ERC20Error> {
+        Ok(self.guardian.get())
+    }
+    
+    /// Returns whether `account` may trigger a guardian pause
+    pub fn is_guardian(&self, account: Address) -> Result<bool, ERC20Error> {
+        Ok(self.guardians.get(account))
+    }
+    
+    /// Returns the number of registered guardians
+    pub fn guardian_count(&self) -> Result<U256, ERC20Error> {
+        Ok(self.guardian_count.get())
+    }
+    
+    /// Sets the emergency admin (for recovery scenarios)
+    pub fn set_emergency_admin(&mut self, new_admin: Address) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        
+        let old_admin = self.emergency_admin.get();
+        self.emergency_admin.set(new_admin);
+        
+        log(self.vm(), EmergencyAdminChanged {
+            old_admin,
+            new_admin,
+        });
+        
+        Ok(true)
+    }
+    
+    /// Sets the guardian (trusted third party for emergency pause)
+    /// Replaces the previous `set_guardian` entry in the guardian set
+    pub fn set_guardian(&mut self, new_guardian: Address) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        
+        let old_guardian = self.guardian.get();
+        if old_guardian != Address::ZERO {
+            self.remove_guardian_member(old_guardian);
+        }
+        if new_guardian != Address::ZERO {
+            self.add_guardian_member(new_guardian);
+        }
+        self.guardian.set(new_guardian);
+        
+        log(self.vm(), GuardianUpdated {
+            old_guardian,
+            new_guardian,
+        });
+        
+        Ok(true)
+    }
+    
+    /// Adds `account` to the guardian set
+    /// Can only be called by owner
+    pub fn add_guardian(&mut self, account: Address) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        
+        if account == Address::ZERO {
+            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
+        }
+        
+        if !self.add_guardian_member(account) {
+            return Err(ERC20Error::GuardianAlreadyAdded(GuardianAlreadyAdded { guardian: account }));
+        }
+        
+        Ok(true)
+    }
+    
+    /// Removes `account` from the guardian set
+    /// Can only be called by owner
+    pub fn remove_guardian(&mut self, account: Address) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        
+        if !self.remove_guardian_member(account) {
+            return Err(ERC20Error::NotGuardian(NotGuardian { account }));
+        }
+        
+        // Keep the legacy getter consistent
+        if self.guardian.get() == account {
+            self.guardian.set(Address::ZERO);
+        }
+        
+        Ok(true)
+    }
+    
+    /// Internal function adding a guardian; returns false if already a member
+    fn add_guardian_member(&mut self, account: Address) -> bool {
+        if self.guardians.get(account) {
+            return false;
+        }
+        
+        self.guardians.setter(account).set(true);
+        self.guardian_count.set(self.guardian_count.get() + U256::from(1));
+        self.set_flag(FLAG_GUARDIAN_ENABLED, true);
+        
+        log(self.vm(), GuardianAdded { guardian: account });
+        
+        true
+    }
+    
+    /// Internal function removing a guardian; returns false if not a member
+    fn remove_guardian_member(&mut self, account: Address) -> bool {
+        if !self.guardians.get(account) {
+            return false;
+        }
+        
+        self.guardians.setter(account).set(false);
+        let count = self.guardian_count.get() - U256::from(1);
+        self.guardian_count.set(count);
+        self.set_flag(FLAG_GUARDIAN_ENABLED, count != U256::ZERO);
+        
+        log(self.vm(), GuardianRemoved { guardian: account });
+        
+        true
+    }
+    
+    /// Returns the maximum duration of a guardian pause in seconds
+    pub fn guardian_pause_max_duration(&self) -> Result<U256, ERC20Error> {
+        Ok(self.effective_guardian_pause_duration())
+    }
+    
+    /// Sets the maximum duration of a guardian pause (0 restores the 72 hour default)
+    /// Applies to guardian pauses started afterwards. Can only be called by owner
+    pub fn set_guardian_pause_max_duration(&mut self, duration: U256) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        
+        let old_value = self.effective_guardian_pause_duration();
+        self.guardian_pause_max_duration.set(duration);
+        self.emit_config_changed(
+            CONFIG_GUARDIAN_PAUSE_MAX_DURATION,
+            old_value,
+            self.effective_guardian_pause_duration(),
+        );
+        
+        Ok(true)
+    }
+    
+    /// Returns when the active guardian pause lifts on its own (0 if no guardian pause is active)
+    /// The owner can keep the token paused past this point by calling `pause`
+    pub fn guardian_pause_expiry(&self) -> Result<U256, ERC20Error> {
+        if !self.flag(FLAG_GUARDIAN_PAUSE) || !self.is_paused() {
+            return Ok(U256::ZERO);
+        }
+        Ok(self.pause_expiry.get())
+    }
+    
+    /// Internal function returning the configured guardian pause window
+    fn effective_guardian_pause_duration(&self) -> U256 {
+        let duration = self.guardian_pause_max_duration.get();
+        if duration == U256::ZERO {
+            U256::from(GUARDIAN_PAUSE_DURATION)
+        } else {
+            duration
+        }
+    }
+    
+    /// Emergency pause by any registered guardian
+    /// The pause lifts automatically after `guardian_pause_max_duration` unless the owner re-pauses
+    pub fn guardian_pause(&mut self) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        if !self.guardians.get(self.vm().msg_sender()) {
+            return Err(ERC20Error::AccessDenied(AccessDenied {
+                account: self.vm().msg_sender(),
+                role: PAUSER_ROLE,
+            }));
+        }
+        
+        if self.is_paused() {
+            return Err(ERC20Error::ContractPaused(ContractPaused {}));
+        }
+        
+        // Guardian pauses are bounded so the token cannot be frozen indefinitely
+        let expiry = self.current_time()
+            .checked_add(self.effective_guardian_pause_duration())
+            .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))?;
+        
+        self.set_flag(FLAG_PAUSED, true);
+        self.set_flag(FLAG_GUARDIAN_PAUSE, true);
+        self.pause_expiry.set(expiry);
+        
+        self.record_pause_event(true);
+        
+        log(self.vm(), Paused {
+            account: self.vm().msg_sender(),
+        });
+        
+        Ok(true)
+    }
+    
+    /// Moves tokens of this contract held by the contract's own address (e.g. fees
+    /// sent to the contract by mistake) to `to`. Tokens reserved for vesting, accrued fees
+    /// or scheduled releases cannot be swept
+    /// Can only be called by owner
+    pub fn sweep_self_tokens(&mut self, to: Address, amount: U256) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        
+        if to == Address::ZERO {
+            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
+        }
+        
+        let this = self.vm().contract_address();
+        let sweepable = self.unreserved_self_balance()?;
+        if sweepable < amount {
+            return Err(ERC20Error::InsufficientBalance(InsufficientBalance {
+                balance: sweepable,
+                required: amount,
+            }));
+        }
+        
+        self.internal_transfer(this, to, amount)?;
+        
+        Ok(true)
+    }
+    
+    /// Withdraws native ETH accidentally sent to the contract
+    /// Can only be called by owner
+    pub fn withdraw_eth(&mut self, to: Address, amount: U256) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        
+        if to == Address::ZERO {
+            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
+        }
+        
+        let balance = self.vm().balance(self.vm().contract_address());
+        if balance < amount {
+            return Err(ERC20Error::InsufficientEthBalance(InsufficientEthBalance {
+                balance,
+                required: amount,
+            }));
+        }
+        
+        self.vm()
+            .transfer_eth(to, amount)
+            .map_err(|_| ERC20Error::EthTransferFailed(EthTransferFailed { to, amount }))?;
+        
+        log(self.vm(), EthWithdrawn { to, amount });
+        
+        Ok(true)
+    }
+    
+    /// Internal function returning the contract's own balance not held for vesting,
+    /// accrued fees or scheduled releases
+    fn unreserved_self_balance(&self) -> Result<U256, ERC20Error> {
+        let reserved = self.vesting_custody.get()
+            .checked_add(self.fee_custody.get())
+            .and_then(|r| r.checked_add(self.release_custody.get()))
+            .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))?;
+        Ok(self.balances.get(self.vm().contract_address()).saturating_sub(reserved))
+    }
+    
+    // ========================================================================
+    // SCHEDULED RELEASES
+    // ========================================================================
+    
+    /// Returns the number of releases ever scheduled (ids are 0-based)
+    pub fn release_count(&self) -> Result<U256, ERC20Error> {
+        Ok(self.release_count.get())
+    }
+    
+    /// Returns release `id` as (to, amount, release_time, executed)
+    pub fn get_scheduled_release(&self, id: U256) -> Result<(Address, U256, U256, bool), ERC20Error> {
+        if id >= self.release_count.get() {
+            return Err(ERC20Error::ReleaseNotFound(ReleaseNotFound { id }));
+        }
+        
+        let release = self.scheduled_releases.getter(id);
+        Ok((
+            release.to.get(),
+            release.amount.get(),
+            release.release_time.get(),
+            release.executed.get(),
+        ))
+    }
+    
+    /// Schedules `amount` of the contract's own unreserved balance to be released to `to`
+    /// at `release_time`; the amount is earmarked so it cannot be swept meanwhile
+    /// Returns the release id. Can only be called by owner
+    pub fn scheduled_release(
+        &mut self,
+        to: Address,
+        amount: U256,
+        release_time: U256,
+    ) -> Result<U256, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        
+        if to == Address::ZERO {
+            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
+        }
+        
+        if amount == U256::ZERO {
+            return Err(ERC20Error::InvalidAmount(InvalidAmount {}));
+        }
+        
+        let available = self.unreserved_self_balance()?;
+        if available < amount {
+            return Err(ERC20Error::InsufficientBalance(InsufficientBalance {
+                balance: available,
                 required: amount,
-            }))?;
+            }));
+        }
         
-        // Update total supply
-        let current_supply = self.total_supply.get();
-        let new_supply = current_supply
-            .checked_sub(amount)
-            .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))?;
+        let id = self.release_count.get();
+        let mut release = self.scheduled_releases.setter(id);
+        release.to.set(to);
+        release.amount.set(amount);
+        release.release_time.set(release_time);
+        release.executed.set(false);
         
-        self.balances.setter(from).set(new_balance);
-        self.total_supply.set(new_supply);
+        self.release_count.set(id + U256::from(1));
+        self.release_custody.set(self.release_custody.get() + amount);
         
-        // Emit Transfer event to zero address (burn)
-        evm::log(Transfer {
-            from,
-            to: Address::ZERO,
+        log(self.vm(), ReleaseScheduled {
+            id,
+            to,
             amount,
+            release_time,
         });
         
-        Ok(true)
+        Ok(id)
     }
     
-    /// Burns `amount` tokens from `from` account on behalf of the caller
-    /// The caller must have allowance for `from`'s tokens of at least `amount`
-    pub fn burn_from(&mut self, from: Address, amount: U256) -> Result<bool, ERC20Error> {
-        let spender = msg::sender();
+    /// Executes release `id` once its release time has passed
+    /// Callable by anyone so keepers can execute due releases
+    pub fn execute_release(&mut self, id: U256) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
         
-        // Check if contract is paused
-        if self.paused.get() {
+        if self.is_paused() {
             return Err(ERC20Error::ContractPaused(ContractPaused {}));
         }
         
-        // Validate from address
-        if from == Address::ZERO {
-            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
-        }
-        
-        // Skip if amount is zero
-        if amount == U256::ZERO {
-            return Ok(true);
+        if id >= self.release_count.get() {
+            return Err(ERC20Error::ReleaseNotFound(ReleaseNotFound { id }));
         }
         
-        // Check and update allowance
-        let current_allowance = self.allowances.getter(from).get(spender);
+        let release = self.scheduled_releases.getter(id);
+        let to = release.to.get();
+        let amount = release.amount.get();
+        let release_time = release.release_time.get();
         
-        // Check for sufficient allowance
-        if current_allowance < amount {
-            return Err(ERC20Error::InsufficientAllowance(
-                InsufficientAllowance {
-                    allowance: current_allowance,
-                    required: amount,
-                },
-            ));
+        if release.executed.get() {
+            return Err(ERC20Error::ReleaseAlreadyExecuted(ReleaseAlreadyExecuted { id }));
         }
         
-        // Decrease allowance using checked subtraction
-        let new_allowance = current_allowance
-            .checked_sub(amount)
-            .ok_or(ERC20Error::InsufficientAllowance(
-                InsufficientAllowance {
-                    allowance: current_allowance,
-                    required: amount,
-                },
-            ))?;
-        
-        self.allowances.setter(from).setter(spender).set(new_allowance);
-        
-        // Check balance and burn
-        let current_balance = self.balances.get(from);
-        if current_balance < amount {
-            return Err(ERC20Error::InsufficientBalance(InsufficientBalance {
-                balance: current_balance,
-                required: amount,
+        let current_time = self.current_time();
+        if current_time < release_time {
+            return Err(ERC20Error::ReleaseNotReady(ReleaseNotReady {
+                id,
+                release_time,
+                current_time,
             }));
         }
         
-        // Update balance with underflow check
-        let new_balance = current_balance
-            .checked_sub(amount)
-            .ok_or(ERC20Error::InsufficientBalance(InsufficientBalance {
-                balance: current_balance,
-                required: amount,
-            }))?;
-        
-        // Update total supply
-        let current_supply = self.total_supply.get();
-        let new_supply = current_supply
-            .checked_sub(amount)
-            .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))?;
+        // Effects before the balance move
+        self.scheduled_releases.setter(id).executed.set(true);
+        self.release_custody.set(self.release_custody.get() - amount);
         
-        self.balances.setter(from).set(new_balance);
-        self.total_supply.set(new_supply);
+        self.internal_transfer(self.vm().contract_address(), to, amount)?;
         
-        // Emit Transfer event to zero address (burn)
-        evm::log(Transfer {
-            from,
-            to: Address::ZERO,
-            amount,
-        });
+        log(self.vm(), ReleaseExecuted { id, to, amount });
         
         Ok(true)
     }
     
     // ========================================================================
-    // OWNERSHIP MANAGEMENT
+    // MINTING LIMITS (Rate Limiting)
     // ========================================================================
     
-    /// Returns the current owner of the contract
-    pub fn owner(&self) -> Result<Address, ERC20Error> {
-        Ok(self.owner.get())
+    /// Returns the minting period limit
+    pub fn minting_period_limit(&self) -> Result<U256, ERC20Error> {
+        Ok(self.minting_period_limit.get())
     }
     
-    /// Transfers ownership of the contract to a new account (`new_owner`)
-    /// Can only be called by the current owner
-    pub fn transfer_ownership(
+    /// Returns the minting period duration in seconds
+    pub fn minting_period_duration(&self) -> Result<U256, ERC20Error> {
+        Ok(self.minting_period_duration.get())
+    }
+    
+    /// Sets minting rate limits
+    pub fn set_minting_limits(
         &mut self,
-        new_owner: Address,
+        period_limit: U256,
+        period_duration_seconds: U256,
     ) -> Result<bool, ERC20Error> {
-        // Check ownership
-        self.only_owner()?;
-        
-        // Validate new owner address
-        if new_owner == Address::ZERO {
-            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
-        }
+        self.require_initialized()?;
         
-        let previous_owner = self.owner.get();
-        
-        self.owner.set(new_owner);
+        self.only_owner()?;
         
-        // Emit ownership transfer event
-        evm::log(OwnershipTransferred {
-            previous_owner,
-            new_owner,
-        });
+        self.minting_period_limit.set(period_limit);
+        self.minting_period_duration.set(period_duration_seconds);
         
         Ok(true)
     }
     
-    /// Internal function to check if caller is owner
-    fn only_owner(&self) -> Result<(), ERC20Error> {
-        let caller = msg::sender();
-        let owner = self.owner.get();
-        
-        if caller != owner {
-            return Err(ERC20Error::NotOwner(NotOwner { caller, owner }));
-        }
-        
-        Ok(())
+    /// Returns the maximum amount a single mint may create (0 = unlimited)
+    pub fn max_mint_per_tx(&self) -> Result<U256, ERC20Error> {
+        Ok(self.max_mint_per_tx.get())
     }
     
-    /// Leaves the contract without an owner
-    /// After renouncing ownership, owner will be Address::ZERO
-    /// Cannot be called if the current owner is Address::ZERO
-    pub fn renounce_ownership(&mut self) -> Result<bool, ERC20Error> {
-        // Check ownership
-        self.only_owner()?;
-        
-        let previous_owner = self.owner.get();
+    /// Sets the maximum amount a single mint may create (0 = unlimited)
+    /// Applies on top of the rolling rate limit. Can only be called by owner
+    pub fn set_max_mint_per_tx(&mut self, max_amount: U256) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
         
-        // Set owner to zero address
-        self.owner.set(Address::ZERO);
+        self.only_owner()?;
         
-        // Emit ownership transfer event
-        evm::log(OwnershipTransferred {
-            previous_owner,
-            new_owner: Address::ZERO,
-        });
+        let old_value = self.max_mint_per_tx.get();
+        self.max_mint_per_tx.set(max_amount);
+        self.emit_config_changed(CONFIG_MAX_MINT_PER_TX, old_value, max_amount);
         
         Ok(true)
     }
     
-    // ========================================================================
-    // PAUSABLE FUNCTIONALITY
-    // ========================================================================
+    /// Returns the outstanding (promised, not yet minted) allocation of `minter`
+    pub fn mint_allocation(&self, minter: Address) -> Result<U256, ERC20Error> {
+        Ok(self.mint_allocations.get(minter))
+    }
     
-    /// Returns true if the contract is paused, false otherwise
-    pub fn paused(&self) -> Result<bool, ERC20Error> {
-        Ok(self.paused.get())
+    /// Returns the sum of all outstanding mint allocations
+    pub fn total_allocated_mint(&self) -> Result<U256, ERC20Error> {
+        Ok(self.total_allocated_mint.get())
     }
     
-    /// Pauses the contract
-    /// Can only be called by the owner
-    pub fn pause(&mut self) -> Result<bool, ERC20Error> {
-        // Check ownership
+    /// Promises `minter` an allocation of `amount` future mints, replacing any previous one
+    /// Mints by `minter` draw the allocation down. While the cap is enabled the supply plus
+    /// all allocations must fit under it. Can only be called by owner
+    pub fn set_mint_allocation(&mut self, minter: Address, amount: U256) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
         self.only_owner()?;
         
-        // Check if already paused
-        if self.paused.get() {
-            return Err(ERC20Error::ContractPaused(ContractPaused {}));
+        if minter == Address::ZERO {
+            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
         }
         
-        self.paused.set(true);
+        let total_allocated = self.total_allocated_mint.get() - self.mint_allocations.get(minter);
+        let total_allocated = total_allocated.checked_add(amount)
+            .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))?;
         
-        // Emit Paused event
-        evm::log(Paused {
-            account: msg::sender(),
-        });
+        let committed = self.total_supply.get()
+            .saturating_add(self.vesting_custody.get())
+            .saturating_add(total_allocated);
+        let cap = self.supply_cap.get();
+        if self.flag(FLAG_SUPPLY_CAP_ENABLED) && committed > cap {
+            return Err(ERC20Error::AllocationExceedsCap(AllocationExceedsCap { committed, cap }));
+        }
+        
+        self.mint_allocations.setter(minter).set(amount);
+        self.total_allocated_mint.set(total_allocated);
+        
+        log(self.vm(), MintAllocationSet { minter, amount });
         
         Ok(true)
     }
     
-    /// Unpauses the contract
-    /// Can only be called by the owner
-    pub fn unpause(&mut self) -> Result<bool, ERC20Error> {
-        // Check ownership
-        self.only_owner()?;
-        
-        // Check if already unpaused
-        if !self.paused.get() {
-            return Err(ERC20Error::NotContractPaused(NotContractPaused {}));
+    /// Internal function drawing `amount` from the caller's outstanding mint allocation
+    fn consume_mint_allocation(&mut self, minter: Address, amount: U256) {
+        let allocation = self.mint_allocations.get(minter);
+        if allocation == U256::ZERO {
+            return;
         }
         
-        self.paused.set(false);
+        let used = allocation.min(amount);
+        self.mint_allocations.setter(minter).set(allocation - used);
+        self.total_allocated_mint.set(self.total_allocated_mint.get() - used);
+    }
+    
+    // ========================================================================
+    // TRANSFER WHITELIST
+    // ========================================================================
+    
+    /// Returns whether an address is whitelisted for transfers
+    pub fn is_transfer_whitelisted(&self, account: Address) -> Result<bool, ERC20Error> {
+        Ok(self.transfer_whitelist.get(account))
+    }
+    
+    /// Adds an address to the transfer whitelist
+    pub fn add_to_whitelist(&mut self, account: Address) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
         
-        // Emit Unpaused event
-        evm::log(Unpaused {
-            account: msg::sender(),
-        });
+        self.only_owner()?;
+        self.transfer_whitelist.setter(account).set(true);
+        Ok(true)
+    }
+    
+    /// Removes an address from the transfer whitelist
+    pub fn remove_from_whitelist(&mut self, account: Address) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
         
+        self.only_owner()?;
+        self.transfer_whitelist.setter(account).set(false);
         Ok(true)
     }
     
-    // ========================================================================
-    // SUPPLY CAP MANAGEMENT
-    // ========================================================================
+    /// Enables or disables transfer restrictions
+    pub fn set_transfer_restrictions_enabled(&mut self, enabled: bool) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        let old_value = self.flag(FLAG_TRANSFER_RESTRICTIONS_ENABLED);
+        self.set_flag(FLAG_TRANSFER_RESTRICTIONS_ENABLED, enabled);
+        self.emit_config_changed(CONFIG_TRANSFER_RESTRICTIONS_ENABLED, U256::from(old_value), U256::from(enabled));
+        Ok(true)
+    }
     
-    /// Returns the current supply cap
-    pub fn supply_cap(&self) -> Result<U256, ERC20Error> {
-        Ok(self.supply_cap.get())
+    /// Returns whether transfer restrictions are enabled
+    pub fn transfer_restrictions_enabled(&self) -> Result<bool, ERC20Error> {
+        Ok(self.flag(FLAG_TRANSFER_RESTRICTIONS_ENABLED))
     }
     
-    /// Returns whether supply cap is enabled
-    pub fn supply_cap_enabled(&self) -> Result<bool, ERC20Error> {
-        Ok(self.supply_cap_enabled.get())
+    /// Returns the whitelist policy (WHITELIST_MODE_*) enforced while restrictions are enabled
+    pub fn whitelist_mode(&self) -> Result<u8, ERC20Error> {
+        Ok(self.whitelist_mode.get().to_le_bytes::<1>()[0])
     }
     
-    /// Sets a new supply cap (can only decrease, not increase)
+    /// Sets the whitelist policy enforced while restrictions are enabled
     /// Can only be called by owner
-    pub fn set_supply_cap(&mut self, new_cap: U256) -> Result<bool, ERC20Error> {
+    pub fn set_whitelist_mode(&mut self, mode: u8) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
         self.only_owner()?;
         
-        let current_cap = self.supply_cap.get();
-        if new_cap > current_cap {
-            return Err(ERC20Error::CannotDecreaseSupplyCap(CannotDecreaseSupplyCap {}));
+        if mode > WHITELIST_MODE_BOTH_PARTIES {
+            return Err(ERC20Error::InvalidWhitelistMode(InvalidWhitelistMode { mode }));
         }
         
-        // Check if new cap would be below current supply
-        let current_supply = self.total_supply.get();
-        if new_cap < current_supply {
-            return Err(ERC20Error::SupplyCapExceeded(SupplyCapExceeded {
-                current_supply,
-                cap: new_cap,
-            }));
+        let old_value = self.whitelist_mode.get().to_le_bytes::<1>()[0];
+        self.whitelist_mode.set(Uint::<8, 1>::from(mode));
+        self.emit_config_changed(CONFIG_WHITELIST_MODE, U256::from(old_value), U256::from(mode));
+        Ok(true)
+    }
+    
+    /// Internal function checking the whitelist policy for a transfer
+    /// Always passes while transfer restrictions are disabled
+    fn passes_whitelist(&self, from: Address, to: Address) -> bool {
+        if !self.flag(FLAG_TRANSFER_RESTRICTIONS_ENABLED) {
+            return true;
         }
         
-        let old_cap = self.supply_cap.get();
-        self.supply_cap.set(new_cap);
+        let sender_listed = self.transfer_whitelist.get(from);
+        let recipient_listed = self.transfer_whitelist.get(to);
         
-        evm::log(SupplyCapUpdated {
-            old_cap,
-            new_cap,
+        match self.whitelist_mode.get().to_le_bytes::<1>()[0] {
+            WHITELIST_MODE_SENDER_ONLY => sender_listed,
+            WHITELIST_MODE_BOTH_PARTIES => sender_listed && recipient_listed,
+            _ => sender_listed || recipient_listed,
+        }
+    }
+    
+    /// Returns the external transfer policy hook (Address::ZERO if disabled)
+    pub fn transfer_hook(&self) -> Result<Address, ERC20Error> {
+        Ok(self.transfer_hook.get())
+    }
+    
+    /// Sets the external transfer policy hook consulted on every transfer
+    /// Pass Address::ZERO to disable. Can only be called by owner
+    pub fn set_transfer_hook(&mut self, new_hook: Address) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        
+        let old_hook = self.transfer_hook.get();
+        self.transfer_hook.set(new_hook);
+        
+        log(self.vm(), TransferHookUpdated {
+            old_hook,
+            new_hook,
         });
         
         Ok(true)
     }
     
-    /// Enables or disables the supply cap
+    /// Internal function asking the external policy hook about a transfer
+    /// A revert or a malformed answer counts as a rejection
+    fn hook_approves(&self, hook: Address, from: Address, to: Address, amount: U256) -> bool {
+        let call = ITransferHook::checkTransferCall { from, to, amount };
+        self.vm()
+            .static_call(&Call::new(), hook, &call.abi_encode())
+            .ok()
+            .and_then(|data| ITransferHook::checkTransferCall::abi_decode_returns(&data, true).ok())
+            .map(|approved| approved._0)
+            .unwrap_or(false)
+    }
+    
+    // ========================================================================
+    // MINIMUM TRANSFER AMOUNT
+    // ========================================================================
+    
+    /// Returns whether zero-amount `transfer`/`transfer_from` calls revert
+    pub fn reject_zero_transfers(&self) -> Result<bool, ERC20Error> {
+        Ok(self.flag(FLAG_REJECT_ZERO_TRANSFERS))
+    }
+    
+    /// Makes zero-amount `transfer`/`transfer_from` revert with InvalidAmount when enabled
+    /// ERC-20 requires zero transfers to succeed, so enabling this may break integrations
+    /// that send zero as a ping. Can only be called by owner
+    pub fn set_reject_zero_transfers(&mut self, enabled: bool) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        let old_value = self.flag(FLAG_REJECT_ZERO_TRANSFERS);
+        self.set_flag(FLAG_REJECT_ZERO_TRANSFERS, enabled);
+        self.emit_config_changed(CONFIG_REJECT_ZERO_TRANSFERS, U256::from(old_value), U256::from(enabled));
+        Ok(true)
+    }
+    
+    /// Returns the minimum nonzero transfer amount
+    pub fn min_transfer_amount(&self) -> Result<U256, ERC20Error> {
+        Ok(self.min_transfer_amount.get())
+    }
+    
+    /// Returns whether the minimum transfer amount is enforced
+    pub fn min_transfer_enabled(&self) -> Result<bool, ERC20Error> {
+        Ok(self.flag(FLAG_MIN_TRANSFER_ENABLED))
+    }
+    
+    /// Sets the minimum nonzero transfer amount and whether it is enforced
     /// Can only be called by owner
-    pub fn set_supply_cap_enabled(&mut self, enabled: bool) -> Result<bool, ERC20Error> {
+    pub fn set_min_transfer_amount(&mut self, min_amount: U256, enabled: bool) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
         self.only_owner()?;
-        self.supply_cap_enabled.set(enabled);
+        let old_amount = self.min_transfer_amount.get();
+        let old_enabled = self.flag(FLAG_MIN_TRANSFER_ENABLED);
+        self.min_transfer_amount.set(min_amount);
+        self.set_flag(FLAG_MIN_TRANSFER_ENABLED, enabled);
+        self.emit_config_changed(CONFIG_MIN_TRANSFER_AMOUNT, old_amount, min_amount);
+        self.emit_config_changed(CONFIG_MIN_TRANSFER_ENABLED, U256::from(old_enabled), U256::from(enabled));
         Ok(true)
     }
     
+    /// Internal function checking a transfer against the minimum amount
+    /// Zero-amount transfers (ERC-20 compatibility) and whitelisted senders are exempt
+    fn is_below_minimum(&self, from: Address, amount: U256) -> bool {
+        self.flag(FLAG_MIN_TRANSFER_ENABLED)
+            && amount != U256::ZERO
+            && amount < self.min_transfer_amount.get()
+            && !self.transfer_whitelist.get(from)
+    }
+    
     // ========================================================================
-    // ROLE-BASED ACCESS CONTROL (RBAC)
+    // PER-ACCOUNT OUTFLOW LIMITS
     // ========================================================================
     
-    /// Returns true if `account` has the given role
-    pub fn has_role(&self, role: u32, account: Address) -> Result<bool, ERC20Error> {
-        Ok(self.roles.getter(bytes32_from_u32(role)).get(account))
+    /// Returns the outflow limit of `account` as (limit, window_seconds, window_start, used)
+    pub fn account_outflow(&self, account: Address) -> Result<(U256, U256, U256, U256), ERC20Error> {
+        let outflow = self.outflow_limits.getter(account);
+        Ok((
+            outflow.limit.get(),
+            outflow.window.get(),
+            outflow.window_start.get(),
+            outflow.used.get(),
+        ))
     }
     
-    /// Returns the admin role for a given role
-    pub fn get_role_admin(&self, role: u32) -> Result<u32, ERC20Error> {
-        Ok(self.role_admins.get(bytes32_from_u32(role)))
+    /// Returns every per-account restriction in one call as
+    /// (blacklisted, frozen, cooldown_until, outflow_used)
+    /// `blacklisted` is true only once a (scheduled) blacklisting is in effect; `frozen` reports
+    /// the transfer deny list; `cooldown_until` is always 0 as this token has no transfer
+    /// cooldown; `outflow_used` is the usage in the current window (0 once it has elapsed)
+    pub fn account_status(&self, account: Address) -> Result<(bool, bool, U256, U256), ERC20Error> {
+        let outflow = self.outflow_limits.getter(account);
+        let window_end = outflow.window_start.get().saturating_add(outflow.window.get());
+        let outflow_used = if self.current_time() >= window_end {
+            U256::ZERO
+        } else {
+            outflow.used.get()
+        };
+        
+        Ok((
+            self.flag(FLAG_BLACKLIST_ENABLED) && self.is_blacklist_active(account),
+            self.transfer_denied.get(account),
+            U256::ZERO,
+            outflow_used,
+        ))
     }
     
-    /// Grants a role to an account
-    /// Can only be called by accounts with the admin role
-    pub fn grant_role(&mut self, role: u32, account: Address) -> Result<bool, ERC20Error> {
-        let admin_role = self.role_admins.get(bytes32_from_u32(role));
-        if !self.roles.getter(bytes32_from_u32(admin_role)).get(msg::sender()) {
-            return Err(ERC20Error::AccessDenied(AccessDenied {
-                account: msg::sender(),
-                role: bytes32_from_u32(admin_role),
-            }));
-        }
+    /// Caps how much can leave `account` per `window_seconds`
+    /// A zero limit removes the cap. Can only be called by owner
+    pub fn set_account_outflow_limit(
+        &mut self,
+        account: Address,
+        limit: U256,
+        window_seconds: U256,
+    ) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
         
-        if account == Address::ZERO {
-            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
-        }
+        self.only_owner()?;
         
-        let was_granted = self.roles.setter(bytes32_from_u32(role)).setter(account).get();
-        if was_granted {
-            return Err(ERC20Error::RoleAlreadyGranted(RoleAlreadyGranted {
-                role: bytes32_from_u32(role),
-                account,
-            }));
+        if limit != U256::ZERO && window_seconds == U256::ZERO {
+            return Err(ERC20Error::InvalidAmount(InvalidAmount {}));
         }
         
-        self.roles.setter(bytes32_from_u32(role)).setter(account).set(true);
-        
-        evm::log(RoleGranted {
-            role: bytes32_from_u32(role),
-            account,
-            sender: msg::sender(),
-        });
+        let now = self.current_time();
+        let mut outflow = self.outflow_limits.setter(account);
+        outflow.limit.set(limit);
+        outflow.window.set(window_seconds);
+        outflow.window_start.set(now);
+        outflow.used.set(U256::ZERO);
         
         Ok(true)
     }
     
-    /// Revokes a role from an account
-    /// Can only be called by accounts with the admin role
-    pub fn revoke_role(&mut self, role: u32, account: Address) -> Result<bool, ERC20Error> {
-        let admin_role = self.role_admins.get(bytes32_from_u32(role));
-        if !self.roles.getter(bytes32_from_u32(admin_role)).get(msg::sender()) {
-            return Err(ERC20Error::AccessDenied(AccessDenied {
-                account: msg::sender(),
-                role: bytes32_from_u32(admin_role),
-            }));
+    /// Internal function returning whether `amount` would push `account` over its outflow limit
+    /// Read-only counterpart of `consume_outflow`, used by `can_transfer`
+    fn exceeds_outflow(&self, account: Address, amount: U256) -> bool {
+        let outflow = self.outflow_limits.getter(account);
+        let limit = outflow.limit.get();
+        if limit == U256::ZERO {
+            return false;
         }
         
-        if account == Address::ZERO {
-            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
+        // An elapsed window would be reset before the amount is recorded
+        let window_end = outflow.window_start.get().saturating_add(outflow.window.get());
+        let used = if self.current_time() >= window_end {
+            U256::ZERO
+        } else {
+            outflow.used.get()
+        };
+        used.saturating_add(amount) > limit
+    }
+    
+    /// Internal function recording `amount` against `account`'s outflow window
+    fn consume_outflow(&mut self, account: Address, amount: U256) -> Result<(), ERC20Error> {
+        let limit = self.outflow_limits.getter(account).limit.get();
+        if limit == U256::ZERO {
+            return Ok(());
         }
         
-        let was_revoked = self.roles.setter(bytes32_from_u32(role)).setter(account).get();
-        if !was_revoked {
-            return Err(ERC20Error::RoleAlreadyRevoked(RoleAlreadyRevoked {
-                role: bytes32_from_u32(role),
+        let now = self.current_time();
+        let mut outflow = self.outflow_limits.setter(account);
+        
+        // Start a new window once the current one has elapsed
+        if now >= outflow.window_start.get().saturating_add(outflow.window.get()) {
+            outflow.window_start.set(now);
+            outflow.used.set(U256::ZERO);
+        }
+        
+        let used = outflow.used.get().saturating_add(amount);
+        if used > limit {
+            return Err(ERC20Error::AccountOutflowExceeded(AccountOutflowExceeded {
                 account,
+                used,
+                limit,
             }));
         }
+        outflow.used.set(used);
         
-        self.roles.setter(bytes32_from_u32(role)).setter(account).set(false);
-        
-        evm::log(RoleRevoked {
-            role: bytes32_from_u32(role),
-            account,
-            sender: msg::sender(),
-        });
-        
-        Ok(true)
-    }
-    
-    /// Revokes role from self (useful for voluntarily giving up roles)
-    pub fn renounce_role(&mut self, role: u32) -> Result<bool, ERC20Error> {
-        self.roles.setter(bytes32_from_u32(role)).setter(msg::sender()).set(false);
-        
-        evm::log(RoleRevoked {
-            role: bytes32_from_u32(role),
-            account: msg::sender(),
-            sender: msg::sender(),
-        });
-        
-        Ok(true)
+        Ok(())
     }
     
     // ========================================================================
-    // BLACKLIST FUNCTIONALITY
+    // TRANSFER FEES
     // ========================================================================
     
-    /// Returns whether an address is blacklisted
-    pub fn is_blacklisted(&self, account: Address) -> Result<bool, ERC20Error> {
-        Ok(self.blacklisted.get(account))
+    /// Returns the transfer fee in basis points
+    pub fn fee_bps(&self) -> Result<U256, ERC20Error> {
+        Ok(self.fee_bps.get())
     }
     
-    /// Returns whether blacklist functionality is enabled
-    pub fn blacklist_enabled(&self) -> Result<bool, ERC20Error> {
-        Ok(self.blacklist_enabled.get())
+    /// Returns the recipient of the transfer fee when no splits are configured
+    pub fn fee_recipient(&self) -> Result<Address, ERC20Error> {
+        Ok(self.fee_recipient.get())
     }
     
-    /// Blacklists an address (prevents transfers to/from)
+    /// Returns the configured fee splits as (recipients, bps)
+    pub fn fee_splits(&self) -> Result<(alloc::vec::Vec<Address>, alloc::vec::Vec<U256>), ERC20Error> {
+        let mut recipients = alloc::vec::Vec::new();
+        let mut bps = alloc::vec::Vec::new();
+        for i in 0..self.fee_split_recipients.len() {
+            recipients.push(self.fee_split_recipients.get(i).unwrap_or(Address::ZERO));
+            bps.push(self.fee_split_bps.get(i).unwrap_or(U256::ZERO));
+        }
+        Ok((recipients, bps))
+    }
+    
+    /// Returns true if transfer fees round up instead of down
+    pub fn fee_rounding(&self) -> Result<bool, ERC20Error> {
+        Ok(self.flag(FLAG_FEE_ROUND_UP))
+    }
+    
+    /// Selects the fee rounding direction (true = round up, false = round down)
+    /// Rounding up means any nonzero transfer pays at least 1 unit of fee when fees are enabled
     /// Can only be called by owner
-    pub fn blacklist(&mut self, account: Address) -> Result<bool, ERC20Error> {
+    pub fn set_fee_rounding(&mut self, round_up: bool) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        let old_value = self.flag(FLAG_FEE_ROUND_UP);
+        self.set_flag(FLAG_FEE_ROUND_UP, round_up);
+        self.emit_config_changed(CONFIG_FEE_ROUND_UP, U256::from(old_value), U256::from(round_up));
+        Ok(true)
+    }
+    
+    /// Sets the transfer fee (in basis points, at most MAX_FEE_BPS) and its recipient
+    /// Configured splits must still sum to the new fee. Can only be called by owner
+    pub fn set_transfer_fee(&mut self, fee_bps: U256, recipient: Address) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
         self.only_owner()?;
         
-        if account == Address::ZERO {
+        if fee_bps > U256::from(MAX_FEE_BPS) {
+            return Err(ERC20Error::FeeTooHigh(FeeTooHigh {
+                fee_bps,
+                max_bps: U256::from(MAX_FEE_BPS),
+            }));
+        }
+        
+        if fee_bps != U256::ZERO && recipient == Address::ZERO {
             return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
         }
         
-        if self.blacklisted.get(account) {
-            return Err(ERC20Error::AddressBlacklisted(AddressBlacklisted { account }));
+        let split_count = self.fee_split_bps.len();
+        if split_count != 0 {
+            let mut total_bps = U256::ZERO;
+            for i in 0..split_count {
+                total_bps += self.fee_split_bps.get(i).unwrap_or(U256::ZERO);
+            }
+            if total_bps != fee_bps {
+                return Err(ERC20Error::FeeSplitMismatch(FeeSplitMismatch { total_bps, fee_bps }));
+            }
         }
         
-        self.blacklisted.setter(account).set(true);
+        let old_fee_bps = self.fee_bps.get();
+        self.fee_bps.set(fee_bps);
+        self.fee_recipient.set(recipient);
+        self.emit_config_changed(CONFIG_FEE_BPS, old_fee_bps, fee_bps);
         
-        evm::log(AddressBlacklisted {
-            account,
-            operator: msg::sender(),
-            timestamp: U256::from(msg::epoch()),
+        log(self.vm(), TransferFeeUpdated {
+            fee_bps,
+            fee_recipient: recipient,
         });
         
         Ok(true)
     }
     
-    /// Removes an address from blacklist
+    /// Splits the transfer fee among several recipients (e.g. treasury, burn, rewards)
+    /// `bps` must sum to `fee_bps`; pass empty vectors to send the whole fee to `fee_recipient`
     /// Can only be called by owner
-    pub fn unblacklist(&mut self, account: Address) -> Result<bool, ERC20Error> {
+    pub fn set_fee_splits(
+        &mut self,
+        recipients: alloc::vec::Vec<Address>,
+        bps: alloc::vec::Vec<U256>,
+    ) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
         self.only_owner()?;
         
-        if !self.blacklisted.get(account) {
-            return Err(ERC20Error::AddressNotBlacklisted(AddressNotBlacklisted { account }));
+        if recipients.len() != bps.len() {
+            return Err(ERC20Error::FeeSplitLengthMismatch(FeeSplitLengthMismatch {}));
         }
         
-        self.blacklisted.setter(account).set(false);
+        let fee_bps = self.fee_bps.get();
+        let mut total_bps = U256::ZERO;
+        for (i, recipient) in recipients.iter().enumerate() {
+            if *recipient == Address::ZERO {
+                return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
+            }
+            total_bps = total_bps.saturating_add(bps[i]);
+        }
         
-        evm::log(AddressUnblacklisted {
-            account,
-            operator: msg::sender(),
-            timestamp: U256::from(msg::epoch()),
+        if !recipients.is_empty() && total_bps != fee_bps {
+            return Err(ERC20Error::FeeSplitMismatch(FeeSplitMismatch { total_bps, fee_bps }));
+        }
+        
+        // Replace the previous configuration
+        while !self.fee_split_recipients.is_empty() {
+            self.fee_split_recipients.pop();
+            self.fee_split_bps.pop();
+        }
+        for (i, recipient) in recipients.iter().enumerate() {
+            self.fee_split_recipients.push(*recipient);
+            self.fee_split_bps.push(bps[i]);
+        }
+        
+        log(self.vm(), FeeSplitsUpdated {
+            count: U256::from(recipients.len()),
         });
         
         Ok(true)
     }
     
-    /// Enables or disables blacklist functionality
-    /// Can only be called by owner
-    pub fn set_blacklist_enabled(&mut self, enabled: bool) -> Result<bool, ERC20Error> {
-        self.only_owner()?;
-        self.blacklist_enabled.set(enabled);
-        Ok(true)
+    /// Internal function crediting the fee on a transfer of `amount` from `from`
+    /// Returns the total fee deducted from the amount delivered to the recipient
+    fn collect_transfer_fee(&mut self, from: Address, amount: U256) -> Result<U256, ERC20Error> {
+        let fee_bps = self.fee_bps.get();
+        if fee_bps == U256::ZERO {
+            return Ok(U256::ZERO);
+        }
+        
+        let split_count = self.fee_split_recipients.len();
+        let total_fee = self.compute_fee(amount, fee_bps);
+        if total_fee == U256::ZERO {
+            return Ok(U256::ZERO);
+        }
+        
+        // Fees are held by the contract and accrued per recipient; recipients pull them
+        // with `claim_fees`, so no recipient code runs on the transfer path
+        let this = self.vm().contract_address();
+        self.credit_balance(this, total_fee)?;
+        self.fee_custody.set(self.fee_custody.get() + total_fee);
+        log(self.vm(), Transfer { from, to: this, amount: total_fee });
+        
+        // Single recipient
+        if split_count == 0 {
+            let recipient = self.fee_recipient.get();
+            self.accrue_fee(recipient, total_fee);
+            return Ok(total_fee);
+        }
+        
+        // Split among several recipients; the sender is debited only once by the caller.
+        // Shares round down and the rounding remainder goes to the first recipient,
+        // so the shares always sum to exactly `total_fee`
+        let mut shares = alloc::vec::Vec::with_capacity(split_count);
+        let mut distributed = U256::ZERO;
+        for i in 0..split_count {
+            let (share, _) = Self::mul_bps(amount, self.fee_split_bps.get(i).unwrap_or(U256::ZERO));
+            distributed += share;
+            shares.push(share);
+        }
+        shares[0] += total_fee.saturating_sub(distributed);
+        
+        for (i, share) in shares.into_iter().enumerate() {
+            if share == U256::ZERO {
+                continue;
+            }
+            let recipient = self.fee_split_recipients.get(i).unwrap_or(Address::ZERO);
+            self.accrue_fee(recipient, share);
+        }
+        
+        Ok(total_fee)
     }
     
-    // ========================================================================
-    // SNAPSHOT FUNCTIONALITY
-    // ========================================================================
-    
-    /// Returns the current snapshot ID (0 if no snapshot in progress)
-    pub fn current_snapshot_id(&self) -> Result<U256, ERC20Error> {
-        Ok(self.current_snapshot_id.get())
+    /// Internal function adding `amount` to `recipient`'s claimable fees
+    fn accrue_fee(&mut self, recipient: Address, amount: U256) {
+        let accrued = self.fee_balances.get(recipient);
+        self.fee_balances.setter(recipient).set(accrued + amount);
     }
     
-    /// Returns the next available snapshot ID
-    pub fn next_snapshot_id(&self) -> Result<U256, ERC20Error> {
-        Ok(self.next_snapshot_id.get())
+    /// Returns the fees `account` can pull with `claim_fees`
+    pub fn claimable_fees(&self, account: Address) -> Result<U256, ERC20Error> {
+        Ok(self.fee_balances.get(account))
     }
     
-    /// Starts a new snapshot
-    /// Can only be called by owner
-    pub fn snapshot(&mut self) -> Result<U256, ERC20Error> {
-        self.only_owner()?;
-        
-        // Cannot start a new snapshot if one is already in progress
-        if self.current_snapshot_id.get() != U256::ZERO {
-            return Err(ERC20Error::SnapshotInProgress(SnapshotInProgress {}));
-        }
+    /// Pulls all fees accrued to the caller from the contract's custody
+    pub fn claim_fees(&mut self) -> Result<U256, ERC20Error> {
+        self.require_initialized()?;
         
-        let snapshot_id = self.next_snapshot_id.get();
-        self.current_snapshot_id.set(snapshot_id);
+        let account = self.vm().msg_sender();
         
-        // Record balances for snapshot
-        // Note: In practice, this would iterate through all addresses
-        // For now, we just mark the snapshot as started
+        if self.is_paused() {
+            return Err(ERC20Error::ContractPaused(ContractPaused {}));
+        }
         
-        evm::log(SnapshotTaken {
-            snapshot_id,
-            timestamp: U256::from(msg::epoch()),
-            total_supply: self.total_supply.get(),
-        });
+        let amount = self.fee_balances.get(account);
+        if amount == U256::ZERO {
+            return Err(ERC20Error::NothingToWithdraw(NothingToWithdraw { account }));
+        }
         
-        Ok(snapshot_id)
-    }
-    
-    /// Finalizes a snapshot (called after all balances are recorded)
-    pub fn finalize_snapshot(&mut self) -> Result<U256, ERC20Error> {
-        self.only_owner()?;
+        let this = self.vm().contract_address();
+        self.check_restrictions(this, account)?;
         
-        let snapshot_id = self.current_snapshot_id.get();
-        if snapshot_id == U256::ZERO {
-            return Err(ERC20Error::SnapshotNotFound(SnapshotNotFound { snapshot_id }));
-        }
+        let custody = self.fee_custody.get();
+        let new_custody = custody
+            .checked_sub(amount)
+            .ok_or(ERC20Error::InsufficientBalance(InsufficientBalance {
+                balance: custody,
+                required: amount,
+            }))?;
+        let this_balance = self.balances.get(this);
+        let new_this_balance = this_balance
+            .checked_sub(amount)
+            .ok_or(ERC20Error::InsufficientBalance(InsufficientBalance {
+                balance: this_balance,
+                required: amount,
+            }))?;
         
-        // Increment next snapshot ID
-        self.next_snapshot_id.set(snapshot_id.checked_add(U256::from(1))
-            .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))?);
+        // Effects before the balance move
+        self.fee_balances.setter(account).set(U256::ZERO);
+        self.fee_custody.set(new_custody);
         
-        // Clear current snapshot
-        self.current_snapshot_id.set(U256::ZERO);
+        // Move straight out of custody: claims are not themselves charged a fee
+        self.checkpoint_balance(this);
+        self.balances.setter(this).set(new_this_balance);
+        self.credit_balance(account, amount)?;
         
-        Ok(snapshot_id)
-    }
-    
-    /// Returns the balance at a specific snapshot
-    pub fn balance_of_at(&self, account: Address, snapshot_id: U256) -> Result<U256, ERC20Error> {
-        if snapshot_id >= self.next_snapshot_id.get() {
-            return Err(ERC20Error::SnapshotNotFound(SnapshotNotFound { snapshot_id }));
-        }
+        log(self.vm(), Transfer { from: this, to: account, amount });
+        log(self.vm(), FeesClaimed { account, amount });
         
-        // For simplicity, return current balance
-        // In full implementation, would read from snapshot storage
-        Ok(self.balances.get(account))
+        Ok(amount)
     }
     
-    /// Returns the total supply at a specific snapshot
-    pub fn total_supply_at(&self, snapshot_id: U256) -> Result<U256, ERC20Error> {
-        if snapshot_id >= self.next_snapshot_id.get() {
-            return Err(ERC20Error::SnapshotNotFound(SnapshotNotFound { snapshot_id }));
+    /// Internal function computing `amount * bps / BPS_DENOMINATOR` in the configured rounding direction
+    /// Fees are moved to recipients rather than burned, so total supply is conserved under either mode;
+    /// a rounded-up amount never exceeds `amount` because `bps <= BPS_DENOMINATOR`
+    fn compute_fee(&self, amount: U256, bps: U256) -> U256 {
+        let (fee, inexact) = Self::mul_bps(amount, bps);
+        
+        if self.flag(FLAG_FEE_ROUND_UP) && inexact {
+            fee + U256::from(1)
+        } else {
+            fee
         }
-        
-        // For simplicity, return current supply
-        // In full implementation, would read from snapshot storage
-        Ok(self.total_supply.get())
     }
     
     // ========================================================================
-    // TIME-LOCKED OWNERSHIP TRANSFER
+    // TOKEN VESTING
     // ========================================================================
     
-    /// Returns the pending owner (Address::ZERO if none)
-    pub fn pending_owner(&self) -> Result<Address, ERC20Error> {
-        Ok(self.pending_owner.get())
+    /// Returns the vesting schedule of `beneficiary`
+    /// as (total_amount, released, start, duration)
+    pub fn vesting_schedule(&self, beneficiary: Address) -> Result<(U256, U256, U256, U256), ERC20Error> {
+        let schedule = self.vesting_schedules.getter(beneficiary);
+        Ok((
+            schedule.total_amount.get(),
+            schedule.released.get(),
+            schedule.start.get(),
+            schedule.duration.get(),
+        ))
     }
     
-    /// Returns the unlock time for pending ownership transfer
-    pub fn ownership_unlock_time(&self) -> Result<U256, ERC20Error> {
-        Ok(self.ownership_unlock_time.get())
+    /// Returns the amount vested so far for `beneficiary` (released or not)
+    pub fn vested_amount(&self, beneficiary: Address) -> Result<U256, ERC20Error> {
+        Ok(self.compute_vested(beneficiary))
     }
     
-    /// Returns the ownership transfer delay
-    pub fn ownership_transfer_delay(&self) -> Result<U256, ERC20Error> {
-        Ok(self.ownership_transfer_delay.get())
+    /// Returns the amount that `release` would currently release for `beneficiary`
+    pub fn releasable_amount(&self, beneficiary: Address) -> Result<U256, ERC20Error> {
+        let released = self.vesting_schedules.getter(beneficiary).released.get();
+        Ok(self.compute_vested(beneficiary).saturating_sub(released))
     }
     
-    /// Initiates ownership transfer to a new account
-    /// The new owner must accept ownership after the time-lock period
-    pub fn initiate_ownership_transfer(
+    /// Returns released tokens that could not be delivered to `beneficiary`
+    pub fn pending_withdrawal(&self, beneficiary: Address) -> Result<U256, ERC20Error> {
+        Ok(self.pending_withdrawals.get(beneficiary))
+    }
+    
+    /// Creates a linear vesting schedule for `beneficiary`
+    /// `amount` is moved from the caller into contract custody
+    /// Can only be called by owner
+    pub fn create_vesting_schedule(
         &mut self,
-        new_owner: Address,
+        beneficiary: Address,
+        amount: U256,
+        start: U256,
+        duration: U256,
     ) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
         self.only_owner()?;
         
-        if new_owner == Address::ZERO {
+        if beneficiary == Address::ZERO {
             return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
         }
         
-        // Cancel any pending transfer first
-        if self.pending_owner.get() != Address::ZERO {
-            self.cancel_ownership_transfer()?;
+        if amount == U256::ZERO || duration == U256::ZERO {
+            return Err(ERC20Error::InvalidAmount(InvalidAmount {}));
         }
         
-        let current_time = U256::from(msg::epoch());
-        let unlock_time = current_time.checked_add(self.ownership_transfer_delay.get())
-            .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))?;
+        if self.vesting_schedules.getter(beneficiary).total_amount.get() != U256::ZERO {
+            return Err(ERC20Error::VestingScheduleExists(VestingScheduleExists { beneficiary }));
+        }
         
-        self.pending_owner.set(new_owner);
-        self.ownership_unlock_time.set(unlock_time);
+        // Take custody of the vesting tokens
+        self.internal_transfer(self.vm().msg_sender(), self.vm().contract_address(), amount)?;
+        self.vesting_custody.set(self.vesting_custody.get() + amount);
         
-        evm::log(OwnershipTransferInitiated {
-            owner: self.owner.get(),
-            new_owner,
-            unlock_time,
+        let mut schedule = self.vesting_schedules.setter(beneficiary);
+        schedule.total_amount.set(amount);
+        schedule.released.set(U256::ZERO);
+        schedule.start.set(start);
+        schedule.duration.set(duration);
+        
+        log(self.vm(), VestingScheduleCreated {
+            beneficiary,
+            amount,
+            start,
+            duration,
         });
         
         Ok(true)
     }
     
-    /// Accepts ownership transfer (called by pending owner after time-lock)
-    pub fn accept_ownership(&mut self) -> Result<bool, ERC20Error> {
-        let pending_owner = self.pending_owner.get();
-        if pending_owner == Address::ZERO {
-            return Err(ERC20Error::NoPendingOwnershipTransfer(NoPendingOwnershipTransfer {}));
+    /// Releases the currently vested tokens of `beneficiary`
+    /// If a transfer restriction blocks delivery (blacklist, whitelist, deny list, hook
+    /// rejection) the amount is credited to `pending_withdrawal` instead of reverting;
+    /// any other failure reverts. Callable by anyone
+    pub fn release(&mut self, beneficiary: Address) -> Result<U256, ERC20Error> {
+        self.require_initialized()?;
+        
+        if self.is_paused() {
+            return Err(ERC20Error::ContractPaused(ContractPaused {}));
         }
         
-        if msg::sender() != pending_owner {
-            return Err(ERC20Error::NotOwner(NotOwner {
-                caller: msg::sender(),
-                owner: pending_owner,
-            }));
+        self.release_vested(beneficiary)
+    }
+    
+    /// Releases vested tokens for several beneficiaries
+    /// A beneficiary that cannot receive is deferred and does not block the others
+    pub fn batch_release(
+        &mut self,
+        beneficiaries: alloc::vec::Vec<Address>,
+    ) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        self.check_batch_size(beneficiaries.len())?;
+        
+        if self.is_paused() {
+            return Err(ERC20Error::ContractPaused(ContractPaused {}));
         }
         
-        let current_time = U256::from(msg::epoch());
-        let unlock_time = self.ownership_unlock_time.get();
-        if current_time < unlock_time {
-            return Err(ERC20Error::OwnershipTransferNotYetUnlockable(
-                OwnershipTransferNotYetUnlockable {
-                    current_time,
-                    unlock_time,
-                },
-            ));
+        for (i, beneficiary) in beneficiaries.into_iter().enumerate() {
+            self.release_vested(beneficiary)
+                .map_err(|e| batch_item_failed(i, e))?;
+        }
+        
+        Ok(true)
+    }
+    
+    /// Withdraws the caller's deferred vesting releases
+    /// Reverts if delivery still fails, leaving the pending balance intact
+    pub fn withdraw_pending(&mut self) -> Result<U256, ERC20Error> {
+        self.require_initialized()?;
+        
+        let beneficiary = self.vm().msg_sender();
+        
+        if self.is_paused() {
+            return Err(ERC20Error::ContractPaused(ContractPaused {}));
         }
         
-        let previous_owner = self.owner.get();
-        self.owner.set(pending_owner);
-        self.pending_owner.set(Address::ZERO);
-        self.ownership_unlock_time.set(U256::ZERO);
+        let amount = self.pending_withdrawals.get(beneficiary);
+        if amount == U256::ZERO {
+            return Err(ERC20Error::NothingToWithdraw(NothingToWithdraw { account: beneficiary }));
+        }
         
-        evm::log(OwnershipTransferExecuted {
-            previous_owner,
-            new_owner: pending_owner,
-        });
+        self.pending_withdrawals.setter(beneficiary).set(U256::ZERO);
+        self.internal_transfer(self.vm().contract_address(), beneficiary, amount)?;
+        self.vesting_custody.set(self.vesting_custody.get() - amount);
         
-        evm::log(OwnershipTransferred {
-            previous_owner,
-            new_owner: pending_owner,
+        log(self.vm(), PendingWithdrawn {
+            beneficiary,
+            amount,
         });
         
-        Ok(true)
+        Ok(amount)
     }
     
-    /// Cancels a pending ownership transfer
-    pub fn cancel_ownership_transfer(&mut self) -> Result<bool, ERC20Error> {
-        self.only_owner()?;
+    /// Returns the contract's own-token balance reserved for vesting
+    pub fn vesting_custody(&self) -> Result<U256, ERC20Error> {
+        Ok(self.vesting_custody.get())
+    }
+    
+    /// Internal function releasing vested tokens, deferring delivery to a restricted beneficiary
+    fn release_vested(&mut self, beneficiary: Address) -> Result<U256, ERC20Error> {
+        if self.vesting_schedules.getter(beneficiary).total_amount.get() == U256::ZERO {
+            return Err(ERC20Error::NoVestingSchedule(NoVestingSchedule { beneficiary }));
+        }
         
-        let pending_owner = self.pending_owner.get();
-        if pending_owner == Address::ZERO {
-            return Err(ERC20Error::NoPendingOwnershipTransfer(NoPendingOwnershipTransfer {}));
+        let released = self.vesting_schedules.getter(beneficiary).released.get();
+        let amount = self.compute_vested(beneficiary).saturating_sub(released);
+        if amount == U256::ZERO {
+            return Ok(U256::ZERO);
         }
         
-        let cancelled_owner = pending_owner;
-        self.pending_owner.set(Address::ZERO);
-        self.ownership_unlock_time.set(U256::ZERO);
+        self.vesting_schedules.setter(beneficiary).released.set(released + amount);
         
-        evm::log(OwnershipTransferCancelled {
-            owner: self.owner.get(),
-            new_owner: cancelled_owner,
-        });
+        // Only a transfer policy restriction on the beneficiary defers the release;
+        // any other failure (pause, accounting) reverts the whole call
+        let this = self.vm().contract_address();
+        let reason = self.transfer_failure_reason(this, beneficiary, amount);
+        if is_restriction_reason(reason) {
+            let pending = self.pending_withdrawals.get(beneficiary)
+                .checked_add(amount)
+                .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))?;
+            self.pending_withdrawals.setter(beneficiary).set(pending);
+            
+            log(self.vm(), ReleaseDeferred { beneficiary, amount });
+            return Ok(amount);
+        }
         
-        Ok(true)
+        self.internal_transfer(this, beneficiary, amount)?;
+        self.vesting_custody.set(self.vesting_custody.get() - amount);
+        log(self.vm(), TokensReleased { beneficiary, amount });
+        
+        Ok(amount)
     }
     
-    /// Sets the ownership transfer delay
-    pub fn set_ownership_transfer_delay(&mut self, delay_seconds: U256) -> Result<bool, ERC20Error> {
-        self.only_owner()?;
-        self.ownership_transfer_delay.set(delay_seconds);
-        Ok(true)
+    /// Internal function computing the linearly vested amount at the current time
+    fn compute_vested(&self, beneficiary: Address) -> U256 {
+        let schedule = self.vesting_schedules.getter(beneficiary);
+        let total = schedule.total_amount.get();
+        let start = schedule.start.get();
+        let duration = schedule.duration.get();
+        let now = self.current_time();
+        
+        if total == U256::ZERO || now < start {
+            return U256::ZERO;
+        }
+        
+        let elapsed = now - start;
+        if elapsed >= duration {
+            return total;
+        }
+        
+        total.saturating_mul(elapsed) / duration
     }
     
     // ========================================================================
-    // EMERGENCY FEATURES
+    // HISTORICAL TOTAL SUPPLY
     // ========================================================================
     
-    /// Returns the emergency admin address
-    pub fn emergency_admin(&self) -> Result<Address, ERC20Error> {
-        Ok(self.emergency_admin.get())
-    }
-    
-    /// Returns the guardian address
-    pub fn guardian(&self) -> Result<Address, ERC20Error> {
-        Ok(self.guardian.get())
-    }
-    
-    /// Sets the emergency admin (for recovery scenarios)
-    pub fn set_emergency_admin(&mut self, new_admin: Address) -> Result<bool, ERC20Error> {
-        self.only_owner()?;
+    /// Returns the total supply at the end of `block_number`
+    /// `block_number` must already be mined (strictly before the current block)
+    pub fn get_past_total_supply(&self, block_number: U256) -> Result<U256, ERC20Error> {
+        let current_block = U256::from(self.vm().block_number());
+        if block_number >= current_block {
+            return Err(ERC20Error::FutureLookup(FutureLookup {
+                block_number,
+                current_block,
+            }));
+        }
         
-        let old_admin = self.emergency_admin.get();
-        self.emergency_admin.set(new_admin);
+        // Binary search for the last checkpoint at or before `block_number`
+        let mut low = U256::ZERO;
+        let mut high = self.supply_checkpoint_count.get();
+        while low < high {
+            let mid = (low + high) / U256::from(2);
+            if self.supply_checkpoints.getter(mid).from_block.get() > block_number {
+                high = mid;
+            } else {
+                low = mid + U256::from(1);
+            }
+        }
         
-        evm::log(EmergencyAdminChanged {
-            old_admin,
-            new_admin,
-        });
+        if high == U256::ZERO {
+            return Ok(U256::ZERO);
+        }
         
-        Ok(true)
+        Ok(self.supply_checkpoints.getter(high - U256::from(1)).value.get())
     }
     
-    /// Sets the guardian (trusted third party for emergency pause)
-    pub fn set_guardian(&mut self, new_guardian: Address) -> Result<bool, ERC20Error> {
-        self.only_owner()?;
+    /// Internal function updating total supply and its per-block checkpoint
+    /// Every mint and burn goes through here
+    fn set_total_supply(&mut self, new_supply: U256) {
+        self.total_supply.set(new_supply);
         
-        let old_guardian = self.guardian.get();
-        self.guardian.set(new_guardian);
-        self.guardian_enabled.set(new_guardian != Address::ZERO);
+        let current_block = U256::from(self.vm().block_number());
+        let count = self.supply_checkpoint_count.get();
         
-        evm::log(GuardianUpdated {
-            old_guardian,
-            new_guardian,
-        });
+        // Overwrite the latest checkpoint if it belongs to this block
+        if count != U256::ZERO {
+            let last = count - U256::from(1);
+            if self.supply_checkpoints.getter(last).from_block.get() == current_block {
+                self.supply_checkpoints.setter(last).value.set(new_supply);
+                return;
+            }
+        }
         
-        Ok(true)
+        let mut checkpoint = self.supply_checkpoints.setter(count);
+        checkpoint.from_block.set(current_block);
+        checkpoint.value.set(new_supply);
+        self.supply_checkpoint_count.set(count + U256::from(1));
     }
     
-    /// Emergency pause by guardian
-    pub fn guardian_pause(&mut self) -> Result<bool, ERC20Error> {
-        if !self.guardian_enabled.get() || msg::sender() != self.guardian.get() {
-            return Err(ERC20Error::AccessDenied(AccessDenied {
-                account: msg::sender(),
-                role: bytes32_from_u32(PAUSER_ROLE),
-            }));
-        }
+    // ========================================================================
+    // TRANSFER DENY LIST
+    // ========================================================================
+    
+    /// Returns whether transfers to `account` are denied
+    pub fn is_transfer_denied(&self, account: Address) -> Result<bool, ERC20Error> {
+        Ok(self.transfer_denied.get(account))
+    }
+    
+    /// Denies or re-allows transfers to `account` (e.g. the token contract itself)
+    /// Protocol-level deny, separate from the compliance blacklist. Can only be called by owner
+    pub fn set_transfer_deny(&mut self, account: Address, denied: bool) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
         
-        if self.paused.get() {
-            return Err(ERC20Error::ContractPaused(ContractPaused {}));
-        }
+        self.only_owner()?;
         
-        self.paused.set(true);
+        self.transfer_denied.setter(account).set(denied);
         
-        evm::log(Paused {
-            account: msg::sender(),
-        });
+        log(self.vm(), TransferDenyUpdated { account, denied });
         
         Ok(true)
     }
     
     // ========================================================================
-    // MINTING LIMITS (Rate Limiting)
+    // PRIVILEGED ADDRESSES
     // ========================================================================
     
-    /// Returns the minting period limit
-    pub fn minting_period_limit(&self) -> Result<U256, ERC20Error> {
-        Ok(self.minting_period_limit.get())
-    }
-    
-    /// Returns the minting period duration in seconds
-    pub fn minting_period_duration(&self) -> Result<U256, ERC20Error> {
-        Ok(self.minting_period_duration.get())
+    /// Returns whether `account` is a privileged system address
+    pub fn is_privileged(&self, account: Address) -> Result<bool, ERC20Error> {
+        Ok(self.privileged.get(account))
     }
     
-    /// Sets minting rate limits
-    pub fn set_minting_limits(
-        &mut self,
-        period_limit: U256,
-        period_duration_seconds: U256,
-    ) -> Result<bool, ERC20Error> {
+    /// Marks or unmarks `account` as privileged: transfers from or to it skip the minimum
+    /// transfer amount, outflow limits, fees and burn-on-dead-transfer
+    /// The blacklist still applies. Can only be called by owner
+    pub fn set_privileged(&mut self, account: Address, privileged: bool) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
         self.only_owner()?;
         
-        self.minting_period_limit.set(period_limit);
-        self.minting_period_duration.set(period_duration_seconds);
+        if account == Address::ZERO {
+            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
+        }
+        
+        self.privileged.setter(account).set(privileged);
+        
+        log(self.vm(), PrivilegedUpdated { account, privileged });
         
         Ok(true)
     }
     
     // ========================================================================
-    // TRANSFER WHITELIST
+    // RECEIVER ACKNOWLEDGEMENT
     // ========================================================================
     
-    /// Returns whether an address is whitelisted for transfers
-    pub fn is_transfer_whitelisted(&self, account: Address) -> Result<bool, ERC20Error> {
-        Ok(self.transfer_whitelist.get(account))
+    /// Returns whether transfers to contracts require prior `register_as_receiver`
+    pub fn require_receiver_ack(&self) -> Result<bool, ERC20Error> {
+        Ok(self.flag(FLAG_REQUIRE_RECEIVER_ACK))
     }
     
-    /// Adds an address to the transfer whitelist
-    pub fn add_to_whitelist(&mut self, account: Address) -> Result<bool, ERC20Error> {
+    /// Enables or disables the receiver acknowledgement requirement
+    /// EOAs can always receive. Can only be called by owner
+    pub fn set_require_receiver_ack(&mut self, enabled: bool) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
         self.only_owner()?;
-        self.transfer_whitelist.setter(account).set(true);
+        let old_value = self.flag(FLAG_REQUIRE_RECEIVER_ACK);
+        self.set_flag(FLAG_REQUIRE_RECEIVER_ACK, enabled);
+        self.emit_config_changed(CONFIG_REQUIRE_RECEIVER_ACK, U256::from(old_value), U256::from(enabled));
         Ok(true)
     }
     
-    /// Removes an address from the transfer whitelist
-    pub fn remove_from_whitelist(&mut self, account: Address) -> Result<bool, ERC20Error> {
-        self.only_owner()?;
-        self.transfer_whitelist.setter(account).set(false);
-        Ok(true)
+    /// Returns whether `account` has registered to accept the token
+    pub fn accepts_token(&self, account: Address) -> Result<bool, ERC20Error> {
+        Ok(self.accepts_token.get(account))
     }
     
-    /// Enables or disables transfer restrictions
-    pub fn set_transfer_restrictions_enabled(&mut self, enabled: bool) -> Result<bool, ERC20Error> {
-        self.only_owner()?;
-        self.transfer_restrictions_enabled.set(enabled);
+    /// Registers the calling contract as able to handle the token
+    pub fn register_as_receiver(&mut self) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        let account = self.vm().msg_sender();
+        self.accepts_token.setter(account).set(true);
+        
+        log(self.vm(), ReceiverRegistered { account });
+        
         Ok(true)
     }
     
-    /// Returns whether transfer restrictions are enabled
-    pub fn transfer_restrictions_enabled(&self) -> Result<bool, ERC20Error> {
-        Ok(self.transfer_restrictions_enabled.get())
+    /// Internal function checking `to` may receive under the acknowledgement requirement
+    /// EOAs (no code) always pass; contracts pass once registered
+    fn is_accepting_receiver(&self, to: Address) -> bool {
+        !self.flag(FLAG_REQUIRE_RECEIVER_ACK) || self.accepts_token.get(to) || self.vm().code_size(to) == 0
     }
     
     // ========================================================================
@@ -1547,31 +5608,258 @@ impl ERC20Token {
         Ok(self.initialized_at.get())
     }
     
-    // ========================================================================
-    // BATCH OPERATIONS (Gas Optimization)
-    // ========================================================================
-    
-    /// Batch transfer tokens to multiple recipients
-    pub fn batch_transfer(
+    /// Returns the configured state for post-deployment verification:
+    /// (owner, total_supply, decimals, initialized_at)
+    /// `decimals` is the real token decimals, ignoring any display override
+    pub fn init_summary(&self) -> Result<(Address, U256, u8, U256), ERC20Error> {
+        Ok((
+            self.owner.get(),
+            self.total_supply.get(),
+            self.decimals.get().to_le_bytes::<1>()[0],
+            self.initialized_at.get(),
+        ))
+    }
+    
+    /// Runs version-gated reinitialization after a contract upgrade
+    /// `new_version` must be greater than the current version and each version runs once
+    /// Can only be called by owner
+    pub fn reinitialize(&mut self, new_version: U256) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        
+        let old_version = self.contract_version.get();
+        if new_version <= old_version {
+            return Err(ERC20Error::InvalidVersion(InvalidVersion {
+                current_version: old_version,
+                new_version,
+            }));
+        }
+        
+        if self.initialized_versions.get(new_version) {
+            return Err(ERC20Error::VersionAlreadyInitialized(VersionAlreadyInitialized {
+                version: new_version,
+            }));
+        }
+        
+        // Version-specific migration steps go here
+        self.migrate_legacy_flags();
+        
+        self.initialized_versions.setter(new_version).set(true);
+        self.contract_version.set(new_version);
+        
+        log(self.vm(), Reinitialized {
+            old_version,
+            new_version,
+        });
+        
+        Ok(true)
+    }
+    
+    // ========================================================================
+    // BATCH OPERATIONS (Gas Optimization)
+    // ========================================================================
+    
+    /// Returns the maximum number of items accepted by a single batch call
+    pub fn max_batch_size(&self) -> Result<U256, ERC20Error> {
+        Ok(self.effective_max_batch_size())
+    }
+    
+    /// Sets the maximum number of items accepted by a single batch call
+    /// Can only be called by owner
+    pub fn set_max_batch_size(&mut self, max_batch_size: U256) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        
+        if max_batch_size == U256::ZERO {
+            return Err(ERC20Error::InvalidAmount(InvalidAmount {}));
+        }
+        
+        let old_value = self.max_batch_size.get();
+        self.max_batch_size.set(max_batch_size);
+        self.emit_config_changed(CONFIG_MAX_BATCH_SIZE, old_value, max_batch_size);
+        Ok(true)
+    }
+    
+    /// Internal function returning the configured batch limit, or the default when unset
+    fn effective_max_batch_size(&self) -> U256 {
+        let max = self.max_batch_size.get();
+        if max == U256::ZERO {
+            U256::from(DEFAULT_MAX_BATCH_SIZE)
+        } else {
+            max
+        }
+    }
+    
+    /// Internal function rejecting batches longer than `max_batch_size` before any work is done
+    fn check_batch_size(&self, len: usize) -> Result<(), ERC20Error> {
+        let len = U256::from(len);
+        let max = self.effective_max_batch_size();
+        if len > max {
+            return Err(ERC20Error::BatchTooLarge(BatchTooLarge { len, max }));
+        }
+        
+        Ok(())
+    }
+    
+    /// Batch transfer tokens to multiple recipients
+    pub fn batch_transfer(
+        &mut self,
+        recipients: alloc::vec::Vec<Address>,
+        amounts: alloc::vec::Vec<U256>,
+    ) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        self.check_batch_size(amounts.len())?;
+        
+        let sender = self.vm().msg_sender();
+        
+        if recipients.len() != amounts.len() {
+            return Err(ERC20Error::BatchTransferLengthMismatch(BatchTransferLengthMismatch {}));
+        }
+        
+        // Check if contract is paused
+        if self.is_paused() {
+            return Err(ERC20Error::ContractPaused(ContractPaused {}));
+        }
+        
+        // Process each transfer, reporting the failing index on error
+        for (i, recipient) in recipients.into_iter().enumerate() {
+            let amount = amounts[i];
+            self.internal_transfer(sender, recipient, amount)
+                .map_err(|e| batch_item_failed(i, e))?;
+        }
+        
+        Ok(true)
+    }
+    
+    /// Resumable batch transfer for distributions too large for one transaction
+    /// Processes entries from `start_index` until the list ends, `max_batch_size` entries
+    /// were processed, or remaining gas drops below RESUMABLE_BATCH_GAS_RESERVE.
+    /// Returns the index to resume from (`recipients.len()` when finished); transfers
+    /// completed before stopping are kept. A failing entry still reverts the call
+    pub fn batch_transfer_resumable(
+        &mut self,
+        recipients: alloc::vec::Vec<Address>,
+        amounts: alloc::vec::Vec<U256>,
+        start_index: U256,
+    ) -> Result<U256, ERC20Error> {
+        self.require_initialized()?;
+        
+        let sender = self.vm().msg_sender();
+        
+        if recipients.len() != amounts.len() {
+            return Err(ERC20Error::BatchTransferLengthMismatch(BatchTransferLengthMismatch {}));
+        }
+        
+        let len = recipients.len();
+        if start_index > U256::from(len) {
+            return Err(ERC20Error::BatchIndexOutOfBounds(BatchIndexOutOfBounds {
+                index: start_index,
+                len: U256::from(len),
+            }));
+        }
+        
+        // Check if contract is paused
+        if self.is_paused() {
+            return Err(ERC20Error::ContractPaused(ContractPaused {}));
+        }
+        
+        let start = start_index.to::<usize>();
+        let max_items = self.effective_max_batch_size().saturating_to::<usize>();
+        let end = len.min(start.saturating_add(max_items));
+        
+        let mut i = start;
+        while i < end {
+            // Stop cleanly, keeping completed transfers, before running out of gas
+            if i > start && self.vm().evm_gas_left() < RESUMABLE_BATCH_GAS_RESERVE {
+                break;
+            }
+            
+            if recipients[i] == Address::ZERO {
+                return Err(batch_item_failed(i, ERC20Error::ZeroAddress(ZeroAddress {})));
+            }
+            
+            self.internal_transfer(sender, recipients[i], amounts[i])
+                .map_err(|e| batch_item_failed(i, e))?;
+            i += 1;
+        }
+        
+        Ok(U256::from(i))
+    }
+    
+    /// Batch transfer from multiple owners into a single recipient
+    /// Each owner must have approved the caller; any failure reverts the whole batch
+    pub fn batch_transfer_from(
+        &mut self,
+        froms: alloc::vec::Vec<Address>,
+        to: Address,
+        amounts: alloc::vec::Vec<U256>,
+    ) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        self.check_batch_size(amounts.len())?;
+        
+        let spender = self.vm().msg_sender();
+        
+        if froms.len() != amounts.len() {
+            return Err(ERC20Error::BatchTransferFromLengthMismatch(BatchTransferFromLengthMismatch {}));
+        }
+        
+        // Check if contract is paused
+        if self.is_paused() {
+            return Err(ERC20Error::ContractPaused(ContractPaused {}));
+        }
+        
+        // Validate recipient address
+        if to == Address::ZERO {
+            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
+        }
+        
+        // Process each pull, reporting the failing index on error
+        for (i, from) in froms.into_iter().enumerate() {
+            let amount = amounts[i];
+            self.spend_allowance(from, spender, amount)
+                .and_then(|_| self.internal_transfer(from, to, amount))
+                .map_err(|e| batch_item_failed(i, e))?;
+        }
+        
+        Ok(true)
+    }
+    
+    /// Settles many sender/recipient pairs atomically: moves `amounts[i]` from `froms[i]`
+    /// to `tos[i]`, spending the caller's allowance from each sender
+    /// Any failing pair reverts the whole settlement, reporting its index
+    pub fn settle(
         &mut self,
-        recipients: alloc::vec::Vec<Address>,
+        froms: alloc::vec::Vec<Address>,
+        tos: alloc::vec::Vec<Address>,
         amounts: alloc::vec::Vec<U256>,
     ) -> Result<bool, ERC20Error> {
-        let sender = msg::sender();
+        self.require_initialized()?;
+        self.check_batch_size(amounts.len())?;
         
-        if recipients.len() != amounts.len() {
-            return Err(ERC20Error::BatchTransferLengthMismatch(BatchTransferLengthMismatch {}));
+        let spender = self.vm().msg_sender();
+        
+        if froms.len() != tos.len() || froms.len() != amounts.len() {
+            return Err(ERC20Error::SettleLengthMismatch(SettleLengthMismatch {}));
         }
         
         // Check if contract is paused
-        if self.paused.get() {
+        if self.is_paused() {
             return Err(ERC20Error::ContractPaused(ContractPaused {}));
         }
         
-        // Process each transfer
-        for (i, recipient) in recipients.into_iter().enumerate() {
+        for (i, from) in froms.into_iter().enumerate() {
+            let to = tos[i];
             let amount = amounts[i];
-            self.internal_transfer(sender, recipient, amount)?;
+            
+            if to == Address::ZERO {
+                return Err(batch_item_failed(i, ERC20Error::ZeroAddress(ZeroAddress {})));
+            }
+            
+            self.spend_allowance(from, spender, amount)
+                .and_then(|_| self.internal_transfer(from, to, amount))
+                .map_err(|e| batch_item_failed(i, e))?;
         }
         
         Ok(true)
@@ -1583,14 +5871,17 @@ impl ERC20Token {
         spenders: alloc::vec::Vec<Address>,
         amounts: alloc::vec::Vec<U256>,
     ) -> Result<bool, ERC20Error> {
-        let owner = msg::sender();
+        self.require_initialized()?;
+        self.check_batch_size(amounts.len())?;
+        
+        let owner = self.vm().msg_sender();
         
         if spenders.len() != amounts.len() {
             return Err(ERC20Error::BatchApproveLengthMismatch(BatchApproveLengthMismatch {}));
         }
         
         // Check if contract is paused
-        if self.paused.get() {
+        if self.is_paused() {
             return Err(ERC20Error::ContractPaused(ContractPaused {}));
         }
         
@@ -1599,12 +5890,16 @@ impl ERC20Token {
             let amount = amounts[i];
             
             if spender == Address::ZERO {
-                return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
+                return Err(batch_item_failed(i, ERC20Error::ZeroAddress(ZeroAddress {})));
             }
             
-            self.allowances.setter(owner).setter(spender).set(amount);
+            self.check_strict_approval(owner, spender, amount)
+                .map_err(|e| batch_item_failed(i, e))?;
             
-            evm::log(Approval {
+            self.set_allowance(owner, spender, amount);
+            self.set_allowance_expiry(owner, spender, U256::ZERO);
+            
+            log(self.vm(), Approval {
                 owner,
                 spender,
                 amount,
@@ -1615,49 +5910,181 @@ impl ERC20Token {
     }
     
     // ========================================================================
-    // ENHANCED TRANSFER WITH BLACKLIST CHECK
+    // ALLOWANCE MIGRATION
     // ========================================================================
     
-    /// Internal transfer function with blacklist and whitelist checks
-    fn internal_transfer_with_checks(
+    /// Returns whether the migration window is still open (`seed_allowances` usable)
+    pub fn migration_active(&self) -> Result<bool, ERC20Error> {
+        Ok(!self.flag(FLAG_MIGRATION_ENDED))
+    }
+    
+    /// Seeds allowances copied from a previous token contract, emitting Approval per entry
+    /// Only usable until `end_migration` is called. Can only be called by owner
+    pub fn seed_allowances(
         &mut self,
+        owners: alloc::vec::Vec<Address>,
+        spenders: alloc::vec::Vec<Address>,
+        amounts: alloc::vec::Vec<U256>,
+    ) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        self.check_batch_size(amounts.len())?;
+        
+        self.only_owner()?;
+        
+        if self.flag(FLAG_MIGRATION_ENDED) {
+            return Err(ERC20Error::MigrationClosed(MigrationClosed {}));
+        }
+        
+        if owners.len() != spenders.len() || owners.len() != amounts.len() {
+            return Err(ERC20Error::SeedAllowancesLengthMismatch(SeedAllowancesLengthMismatch {}));
+        }
+        
+        for (i, owner) in owners.into_iter().enumerate() {
+            let spender = spenders[i];
+            let amount = amounts[i];
+            
+            if owner == Address::ZERO || spender == Address::ZERO {
+                return Err(batch_item_failed(i, ERC20Error::ZeroAddress(ZeroAddress {})));
+            }
+            
+            self.set_allowance(owner, spender, amount);
+            self.set_allowance_expiry(owner, spender, U256::ZERO);
+            
+            log(self.vm(), Approval {
+                owner,
+                spender,
+                amount,
+            });
+        }
+        
+        Ok(true)
+    }
+    
+    /// Permanently closes the migration window. This cannot be undone
+    /// Can only be called by owner
+    pub fn end_migration(&mut self) -> Result<bool, ERC20Error> {
+        self.require_initialized()?;
+        
+        self.only_owner()?;
+        
+        if self.flag(FLAG_MIGRATION_ENDED) {
+            return Err(ERC20Error::MigrationClosed(MigrationClosed {}));
+        }
+        
+        self.set_flag(FLAG_MIGRATION_ENDED, true);
+        
+        log(self.vm(), MigrationEnded {
+            account: self.vm().msg_sender(),
+            timestamp: self.current_time(),
+        });
+        
+        Ok(true)
+    }
+    
+    // ========================================================================
+    // TRANSFER POLICY CHECKS
+    // ========================================================================
+    
+    /// Internal function enforcing blacklist and whitelist restrictions between two parties
+    fn check_restrictions(&self, from: Address, to: Address) -> Result<(), ERC20Error> {
+        // Check blacklist (scheduled entries only block once effective)
+        if self.flag(FLAG_BLACKLIST_ENABLED) {
+            if self.is_blacklist_active(from) {
+                return Err(ERC20Error::AddressBlacklisted(AddressBlacklisted { account: from }));
+            }
+            if self.is_blacklist_active(to) {
+                return Err(ERC20Error::AddressBlacklisted(AddressBlacklisted { account: to }));
+            }
+        }
+        
+        // Check transfer restrictions (policy selected by whitelist_mode)
+        if !self.passes_whitelist(from, to) {
+            return Err(ERC20Error::TransferRestricted(TransferRestricted { from, to }));
+        }
+        
+        Ok(())
+    }
+    
+    /// Returns whether a transfer of `amount` from `from` to `to` would pass all
+    /// current checks, and a reason code identifying the first failing gate
+    /// Mirrors the checked transfer path without mutating state
+    pub fn can_transfer(
+        &self,
         from: Address,
         to: Address,
         amount: U256,
-    ) -> Result<(), ERC20Error> {
-        // Check blacklist
-        if self.blacklist_enabled.get() {
-            if self.blacklisted.get(from) {
-                return Err(ERC20Error::AddressBlacklisted(AddressBlacklisted { account: from }));
+    ) -> Result<(bool, u8), ERC20Error> {
+        let reason = self.transfer_failure_reason(from, to, amount);
+        Ok((reason == TRANSFER_REASON_OK, reason))
+    }
+    
+    /// Internal function returning the reason code for the first failing transfer gate
+    fn transfer_failure_reason(&self, from: Address, to: Address, amount: U256) -> u8 {
+        if self.flag(FLAG_DECOMMISSIONED) {
+            return TRANSFER_REASON_DECOMMISSIONED;
+        }
+        
+        if self.is_paused() {
+            return TRANSFER_REASON_PAUSED;
+        }
+        
+        if to == Address::ZERO {
+            return TRANSFER_REASON_ZERO_ADDRESS;
+        }
+        
+        // Zero-amount transfers succeed (ERC-20 compatible) unless rejected by config
+        if amount == U256::ZERO {
+            if self.flag(FLAG_REJECT_ZERO_TRANSFERS) {
+                return TRANSFER_REASON_ZERO_AMOUNT;
             }
-            if self.blacklisted.get(to) {
-                return Err(ERC20Error::AddressBlacklisted(AddressBlacklisted { account: to }));
+            return TRANSFER_REASON_OK;
+        }
+        
+        if self.flag(FLAG_BLACKLIST_ENABLED) {
+            if self.is_blacklist_active(from) {
+                return TRANSFER_REASON_SENDER_BLACKLISTED;
+            }
+            if self.is_blacklist_active(to) {
+                return TRANSFER_REASON_RECIPIENT_BLACKLISTED;
             }
         }
         
-        // Check transfer restrictions (whitelist mode)
-        if self.transfer_restrictions_enabled.get() {
-            if !self.transfer_whitelist.get(from) && !self.transfer_whitelist.get(to) {
-                // Both addresses need to be whitelisted
-                // This is a strict mode - adjust as needed
+        if !self.passes_whitelist(from, to) {
+            return TRANSFER_REASON_RESTRICTED;
+        }
+        
+        if self.transfer_denied.get(to) {
+            return TRANSFER_REASON_RECIPIENT_DENIED;
+        }
+        
+        let privileged = self.privileged.get(from) || self.privileged.get(to);
+        if !privileged && !self.is_accepting_receiver(to) {
+            return TRANSFER_REASON_RECIPIENT_NOT_ACCEPTING;
+        }
+        
+        if !privileged && self.is_below_minimum(from, amount) {
+            return TRANSFER_REASON_BELOW_MINIMUM;
+        }
+        
+        let hook = self.transfer_hook.get();
+        if hook != Address::ZERO {
+            let approved = self.hook_approves(hook, from, to, amount);
+            if !approved {
+                return TRANSFER_REASON_HOOK_REJECTED;
             }
         }
         
-        // Perform standard transfer
-        self.internal_transfer(from, to, amount)?;
+        if self.balances.get(from) < amount {
+            return TRANSFER_REASON_INSUFFICIENT_BALANCE;
+        }
         
-        // Log large transfers for monitoring
-        let large_threshold = U256::from(100_000_000_000_000_000_000_000u128); // 100K tokens with 18 decimals
-        if amount >= large_threshold {
-            evm::log(LargeTransfer {
-                from,
-                to,
-                amount,
-                timestamp: U256::from(msg::epoch()),
-            });
+        // Outflow limits apply after the self-transfer and burn-on-dead short circuits
+        let burns_on_dead = to == DEAD_ADDRESS && self.flag(FLAG_BURN_ON_DEAD_TRANSFER);
+        if !privileged && !burns_on_dead && from != to && self.exceeds_outflow(from, amount) {
+            return TRANSFER_REASON_OUTFLOW_EXCEEDED;
         }
         
-        Ok(())
+        TRANSFER_REASON_OK
     }
     
     // ========================================================================
@@ -1665,212 +6092,156 @@ impl ERC20Token {
     // ========================================================================
     
     /// Enhanced mint function with supply cap and rate limiting checks
-    fn internal_mint(&mut self, to: Address, amount: U256) -> Result<(), ERC20Error> {
-        // Check supply cap
-        if self.supply_cap_enabled.get() {
-            let current_supply = self.total_supply.get();
-            let new_supply = current_supply.checked_add(amount)
-                .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))?;
+    /// `source` (MINT_SOURCE_*) is reported by the `Minted` event
+    fn internal_mint(&mut self, to: Address, amount: U256, source: u8) -> Result<(), ERC20Error> {
+        if self.flag(FLAG_DECOMMISSIONED) {
+            return Err(ERC20Error::TokenDecommissioned(TokenDecommissioned {}));
+        }
+        
+        if self.flag(FLAG_MINTING_RENOUNCED) {
+            return Err(ERC20Error::MintingDisabled(MintingDisabled {}));
+        }
+        
+        // Hard ceiling on a single mint, independent of the rate window
+        let max_per_tx = self.max_mint_per_tx.get();
+        if max_per_tx != U256::ZERO && amount > max_per_tx {
+            return Err(ERC20Error::MintExceedsPerTxLimit(MintExceedsPerTxLimit {
+                amount,
+                max: max_per_tx,
+            }));
+        }
+        
+        // Minting to the token contract itself strands tokens unless deliberately allowed
+        if to == self.vm().contract_address() && !self.flag(FLAG_ALLOW_MINT_TO_SELF) {
+            return Err(ERC20Error::CannotMintToSelf(CannotMintToSelf {}));
+        }
+        
+        // Supply overflow is checked first: the recipient balance and the rate window
+        // can only overflow if the supply does
+        let current_supply = self.total_supply.get();
+        let new_supply = current_supply.checked_add(amount)
+            .ok_or(ERC20Error::SupplyOverflow(SupplyOverflow {
+                total_supply: current_supply,
+                amount,
+            }))?;
+        
+        // Check minting rate limit (disabled when duration is zero)
+        let period_duration = self.minting_period_duration.get();
+        if period_duration != U256::ZERO {
+            let now = self.current_time();
+            let period_end = self.minting_period_start.get().saturating_add(period_duration);
             
-            if new_supply > self.supply_cap.get() {
-                evm::log(MintExceedsCap {
-                    amount,
-                    current_supply,
-                    cap: self.supply_cap.get(),
-                });
-                return Err(ERC20Error::SupplyCapExceeded(SupplyCapExceeded {
-                    current_supply,
-                    cap: self.supply_cap.get(),
+            // Start a new period once the current one has elapsed
+            if now >= period_end {
+                self.minting_period_start.set(now);
+                self.minted_in_period.set(U256::ZERO);
+            }
+            
+            let limit = self.minting_period_limit.get();
+            let minted = self.minted_in_period.get().checked_add(amount)
+                .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))?;
+            if minted > limit {
+                return Err(ERC20Error::MintRateLimitExceeded(MintRateLimitExceeded {
+                    minted,
+                    limit,
                 }));
             }
+            self.minted_in_period.set(minted);
+        }
+        
+        // Check supply cap
+        if self.flag(FLAG_SUPPLY_CAP_ENABLED) && new_supply > self.supply_cap.get() {
+            return Err(ERC20Error::SupplyCapExceeded(SupplyCapExceeded {
+                current_supply,
+                cap: self.supply_cap.get(),
+            }));
         }
         
         // Update recipient balance
         let current_balance = self.balances.get(to);
         let new_balance = current_balance.checked_add(amount)
-            .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))?;
+            .ok_or(ERC20Error::BalanceOverflow(BalanceOverflow {
+                account: to,
+                balance: current_balance,
+                amount,
+            }))?;
         
+        self.checkpoint_balance(to);
         self.balances.setter(to).set(new_balance);
+        self.track_holder(to);
         
         // Update total supply
-        let current_supply = self.total_supply.get();
-        let new_supply = current_supply.checked_add(amount)
-            .ok_or(ERC20Error::InvalidAmount(InvalidAmount {}))?;
-        
-        self.total_supply.set(new_supply);
+        self.set_total_supply(new_supply);
+        self.consume_mint_allocation(self.vm().msg_sender(), amount);
         
         // Emit Transfer event from zero address (mint)
-        evm::log(Transfer {
+        log(self.vm(), Transfer {
             from: Address::ZERO,
             to,
             amount,
         });
+        self.emit_minted(to, amount, source);
         
         Ok(())
     }
     
+    /// Internal function emitting `Minted` next to the ERC-20 Transfer from zero
+    fn emit_minted(&self, to: Address, amount: U256, source: u8) {
+        log(self.vm(), Minted {
+            to,
+            amount,
+            reason: source,
+            operator: self.vm().msg_sender(),
+        });
+    }
+    
     // ========================================================================
     // OVERRIDE ERC-20 FUNCTIONS FOR ENHANCED SECURITY
     // ========================================================================
     
     /// Enhanced transfer with blacklist and whitelist checks
+    /// Deprecated: kept for ABI compatibility, delegates to the canonical `transfer`
     pub fn transfer_with_checks(&mut self, to: Address, amount: U256) -> Result<bool, ERC20Error> {
-        let from = msg::sender();
-        
-        // Check if contract is paused
-        if self.paused.get() {
-            return Err(ERC20Error::ContractPaused(ContractPaused {}));
-        }
-        
-        // Validate recipient address
-        if to == Address::ZERO {
-            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
-        }
-        
-        // Allow zero amount transfers
-        if amount == U256::ZERO {
-            evm::log(Transfer {
-                from,
-                to,
-                amount: U256::ZERO,
-            });
-            return Ok(true);
-        }
+        self.require_initialized()?;
         
-        self.internal_transfer_with_checks(from, to, amount)?;
-        
-        Ok(true)
+        self.transfer(to, amount)
     }
     
     /// Enhanced transfer_from with blacklist and whitelist checks
+    /// Deprecated: kept for ABI compatibility, delegates to the canonical `transfer_from`
     pub fn transfer_from_with_checks(
         &mut self,
         from: Address,
         to: Address,
         amount: U256,
     ) -> Result<bool, ERC20Error> {
-        let spender = msg::sender();
-        
-        // Check if contract is paused
-        if self.paused.get() {
-            return Err(ERC20Error::ContractPaused(ContractPaused {}));
-        }
-        
-        // Validate recipient address
-        if to == Address::ZERO {
-            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
-        }
-        
-        // Allow zero amount transfers
-        if amount == U256::ZERO {
-            evm::log(Transfer {
-                from,
-                to,
-                amount: U256::ZERO,
-            });
-            return Ok(true);
-        }
-        
-        // Check and update allowance
-        let current_allowance = self.allowances.getter(from).get(spender);
+        self.require_initialized()?;
         
-        if current_allowance < amount {
-            return Err(ERC20Error::InsufficientAllowance(
-                InsufficientAllowance {
-                    allowance: current_allowance,
-                    required: amount,
-                },
-            ));
-        }
-        
-        let new_allowance = current_allowance.checked_sub(amount)
-            .ok_or(ERC20Error::InsufficientAllowance(
-                InsufficientAllowance {
-                    allowance: current_allowance,
-                    required: amount,
-                },
-            ))?;
-        
-        self.allowances.setter(from).setter(spender).set(new_allowance);
-        
-        // Perform transfer with checks
-        self.internal_transfer_with_checks(from, to, amount)?;
-        
-        Ok(true)
+        self.transfer_from(from, to, amount)
     }
     
     /// Enhanced mint with supply cap and rate limiting
+    /// Deprecated: kept for ABI compatibility, delegates to the canonical `mint`
     pub fn mint_with_checks(&mut self, to: Address, amount: U256) -> Result<bool, ERC20Error> {
-        // Check minter role
-        if !self.roles.getter(bytes32_from_u32(MINTER_ROLE)).get(msg::sender()) {
-            return Err(ERC20Error::AccessDenied(AccessDenied {
-                account: msg::sender(),
-                role: bytes32_from_u32(MINTER_ROLE),
-            }));
-        }
-        
-        // Check if contract is paused
-        if self.paused.get() {
-            return Err(ERC20Error::ContractPaused(ContractPaused {}));
-        }
-        
-        // Validate recipient address
-        if to == Address::ZERO {
-            return Err(ERC20Error::ZeroAddress(ZeroAddress {}));
-        }
+        self.require_initialized()?;
         
-        // Skip if amount is zero
-        if amount == U256::ZERO {
-            return Ok(true);
-        }
-        
-        self.internal_mint(to, amount)?;
-        
-        Ok(true)
+        self.mint(to, amount)
     }
     
     /// Enhanced pause with role check
+    /// Deprecated: kept for ABI compatibility, delegates to the canonical `pause`
     pub fn pause_with_role(&mut self) -> Result<bool, ERC20Error> {
-        if !self.roles.getter(bytes32_from_u32(PAUSER_ROLE)).get(msg::sender()) {
-            return Err(ERC20Error::AccessDenied(AccessDenied {
-                account: msg::sender(),
-                role: bytes32_from_u32(PAUSER_ROLE),
-            }));
-        }
-        
-        if self.paused.get() {
-            return Err(ERC20Error::ContractPaused(ContractPaused {}));
-        }
-        
-        self.paused.set(true);
-        
-        evm::log(Paused {
-            account: msg::sender(),
-        });
+        self.require_initialized()?;
         
-        Ok(true)
+        self.pause()
     }
     
     /// Enhanced unpause with role check
+    /// Deprecated: kept for ABI compatibility, delegates to the canonical `unpause`
     pub fn unpause_with_role(&mut self) -> Result<bool, ERC20Error> {
-        if !self.roles.getter(bytes32_from_u32(PAUSER_ROLE)).get(msg::sender()) {
-            return Err(ERC20Error::AccessDenied(AccessDenied {
-                account: msg::sender(),
-                role: bytes32_from_u32(PAUSER_ROLE),
-            }));
-        }
-        
-        if !self.paused.get() {
-            return Err(ERC20Error::NotContractPaused(NotContractPaused {}));
-        }
+        self.require_initialized()?;
         
-        self.paused.set(false);
-        
-        evm::log(Unpaused {
-            account: msg::sender(),
-        });
-        
-        Ok(true)
+        self.unpause()
     }
 }
 
@@ -2024,6 +6395,19 @@ mod tests {
         assert_eq!(ownership_event.previous_owner, Address::ZERO);
     }
 
+    #[test]
+    fn test_role_constants_match_keccak() {
+        // Role identifiers must match keccak256("<ROLE_NAME>") used by other tooling
+        assert_eq!(MINTER_ROLE, alloy_primitives::keccak256("MINTER_ROLE"));
+        assert_eq!(PAUSER_ROLE, alloy_primitives::keccak256("PAUSER_ROLE"));
+        assert_eq!(ADMIN_ROLE, alloy_primitives::keccak256("ADMIN_ROLE"));
+        assert_eq!(DEFAULT_ADMIN_ROLE, FixedBytes::<32>::ZERO);
+
+        assert_ne!(MINTER_ROLE, PAUSER_ROLE);
+        assert_ne!(MINTER_ROLE, ADMIN_ROLE);
+        assert_ne!(PAUSER_ROLE, ADMIN_ROLE);
+    }
+
     // ============================================================================
     // SUPPLY CALCULATION TESTS
     // ============================================================================
@@ -3,17 +3,25 @@
 // Following the SECURITY.md checklist requirements
 // Tests all production features: RBAC, Supply Cap, Blacklist, Snapshots, Time-Lock, Emergency Features
 
-use alloy_primitives::{Address, U256};
+use alloy_primitives::{fixed_bytes, Address, FixedBytes, U256};
+use stylus_erc20::{ERC20Error, ERC20Token};
+use stylus_sdk::alloy_sol_types::{SolEvent, SolValue};
+use stylus_sdk::testing::*;
 
 // ============================================================================
 // CONSTANTS FOR ROLES AND TESTING
 // ============================================================================
 
 // Role constants (matching lib.rs)
-const MINTER_ROLE: u32 = 0x9f2df0fed2c77648de5860a4cc508cd0818c85b8b8a1ab4ceeef8d981c8956a6;
-const PAUSER_ROLE: u32 = 0x65d7a28e3265b37a6474929f336521b332cbb1a44ac7f6c0e19d4e9cfe7b8a4d;
-const ADMIN_ROLE: u32 = 0xa49807205ce4d355092ef5a8a14f63e0a5e76c1d2932e00e8c0a0f9d7c7e3d5c;
-const DEFAULT_ADMIN_ROLE: u32 = 0x0000000000000000000000000000000000000000000000000000000000000000;
+const MINTER_ROLE: FixedBytes<32> =
+    fixed_bytes!("9f2df0fed2c77648de5860a4cc508cd0818c85b8b8a1ab4ceeef8d981c8956a6");
+const PAUSER_ROLE: FixedBytes<32> =
+    fixed_bytes!("65d7a28e3265b37a6474929f336521b332c1681b933f6cb9f3376673440d862a");
+const ADMIN_ROLE: FixedBytes<32> =
+    fixed_bytes!("a49807205ce4d355092ef5a8a18f56e8913cf4a201fbe287825b095693c21775");
+const COMPLIANCE_ROLE: FixedBytes<32> =
+    fixed_bytes!("442a94f1a1fac79af32856af2a64f63648cfa2ef3b98610a5bb7cbec4cee6985");
+const DEFAULT_ADMIN_ROLE: FixedBytes<32> = FixedBytes::ZERO;
 
 // ============================================================================
 // HELPER FUNCTIONS
@@ -29,6 +37,84 @@ fn addr_from_u32(n: u32) -> Address {
     Address::from(bytes)
 }
 
+// ============================================================================
+// CONTRACT TEST HARNESS
+// Contract tests run against the Stylus TestVM: storage, msg.sender, block
+// values and emitted logs are all served by the VM passed to `ERC20Token::from`
+// ============================================================================
+
+/// Initial supply minted to the owner by `deploy`
+const SUPPLY: u64 = 1_000_000;
+
+/// Address the TestVM deploys the token at
+const CONTRACT: Address = alloy_primitives::address!("dCE82b5f92C98F27F116F70491a487EFFDb6a2a9");
+
+/// Deploys a token owned by addr(1) holding the full `SUPPLY`
+/// The VM clock starts at timestamp 1_000, block 1, with addr(1) as sender
+fn deploy() -> (TestVM, ERC20Token) {
+    deploy_with_supply(SUPPLY)
+}
+
+fn deploy_with_supply(supply: u64) -> (TestVM, ERC20Token) {
+    let vm = TestVM::default();
+    vm.set_sender(addr(1));
+    vm.set_block_timestamp(1_000);
+    vm.set_block_number(1);
+    let mut token = ERC20Token::from(&vm);
+    ok(token.initialize("Test Token".into(), "TST".into(), 18, U256::from(supply), addr(1)));
+    (vm, token)
+}
+
+/// Deploys a token without initializing it
+fn deploy_uninitialized() -> (TestVM, ERC20Token) {
+    let vm = TestVM::default();
+    vm.set_sender(addr(1));
+    vm.set_block_timestamp(1_000);
+    vm.set_block_number(1);
+    let token = ERC20Token::from(&vm);
+    (vm, token)
+}
+
+/// Sends `amount` from the owner to `to`, leaving the owner as sender
+fn fund(vm: &TestVM, token: &mut ERC20Token, to: Address, amount: u64) {
+    vm.set_sender(addr(1));
+    ok(token.transfer(to, U256::from(amount)));
+}
+
+/// Unwraps a contract call, panicking with the raw revert data on failure
+fn ok<T>(result: Result<T, ERC20Error>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(error) => panic!("call reverted: {:?}", Vec::<u8>::from(error)),
+    }
+}
+
+/// Mocks the ecrecover precompile so the returned (v, r, s) over the EIP-712 digest
+/// of `struct_hash` recovers to `signer`
+fn mock_signature(
+    vm: &TestVM,
+    token: &ERC20Token,
+    struct_hash: FixedBytes<32>,
+    signer: Address,
+) -> (u8, FixedBytes<32>, FixedBytes<32>) {
+    let digest = alloy_primitives::keccak256(
+        [&[0x19u8, 0x01][..], ok(token.domain_separator()).as_slice(), struct_hash.as_slice()].concat(),
+    );
+    let (v, r, s) = (27u8, FixedBytes::from([0x11u8; 32]), FixedBytes::from([0x22u8; 32]));
+    let ecrecover = Address::with_last_byte(1);
+    vm.mock_static_call(ecrecover, (digest, U256::from(v), r, s).abi_encode(), Ok(signer.into_word().to_vec()));
+    (v, r, s)
+}
+
+/// Decodes every emitted log of event type `E`, in emission order
+fn events<E: SolEvent>(vm: &TestVM) -> Vec<E> {
+    vm.get_emitted_logs()
+        .into_iter()
+        .filter(|(topics, _)| topics.first() == Some(&E::SIGNATURE_HASH))
+        .filter_map(|(topics, data)| E::decode_raw_log(topics, &data, true).ok())
+        .collect()
+}
+
 // ============================================================================
 // BASIC TYPE TESTS
 // ============================================================================
@@ -82,7 +168,66 @@ fn test_role_constants() {
     assert_ne!(MINTER_ROLE, PAUSER_ROLE);
     assert_ne!(MINTER_ROLE, ADMIN_ROLE);
     assert_ne!(PAUSER_ROLE, ADMIN_ROLE);
-    assert_eq!(DEFAULT_ADMIN_ROLE, 0);
+    assert_eq!(DEFAULT_ADMIN_ROLE, FixedBytes::<32>::ZERO);
+}
+
+#[test]
+fn test_role_identifiers_are_full_width() {
+    // Roles are full 32-byte keccak hashes, not truncated integers
+    assert_eq!(MINTER_ROLE, alloy_primitives::keccak256("MINTER_ROLE"));
+    assert_eq!(PAUSER_ROLE, alloy_primitives::keccak256("PAUSER_ROLE"));
+    assert_eq!(ADMIN_ROLE, alloy_primitives::keccak256("ADMIN_ROLE"));
+}
+
+/// Two 32-byte roles that share the same low 4 bytes (they collided under u32 truncation)
+fn colliding_roles() -> (FixedBytes<32>, FixedBytes<32>) {
+    let mut role_a = [0u8; 32];
+    let mut role_b = [0u8; 32];
+    role_a[28..].copy_from_slice(&[0xde, 0xad, 0xbe, 0xef]);
+    role_b[28..].copy_from_slice(&[0xde, 0xad, 0xbe, 0xef]);
+    role_a[0] = 0x01;
+    role_b[0] = 0x02;
+    (FixedBytes::from(role_a), FixedBytes::from(role_b))
+}
+
+#[test]
+fn test_distinct_roles_do_not_collide() {
+    let (_vm, mut token) = deploy();
+    let (role_a, role_b) = colliding_roles();
+    
+    ok(token.grant_role(role_a, addr(2)));
+    ok(token.grant_role(role_b, addr(3)));
+    
+    assert!(ok(token.has_role(role_a, addr(2))));
+    assert!(!ok(token.has_role(role_b, addr(2))));
+    assert!(ok(token.has_role(role_b, addr(3))));
+    assert!(!ok(token.has_role(role_a, addr(3))));
+    
+    // Revoking one leaves the other untouched
+    ok(token.revoke_role(role_a, addr(2)));
+    assert!(!ok(token.has_role(role_a, addr(2))));
+    assert!(ok(token.has_role(role_b, addr(3))));
+}
+
+#[test]
+fn test_has_role_with_full_identifier() {
+    use stylus_erc20::RoleGranted;
+    let (vm, mut token) = deploy();
+    
+    ok(token.grant_role(MINTER_ROLE, addr(2)));
+    assert!(ok(token.has_role(MINTER_ROLE, addr(2))));
+    
+    // Same low bytes, different high bytes: must not match
+    let mut truncated = [0u8; 32];
+    truncated[28..].copy_from_slice(&MINTER_ROLE[28..]);
+    assert!(!ok(token.has_role(FixedBytes::<32>::from(truncated), addr(2))));
+    
+    // The event carries the full 32-byte hash
+    let granted = events::<RoleGranted>(&vm);
+    let last = granted.last().unwrap();
+    assert_eq!(last.role, MINTER_ROLE);
+    assert_eq!(last.account, addr(2));
+    assert_eq!(last.sender, addr(1));
 }
 
 // ============================================================================
@@ -163,7 +308,7 @@ fn test_supply_cap_below_current_supply_fails() {
 #[test]
 fn test_role_initialization() {
     // Simulate role initialization during contract setup
-    let mut roles: Vec<(u32, Address)> = Vec::new();
+    let mut roles: Vec<(FixedBytes<32>, Address)> = Vec::new();
 
     // Admin role granted to initial owner
     let admin = addr(1);
@@ -188,7 +333,7 @@ fn test_role_check() {
     let minter = addr(1);
     let non_minter = addr(2);
 
-    let mut roles: Vec<(u32, Vec<Address>)> = Vec::new();
+    let mut roles: Vec<(FixedBytes<32>, Vec<Address>)> = Vec::new();
     roles.push((MINTER_ROLE, vec![minter]));
 
     // Check minter has role
@@ -246,7 +391,7 @@ fn test_role_cannot_grant_to_zero_address() {
 #[test]
 fn test_role_admin_hierarchy() {
     // Test role admin hierarchy
-    let role_admins: Vec<(u32, u32)> = vec![
+    let role_admins: Vec<(FixedBytes<32>, FixedBytes<32>)> = vec![
         (DEFAULT_ADMIN_ROLE, ADMIN_ROLE),
         (ADMIN_ROLE, ADMIN_ROLE),
         (MINTER_ROLE, ADMIN_ROLE),
@@ -264,7 +409,7 @@ fn test_role_admin_hierarchy() {
 fn test_role_renunciation() {
     // Test voluntary role renouncement
     let holder = addr(1);
-    let mut roles: Vec<(u32, Vec<Address>)> = vec![
+    let mut roles: Vec<(FixedBytes<32>, Vec<Address>)> = vec![
         (MINTER_ROLE, vec![holder]),
         (PAUSER_ROLE, vec![holder]),
     ];
@@ -1076,7 +1221,7 @@ fn test_full_production_deployment_scenario() {
     assert!(initialized);
 
     // 2. Configure roles
-    let mut roles: Vec<(u32, Vec<Address>)> = vec![
+    let mut roles: Vec<(FixedBytes<32>, Vec<Address>)> = vec![
         (ADMIN_ROLE, vec![owner]),
         (MINTER_ROLE, vec![owner]),
         (PAUSER_ROLE, vec![owner]),
@@ -1182,3 +1327,5392 @@ fn test_governance_snapshot_scenario() {
     let total_voting_power: U256 = voters.iter().map(|(_, balance)| *balance).sum();
     assert_eq!(total_voting_power, U256::from(600_000u64));
 }
+
+// ============================================================================
+// TRANSFER WITH MEMO TESTS
+// ============================================================================
+
+#[test]
+fn test_transfer_with_memo_emits_both_events() {
+    use stylus_erc20::{Transfer, TransferWithMemo};
+
+    let (vm, mut token) = deploy();
+    let memo = FixedBytes::<32>::from([0xabu8; 32]);
+
+    assert!(ok(token.transfer_with_memo(addr(2), U256::from(250u64), memo)));
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(250u64));
+
+    let transfers = events::<Transfer>(&vm);
+    let last = transfers.last().unwrap();
+    assert_eq!((last.from, last.to, last.amount), (addr(1), addr(2), U256::from(250u64)));
+
+    let memos = events::<TransferWithMemo>(&vm);
+    assert_eq!(memos.len(), 1);
+    assert_eq!((memos[0].from, memos[0].to, memos[0].amount), (addr(1), addr(2), U256::from(250u64)));
+    assert_eq!(memos[0].memo, memo);
+}
+
+#[test]
+fn test_transfer_with_memo_failure_emits_no_memo() {
+    let (vm, mut token) = deploy();
+    vm.set_sender(addr(2));
+
+    assert!(matches!(
+        token.transfer_with_memo(addr(3), U256::from(1u64), FixedBytes::ZERO),
+        Err(ERC20Error::InsufficientBalance(_))
+    ));
+    assert!(events::<stylus_erc20::TransferWithMemo>(&vm).is_empty());
+}
+
+// ============================================================================
+// HOLDER REGISTRY TESTS
+// ============================================================================
+
+#[test]
+fn test_holder_registry_is_append_only() {
+    let (vm, mut token) = deploy();
+    assert_eq!(ok(token.holder_count()), U256::from(1u64));
+
+    fund(&vm, &mut token, addr(2), 100);
+    fund(&vm, &mut token, addr(3), 100);
+
+    // Emptying a balance keeps the holder registered
+    vm.set_sender(addr(2));
+    ok(token.transfer(addr(1), U256::from(100u64)));
+
+    assert_eq!(ok(token.holder_count()), U256::from(3u64));
+    assert_eq!(ok(token.get_holder(U256::ZERO)), addr(1));
+    assert_eq!(ok(token.get_holder(U256::from(1u64))), addr(2));
+    assert_eq!(ok(token.get_holder(U256::from(2u64))), addr(3));
+    assert!(matches!(
+        token.get_holder(U256::from(3u64)),
+        Err(ERC20Error::HolderIndexOutOfBounds(_))
+    ));
+}
+
+#[test]
+fn test_snapshot_all_in_two_batches() {
+    let (vm, mut token) = deploy();
+    for n in 2..=5u8 {
+        fund(&vm, &mut token, addr(n), n as u64 * 100);
+    }
+    let id = ok(token.snapshot());
+
+    assert_eq!(ok(token.snapshot_all(U256::ZERO, U256::from(3u64))), U256::from(3u64));
+    // Only two holders remain, so the second batch is clamped
+    assert_eq!(ok(token.snapshot_all(U256::from(3u64), U256::from(3u64))), U256::from(2u64));
+    assert_eq!(events::<stylus_erc20::SnapshotBatchRecorded>(&vm).len(), 2);
+
+    // Balances moved after recording don't change the snapshot
+    vm.set_sender(addr(5));
+    ok(token.transfer(addr(2), U256::from(500u64)));
+    vm.set_sender(addr(1));
+    ok(token.finalize_snapshot());
+
+    assert_eq!(ok(token.balance_of_at(addr(5), id)), U256::from(500u64));
+    assert_eq!(ok(token.balance_of_at(addr(2), id)), U256::from(200u64));
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(700u64));
+}
+
+#[test]
+fn test_snapshot_all_start_out_of_bounds() {
+    let (_vm, mut token) = deploy();
+
+    // No snapshot in progress
+    assert!(matches!(
+        token.snapshot_all(U256::ZERO, U256::from(1u64)),
+        Err(ERC20Error::SnapshotNotFound(_))
+    ));
+
+    ok(token.snapshot());
+    assert!(matches!(
+        token.snapshot_all(U256::from(1u64), U256::from(1u64)),
+        Err(ERC20Error::HolderIndexOutOfBounds(_))
+    ));
+}
+
+// ============================================================================
+// UNIFIED MINT AUTHORIZATION TESTS
+// ============================================================================
+
+#[test]
+fn test_owner_can_mint_without_minter_role() {
+    let (_vm, mut token) = deploy();
+    ok(token.revoke_role(MINTER_ROLE, addr(1)));
+    assert!(!ok(token.has_role(MINTER_ROLE, addr(1))));
+
+    ok(token.mint(addr(2), U256::from(10u64)));
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(10u64));
+}
+
+#[test]
+fn test_minter_role_can_mint() {
+    let (vm, mut token) = deploy();
+    ok(token.grant_role(MINTER_ROLE, addr(2)));
+
+    vm.set_sender(addr(2));
+    ok(token.mint(addr(2), U256::from(10u64)));
+    assert_eq!(ok(token.total_supply()), U256::from(SUPPLY + 10));
+}
+
+#[test]
+fn test_unauthorized_mint_rejected() {
+    let (vm, mut token) = deploy();
+    ok(token.grant_role(MINTER_ROLE, addr(2)));
+
+    vm.set_sender(addr(3));
+    assert!(matches!(
+        token.mint(addr(3), U256::from(10u64)),
+        Err(ERC20Error::AccessDenied(_))
+    ));
+    assert_eq!(ok(token.total_supply()), U256::from(SUPPLY));
+}
+
+#[test]
+fn test_mint_rate_limit_window() {
+    let (vm, mut token) = deploy();
+    ok(token.set_minting_limits(U256::from(1_000u64), U256::from(3_600u64)));
+
+    ok(token.mint(addr(2), U256::from(600u64)));
+    assert!(matches!(
+        token.mint(addr(2), U256::from(500u64)),
+        Err(ERC20Error::MintRateLimitExceeded(_))
+    ));
+
+    // A new window starts once the previous one has elapsed
+    vm.set_block_timestamp(1_000 + 3_600);
+    ok(token.mint(addr(2), U256::from(500u64)));
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(1_100u64));
+}
+
+// ============================================================================
+// PENDING OWNERSHIP INFO TESTS
+// ============================================================================
+
+#[test]
+fn test_pending_ownership_info_none() {
+    let (_vm, token) = deploy();
+    assert_eq!(ok(token.pending_ownership_info()), (Address::ZERO, U256::ZERO, false));
+}
+
+#[test]
+fn test_pending_ownership_info_not_yet_unlockable() {
+    let (_vm, mut token) = deploy();
+    ok(token.initiate_ownership_transfer(addr(2)));
+
+    let unlock_time = U256::from(1_000u64 + 48 * 60 * 60);
+    assert_eq!(ok(token.pending_ownership_info()), (addr(2), unlock_time, false));
+}
+
+#[test]
+fn test_pending_ownership_info_claimable() {
+    let (vm, mut token) = deploy();
+    ok(token.initiate_ownership_transfer(addr(2)));
+
+    let unlock_time = 1_000u64 + 48 * 60 * 60;
+    vm.set_block_timestamp(unlock_time);
+    assert_eq!(ok(token.pending_ownership_info()), (addr(2), U256::from(unlock_time), true));
+
+    // Accepting clears the pending entry
+    vm.set_sender(addr(2));
+    ok(token.accept_ownership());
+    assert_eq!(ok(token.pending_ownership_info()), (Address::ZERO, U256::ZERO, false));
+}
+
+// ============================================================================
+// BURN UNDER TRANSFER RESTRICTIONS TESTS
+// ============================================================================
+
+#[test]
+fn test_non_whitelisted_holder_can_self_burn() {
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(2), 1_000);
+    ok(token.add_to_whitelist(addr(9)));
+    ok(token.set_transfer_restrictions_enabled(true));
+
+    // Self-burn is not subject to restrictions
+    vm.set_sender(addr(2));
+    ok(token.burn(U256::from(400u64)));
+
+    assert!(!ok(token.is_transfer_whitelisted(addr(2))));
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(600u64));
+    assert_eq!(ok(token.total_supply()), U256::from(SUPPLY - 400));
+}
+
+#[test]
+fn test_non_whitelisted_spender_cannot_burn_from() {
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(2), 1_000);
+    ok(token.add_to_whitelist(addr(9)));
+    ok(token.set_transfer_restrictions_enabled(true));
+
+    vm.set_sender(addr(2));
+    ok(token.approve(addr(3), U256::from(500u64)));
+
+    // burn_from checks restrictions between holder and spender
+    vm.set_sender(addr(3));
+    assert!(matches!(
+        token.burn_from(addr(2), U256::from(100u64)),
+        Err(ERC20Error::TransferRestricted(_))
+    ));
+
+    // Whitelisting either party unblocks it
+    vm.set_sender(addr(1));
+    ok(token.add_to_whitelist(addr(3)));
+    vm.set_sender(addr(3));
+    ok(token.burn_from(addr(2), U256::from(100u64)));
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(900u64));
+    assert_eq!(ok(token.allowance(addr(2), addr(3))), U256::from(400u64));
+}
+
+// ============================================================================
+// BATCH ROLE MANAGEMENT TESTS
+// ============================================================================
+
+#[test]
+fn test_batch_grant_role_to_several_accounts() {
+    use stylus_erc20::RoleGranted;
+
+    let (vm, mut token) = deploy();
+    ok(token.grant_role(MINTER_ROLE, addr(2)));
+    let before = events::<RoleGranted>(&vm).len();
+
+    // Zero addresses and existing holders are skipped
+    ok(token.batch_grant_role(MINTER_ROLE, vec![addr(2), addr(3), Address::ZERO, addr(4)]));
+
+    assert!(ok(token.has_role(MINTER_ROLE, addr(2))));
+    assert!(ok(token.has_role(MINTER_ROLE, addr(3))));
+    assert!(ok(token.has_role(MINTER_ROLE, addr(4))));
+    assert!(!ok(token.has_role(MINTER_ROLE, Address::ZERO)));
+    assert_eq!(events::<RoleGranted>(&vm).len() - before, 2);
+}
+
+#[test]
+fn test_batch_revoke_role_skips_non_holders() {
+    use stylus_erc20::RoleRevoked;
+
+    let (vm, mut token) = deploy();
+    ok(token.batch_grant_role(PAUSER_ROLE, vec![addr(2), addr(3)]));
+
+    ok(token.batch_revoke_role(PAUSER_ROLE, vec![addr(2), addr(5)]));
+
+    assert!(!ok(token.has_role(PAUSER_ROLE, addr(2))));
+    assert!(ok(token.has_role(PAUSER_ROLE, addr(3))));
+    assert_eq!(events::<RoleRevoked>(&vm).len(), 1);
+}
+
+#[test]
+fn test_batch_grant_role_requires_role_admin() {
+    let (vm, mut token) = deploy();
+
+    vm.set_sender(addr(2));
+    assert!(matches!(
+        token.batch_grant_role(MINTER_ROLE, vec![addr(2)]),
+        Err(ERC20Error::AccessDenied(_))
+    ));
+    assert!(!ok(token.has_role(MINTER_ROLE, addr(2))));
+}
+
+// ============================================================================
+// PERMIT NONCE INVALIDATION TESTS
+// ============================================================================
+
+#[test]
+fn test_permit_typehashes() {
+    use stylus_erc20::{EIP712_DOMAIN_TYPEHASH, PERMIT_TYPEHASH};
+
+    assert_eq!(
+        PERMIT_TYPEHASH,
+        alloy_primitives::keccak256(
+            "Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)"
+        )
+    );
+    assert_eq!(
+        EIP712_DOMAIN_TYPEHASH,
+        alloy_primitives::keccak256(
+            "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"
+        )
+    );
+}
+
+fn permit_hash(owner: Address, spender: Address, value: U256, nonce: U256, deadline: U256) -> FixedBytes<32> {
+    use stylus_erc20::PERMIT_TYPEHASH;
+
+    alloy_primitives::keccak256((PERMIT_TYPEHASH, owner, spender, value, nonce, deadline).abi_encode())
+}
+
+#[test]
+fn test_permit_consumes_nonce() {
+    let (vm, mut token) = deploy();
+    let deadline = U256::from(2_000u64);
+    let (v, r, s) = mock_signature(
+        &vm,
+        &token,
+        permit_hash(addr(1), addr(2), U256::from(500u64), U256::ZERO, deadline),
+        addr(1),
+    );
+
+    ok(token.permit(addr(1), addr(2), U256::from(500u64), deadline, v, r, s));
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::from(500u64));
+    assert_eq!(ok(token.nonces(addr(1))), U256::from(1u64));
+
+    // Replaying the same signature is checked against the next nonce
+    assert!(matches!(
+        token.permit(addr(1), addr(2), U256::from(500u64), deadline, v, r, s),
+        Err(ERC20Error::InvalidSigner(_))
+    ));
+}
+
+#[test]
+fn test_invalidate_nonces_voids_signed_permit() {
+    let (vm, mut token) = deploy();
+    let deadline = U256::from(2_000u64);
+    let (v, r, s) = mock_signature(
+        &vm,
+        &token,
+        permit_hash(addr(1), addr(2), U256::from(500u64), U256::ZERO, deadline),
+        addr(1),
+    );
+
+    // Owner invalidates outstanding signatures
+    assert_eq!(ok(token.invalidate_nonces(U256::from(100u64))), U256::from(100u64));
+    assert_eq!(ok(token.nonces(addr(1))), U256::from(100u64));
+
+    // The digest is recomputed with the new nonce, so the old signature no longer recovers
+    assert!(matches!(
+        token.permit(addr(1), addr(2), U256::from(500u64), deadline, v, r, s),
+        Err(ERC20Error::InvalidSigner(_))
+    ));
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::ZERO);
+}
+
+#[test]
+fn test_invalidate_nonces_rejects_zero_increment() {
+    let (_vm, mut token) = deploy();
+    assert!(matches!(
+        token.invalidate_nonces(U256::ZERO),
+        Err(ERC20Error::InvalidAmount(_))
+    ));
+    assert_eq!(ok(token.nonces(addr(1))), U256::ZERO);
+}
+
+#[test]
+fn test_nonce_at_max_cannot_be_consumed() {
+    let (vm, mut token) = deploy();
+    ok(token.invalidate_nonces(U256::MAX));
+    assert_eq!(ok(token.nonces(addr(1))), U256::MAX);
+
+    // A valid signature over the last nonce still can't wrap it back to zero
+    let deadline = U256::from(2_000u64);
+    let (v, r, s) = mock_signature(
+        &vm,
+        &token,
+        permit_hash(addr(1), addr(2), U256::from(500u64), U256::MAX, deadline),
+        addr(1),
+    );
+    assert!(matches!(
+        token.permit(addr(1), addr(2), U256::from(500u64), deadline, v, r, s),
+        Err(ERC20Error::NonceOverflow(_))
+    ));
+    assert_eq!(ok(token.nonces(addr(1))), U256::MAX);
+
+    assert!(matches!(
+        token.invalidate_nonces(U256::from(1u64)),
+        Err(ERC20Error::InvalidAmount(_))
+    ));
+}
+
+// ============================================================================
+// WHOLE TOKEN CONVERSION TESTS
+// ============================================================================
+
+#[test]
+fn test_whole_token_round_trip_6_decimals() {
+    let (_vm, mut token) = deploy_uninitialized();
+    ok(token.initialize("USD Coin".into(), "USDC".into(), 6, U256::ZERO, addr(1)));
+
+    let raw = ok(token.from_whole_tokens(U256::from(1_234u64)));
+    assert_eq!(raw, U256::from(1_234_000_000u64));
+    assert_eq!(ok(token.to_whole_tokens(raw)), U256::from(1_234u64));
+}
+
+#[test]
+fn test_whole_token_round_trip_18_decimals() {
+    let (_vm, token) = deploy();
+
+    let raw = ok(token.from_whole_tokens(U256::from(1_000_000u64)));
+    assert_eq!(raw, U256::from(1_000_000_000_000_000_000_000_000u128));
+    assert_eq!(ok(token.to_whole_tokens(raw)), U256::from(1_000_000u64));
+
+    // Fractional raw amounts round down
+    assert_eq!(ok(token.to_whole_tokens(raw + U256::from(1u64))), U256::from(1_000_000u64));
+}
+
+#[test]
+fn test_from_whole_tokens_overflow_rejected() {
+    let (_vm, token) = deploy();
+
+    // Absurd input overflows and is rejected as InvalidAmount
+    assert!(matches!(
+        token.from_whole_tokens(U256::MAX),
+        Err(ERC20Error::InvalidAmount(_))
+    ));
+}
+
+// ============================================================================
+// ETH WITHDRAWAL TESTS
+// ============================================================================
+
+#[test]
+fn test_withdraw_eth_after_funding() {
+    use stylus_erc20::EthWithdrawn;
+
+    let (vm, mut token) = deploy();
+
+    // Contract accidentally funded with 1 ETH
+    vm.set_balance(CONTRACT, U256::from(1_000_000_000_000_000_000u128));
+
+    let amount = U256::from(400_000_000_000_000_000u128);
+    ok(token.withdraw_eth(addr(2), amount));
+
+    let withdrawn = events::<EthWithdrawn>(&vm);
+    assert_eq!(withdrawn.len(), 1);
+    assert_eq!((withdrawn[0].to, withdrawn[0].amount), (addr(2), amount));
+}
+
+#[test]
+fn test_withdraw_eth_insufficient_balance() {
+    let (vm, mut token) = deploy();
+    vm.set_balance(CONTRACT, U256::from(100u64));
+
+    assert!(matches!(
+        token.withdraw_eth(addr(2), U256::from(101u64)),
+        Err(ERC20Error::InsufficientEthBalance(_))
+    ));
+}
+
+#[test]
+fn test_withdraw_eth_only_owner() {
+    let (vm, mut token) = deploy();
+    vm.set_balance(CONTRACT, U256::from(100u64));
+
+    vm.set_sender(addr(2));
+    assert!(matches!(
+        token.withdraw_eth(addr(2), U256::from(100u64)),
+        Err(ERC20Error::NotOwner(_))
+    ));
+
+    vm.set_sender(addr(1));
+    assert!(matches!(
+        token.withdraw_eth(Address::ZERO, U256::from(100u64)),
+        Err(ERC20Error::ZeroAddress(_))
+    ));
+}
+
+// ============================================================================
+// REINITIALIZATION TESTS
+// ============================================================================
+
+#[test]
+fn test_reinitialize_to_version_2() {
+    use stylus_erc20::Reinitialized;
+
+    let (vm, mut token) = deploy();
+    assert_eq!(ok(token.contract_version()), U256::from(1u64));
+
+    ok(token.reinitialize(U256::from(2u64)));
+    assert_eq!(ok(token.contract_version()), U256::from(2u64));
+
+    let logs = events::<Reinitialized>(&vm);
+    assert_eq!(logs.len(), 1);
+    assert_eq!((logs[0].old_version, logs[0].new_version), (U256::from(1u64), U256::from(2u64)));
+}
+
+#[test]
+fn test_reinitialize_same_version_twice_reverts() {
+    let (_vm, mut token) = deploy();
+
+    ok(token.reinitialize(U256::from(2u64)));
+    assert!(matches!(
+        token.reinitialize(U256::from(2u64)),
+        Err(ERC20Error::InvalidVersion(_))
+    ));
+}
+
+#[test]
+fn test_reinitialize_lower_version_reverts() {
+    let (_vm, mut token) = deploy();
+    ok(token.reinitialize(U256::from(3u64)));
+
+    // Version 2 is not greater than the current version
+    assert!(matches!(
+        token.reinitialize(U256::from(2u64)),
+        Err(ERC20Error::InvalidVersion(_))
+    ));
+    assert_eq!(ok(token.contract_version()), U256::from(3u64));
+}
+
+#[test]
+fn test_reinitialize_only_owner() {
+    let (vm, mut token) = deploy();
+
+    vm.set_sender(addr(2));
+    assert!(matches!(
+        token.reinitialize(U256::from(2u64)),
+        Err(ERC20Error::NotOwner(_))
+    ));
+}
+
+// ============================================================================
+// CIRCULATING SUPPLY AND DEAD-ADDRESS BURN TESTS
+// ============================================================================
+
+#[test]
+fn test_dead_address_constant() {
+    use stylus_erc20::DEAD_ADDRESS;
+
+    let mut expected = [0u8; 20];
+    expected[18] = 0xde;
+    expected[19] = 0xad;
+    assert_eq!(DEAD_ADDRESS, Address::from(expected));
+}
+
+#[test]
+fn test_circulating_supply_excludes_dead_and_contract_balances() {
+    use stylus_erc20::DEAD_ADDRESS;
+
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, DEAD_ADDRESS, 100_000);
+    fund(&vm, &mut token, CONTRACT, 50_000);
+
+    assert_eq!(ok(token.total_supply()), U256::from(SUPPLY));
+    assert_eq!(ok(token.circulating_supply()), U256::from(SUPPLY - 150_000));
+}
+
+#[test]
+fn test_transfer_to_dead_without_burn_mode() {
+    use stylus_erc20::DEAD_ADDRESS;
+
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(2), 1_000);
+
+    vm.set_sender(addr(2));
+    ok(token.transfer(DEAD_ADDRESS, U256::from(300u64)));
+
+    // Total supply unchanged, circulating reduced
+    assert_eq!(ok(token.total_supply()), U256::from(SUPPLY));
+    assert_eq!(ok(token.balance_of(DEAD_ADDRESS)), U256::from(300u64));
+    assert_eq!(ok(token.circulating_supply()), U256::from(SUPPLY - 300));
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(700u64));
+}
+
+#[test]
+fn test_transfer_to_dead_with_burn_mode() {
+    use stylus_erc20::DEAD_ADDRESS;
+
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(2), 1_000);
+    ok(token.set_burn_on_dead_transfer(true));
+
+    vm.set_sender(addr(2));
+    ok(token.transfer(DEAD_ADDRESS, U256::from(300u64)));
+
+    // Routed through burn logic: supply reduced, dead address holds nothing
+    assert_eq!(ok(token.total_supply()), U256::from(SUPPLY - 300));
+    assert_eq!(ok(token.balance_of(DEAD_ADDRESS)), U256::ZERO);
+    assert_eq!(ok(token.total_burned()), U256::from(300u64));
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(700u64));
+}
+
+// ============================================================================
+// TRANSFER HOOK TESTS
+// ============================================================================
+
+/// ABI-encoded `checkTransfer(from, to, amount)` call sent to the hook
+fn hook_calldata(from: Address, to: Address, amount: U256) -> Vec<u8> {
+    let selector = &alloy_primitives::keccak256("checkTransfer(address,address,uint256)")[..4];
+    [selector, &(from, to, amount).abi_encode()[..]].concat()
+}
+
+/// Mocks `checkTransfer(from, to, amount)` on `hook` to return `answer`
+fn mock_hook(vm: &TestVM, hook: Address, from: Address, to: Address, amount: U256, answer: bool) {
+    vm.mock_static_call(hook, hook_calldata(from, to, amount), Ok(answer.abi_encode()));
+}
+
+#[test]
+fn test_transfer_hook_approves() {
+    let (vm, mut token) = deploy();
+    ok(token.set_transfer_hook(addr(9)));
+    mock_hook(&vm, addr(9), addr(1), addr(2), U256::from(10u64), true);
+
+    ok(token.transfer(addr(2), U256::from(10u64)));
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(10u64));
+}
+
+#[test]
+fn test_transfer_hook_rejects() {
+    let (vm, mut token) = deploy();
+    ok(token.set_transfer_hook(addr(9)));
+    mock_hook(&vm, addr(9), addr(1), addr(2), U256::from(10u64), false);
+
+    assert!(matches!(
+        token.transfer(addr(2), U256::from(10u64)),
+        Err(ERC20Error::TransferRejectedByHook(_))
+    ));
+    assert_eq!(ok(token.balance_of(addr(2))), U256::ZERO);
+}
+
+#[test]
+fn test_transfer_hook_revert_counts_as_rejection() {
+    let (vm, mut token) = deploy();
+    ok(token.set_transfer_hook(addr(9)));
+    vm.mock_static_call(addr(9), hook_calldata(addr(1), addr(2), U256::from(10u64)), Err(vec![]));
+
+    assert!(matches!(
+        token.transfer(addr(2), U256::from(10u64)),
+        Err(ERC20Error::TransferRejectedByHook(_))
+    ));
+}
+
+#[test]
+fn test_no_transfer_hook_means_no_call() {
+    let (vm, mut token) = deploy();
+    ok(token.set_transfer_hook(addr(9)));
+    ok(token.set_transfer_hook(Address::ZERO));
+
+    // Zero hook address: transfer proceeds without an external call
+    ok(token.transfer(addr(2), U256::from(10u64)));
+    assert_eq!(ok(token.transfer_hook()), Address::ZERO);
+    assert_eq!(events::<stylus_erc20::TransferHookUpdated>(&vm).len(), 2);
+}
+
+// ============================================================================
+// SNAPSHOT VALIDATION TESTS
+// ============================================================================
+
+/// Deploys a token with holders addr(1..=3), records them all into snapshot 1 and finalizes it
+fn recorded_snapshot() -> (TestVM, ERC20Token, U256) {
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(2), 200);
+    fund(&vm, &mut token, addr(3), 300);
+    let id = ok(token.snapshot());
+    ok(token.snapshot_all(U256::ZERO, U256::from(3u64)));
+    ok(token.finalize_snapshot());
+    (vm, token, id)
+}
+
+#[test]
+fn test_validate_complete_snapshot() {
+    let (_vm, token, id) = recorded_snapshot();
+    assert!(ok(token.validate_snapshot(id, vec![addr(1), addr(2), addr(3)])));
+}
+
+#[test]
+fn test_validate_partial_snapshot_subset() {
+    let (_vm, token, id) = recorded_snapshot();
+
+    // A subset's partial sum stays within the recorded total
+    assert!(ok(token.validate_snapshot(id, vec![addr(2), addr(3)])));
+}
+
+#[test]
+fn test_validate_snapshot_counts_duplicates_once() {
+    let (_vm, token, id) = recorded_snapshot();
+
+    // Repeating the largest holder would otherwise push the sum past the total
+    assert!(ok(token.validate_snapshot(id, vec![addr(1), addr(1), addr(2)])));
+}
+
+#[test]
+fn test_validate_snapshot_in_progress_rejected() {
+    let (_vm, mut token) = deploy();
+    let id = ok(token.snapshot());
+    ok(token.snapshot_all(U256::ZERO, U256::from(1u64)));
+
+    assert!(matches!(
+        token.validate_snapshot(id, vec![addr(1)]),
+        Err(ERC20Error::SnapshotNotFound(_))
+    ));
+}
+
+#[test]
+fn test_validate_snapshot_unknown_id() {
+    let (_vm, token, id) = recorded_snapshot();
+
+    assert!(matches!(
+        token.validate_snapshot(U256::ZERO, vec![addr(1)]),
+        Err(ERC20Error::SnapshotNotFound(_))
+    ));
+    // The next id has not been taken yet
+    assert!(matches!(
+        token.validate_snapshot(id + U256::from(1u64), vec![addr(1)]),
+        Err(ERC20Error::SnapshotNotFound(_))
+    ));
+}
+
+// ============================================================================
+// BLACKLIST GRACE PERIOD TESTS
+// ============================================================================
+
+#[test]
+fn test_scheduled_blacklist_allows_transfers_until_effective() {
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(2), 1_000);
+    ok(token.set_blacklist_enabled(true));
+    ok(token.blacklist_with_delay(addr(2), U256::from(3_600u64)));
+    assert_eq!(ok(token.blacklist_effective_at(addr(2))), U256::from(4_600u64));
+
+    // Still within the grace period: transfers allowed
+    vm.set_block_timestamp(1_010);
+    vm.set_sender(addr(2));
+    ok(token.transfer(addr(3), U256::from(100u64)));
+
+    // After the delay: transfers blocked
+    vm.set_block_timestamp(4_600);
+    assert!(matches!(
+        token.transfer(addr(3), U256::from(100u64)),
+        Err(ERC20Error::AddressBlacklisted(_))
+    ));
+}
+
+#[test]
+fn test_unblacklist_cancels_scheduled_blacklist() {
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(2), 1_000);
+    ok(token.set_blacklist_enabled(true));
+    ok(token.blacklist_with_delay(addr(2), U256::from(4_000u64)));
+
+    // Cancel before it takes effect
+    ok(token.unblacklist(addr(2)));
+
+    vm.set_block_timestamp(5_001);
+    vm.set_sender(addr(2));
+    ok(token.transfer(addr(3), U256::from(100u64)));
+    assert!(!ok(token.is_blacklisted(addr(2))));
+}
+
+#[test]
+fn test_immediate_blacklist_is_active() {
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(2), 1_000);
+    ok(token.set_blacklist_enabled(true));
+    ok(token.blacklist(addr(2)));
+
+    // Instant blacklist stores effective_at = 0
+    assert_eq!(ok(token.blacklist_effective_at(addr(2))), U256::ZERO);
+    vm.set_sender(addr(2));
+    assert!(matches!(
+        token.transfer(addr(3), U256::from(1u64)),
+        Err(ERC20Error::AddressBlacklisted(_))
+    ));
+}
+
+// ============================================================================
+// SPENDABLE ALLOWANCE TESTS
+// ============================================================================
+
+#[test]
+fn test_spendable_allowance_limited_by_balance() {
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(2), 10);
+    vm.set_sender(addr(2));
+    ok(token.approve(addr(3), U256::from(1_000u64)));
+
+    assert_eq!(ok(token.spendable_allowance(addr(2), addr(3))), U256::from(10u64));
+}
+
+#[test]
+fn test_spendable_allowance_limited_by_allowance() {
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(2), 10_000);
+    vm.set_sender(addr(2));
+    ok(token.approve(addr(3), U256::from(50u64)));
+
+    assert_eq!(ok(token.spendable_allowance(addr(2), addr(3))), U256::from(50u64));
+}
+
+#[test]
+fn test_spendable_allowance_frozen_owner() {
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(2), 10_000);
+    vm.set_sender(addr(2));
+    ok(token.approve(addr(3), U256::from(50u64)));
+
+    // A blacklisted owner cannot be spent from at all
+    vm.set_sender(addr(1));
+    ok(token.set_blacklist_enabled(true));
+    ok(token.blacklist(addr(2)));
+    assert_eq!(ok(token.spendable_allowance(addr(2), addr(3))), U256::ZERO);
+    assert_eq!(ok(token.allowance(addr(2), addr(3))), U256::from(50u64));
+}
+
+// ============================================================================
+// DISPLAY DECIMALS OVERRIDE TESTS
+// ============================================================================
+
+#[test]
+fn test_display_decimals_default_matches() {
+    let (_vm, token) = deploy();
+    assert_eq!(ok(token.decimals()), 18);
+}
+
+#[test]
+fn test_display_decimals_override_does_not_affect_arithmetic() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_display_decimals(6));
+
+    // Reported decimals change
+    assert_eq!(ok(token.decimals()), 6);
+
+    // Accounting still uses the real decimals
+    let raw = ok(token.from_whole_tokens(U256::from(5u64)));
+    assert_eq!(raw, U256::from(5_000_000_000_000_000_000u128));
+    assert_eq!(ok(token.to_whole_tokens(raw)), U256::from(5u64));
+
+    // Zero restores the real decimals
+    ok(token.set_display_decimals(0));
+    assert_eq!(ok(token.decimals()), 18);
+}
+
+// ============================================================================
+// PAUSE EXPIRY TESTS
+// ============================================================================
+
+#[test]
+fn test_pause_until_blocks_then_auto_expires() {
+    let (vm, mut token) = deploy();
+    ok(token.pause_until(U256::from(1_600u64)));
+    assert_eq!(ok(token.pause_expiry()), U256::from(1_600u64));
+
+    // Transfers revert while paused
+    vm.set_block_timestamp(1_599);
+    assert!(ok(token.paused()));
+    assert!(matches!(
+        token.transfer(addr(2), U256::from(1u64)),
+        Err(ERC20Error::ContractPaused(_))
+    ));
+
+    // Transfers succeed once the expiry passes, without an explicit unpause
+    vm.set_block_timestamp(1_600);
+    assert!(!ok(token.paused()));
+    ok(token.transfer(addr(2), U256::from(1u64)));
+}
+
+#[test]
+fn test_pause_until_rejects_past_expiry() {
+    let (_vm, mut token) = deploy();
+    match token.pause_until(U256::from(1_000u64)) {
+        Err(ERC20Error::InvalidExpiry(e)) => {
+            assert_eq!(e.expiry, U256::from(1_000u64));
+            assert_eq!(e.current_time, U256::from(1_000u64));
+        }
+        _ => panic!("expected InvalidExpiry"),
+    }
+}
+
+#[test]
+fn test_indefinite_pause_has_no_expiry() {
+    let (vm, mut token) = deploy();
+    ok(token.pause());
+
+    vm.set_block_timestamp(u64::MAX);
+    assert!(ok(token.paused()));
+    assert_eq!(ok(token.pause_expiry()), U256::ZERO);
+}
+
+#[test]
+fn test_guardian_pause_bounded_to_72_hours() {
+    use stylus_erc20::GUARDIAN_PAUSE_DURATION;
+
+    assert_eq!(GUARDIAN_PAUSE_DURATION, 72 * 60 * 60);
+
+    let (vm, mut token) = deploy();
+    ok(token.set_guardian(addr(5)));
+    vm.set_sender(addr(5));
+    ok(token.guardian_pause());
+
+    vm.set_block_timestamp(1_000 + 71 * 60 * 60);
+    assert!(ok(token.paused()));
+    vm.set_block_timestamp(1_000 + GUARDIAN_PAUSE_DURATION);
+    assert!(!ok(token.paused()));
+}
+
+// ============================================================================
+// BATCH FAILURE INDEX TESTS
+// ============================================================================
+
+#[test]
+fn test_batch_transfer_reports_failing_index() {
+    use stylus_erc20::InsufficientBalance;
+    use stylus_sdk::alloy_sol_types::SolError;
+
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(5), 100);
+    vm.set_sender(addr(5));
+
+    let result = token.batch_transfer(
+        vec![addr(2), addr(3), addr(4)],
+        vec![U256::from(60u64), U256::from(60u64), U256::from(10u64)],
+    );
+
+    // Second item fails with insufficient balance
+    match result {
+        Err(ERC20Error::BatchItemFailed(e)) => {
+            assert_eq!(e.index, U256::from(1u64));
+            assert_eq!(e.reason[..4], InsufficientBalance::SELECTOR);
+        }
+        _ => panic!("expected BatchItemFailed"),
+    }
+}
+
+#[test]
+fn test_batch_transfer_length_mismatch() {
+    let (_vm, mut token) = deploy();
+    assert!(matches!(
+        token.batch_transfer(vec![addr(2), addr(3)], vec![U256::from(1u64)]),
+        Err(ERC20Error::BatchTransferLengthMismatch(_))
+    ));
+}
+
+// ============================================================================
+// INITIAL ALLOCATION TESTS
+// ============================================================================
+
+#[test]
+fn test_three_way_initial_allocation() {
+    use stylus_erc20::Transfer;
+
+    let (vm, mut token) = deploy_uninitialized();
+    ok(token.initialize_with_allocations(
+        "Test Token".into(),
+        "TST".into(),
+        18,
+        vec![addr(1), addr(2), addr(3)],
+        vec![U256::from(200_000u64), U256::from(500_000u64), U256::from(300_000u64)],
+        addr(1),
+    ));
+
+    // One Transfer from zero per recipient, total supply equals the sum
+    let mints: Vec<Transfer> = events::<Transfer>(&vm)
+        .into_iter()
+        .filter(|t| t.from == Address::ZERO)
+        .collect();
+    assert_eq!(mints.len(), 3);
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(500_000u64));
+    assert_eq!(ok(token.total_supply()), U256::from(1_000_000u64));
+    assert_eq!(ok(token.owner()), addr(1));
+}
+
+#[test]
+fn test_initial_allocation_length_mismatch_reverts() {
+    let (_vm, mut token) = deploy_uninitialized();
+    assert!(matches!(
+        token.initialize_with_allocations(
+            "Test Token".into(),
+            "TST".into(),
+            18,
+            vec![addr(1), addr(2)],
+            vec![U256::from(1u64)],
+            addr(1),
+        ),
+        Err(ERC20Error::BatchTransferLengthMismatch(_))
+    ));
+}
+
+#[test]
+fn test_initial_allocation_zero_address_reverts() {
+    let (_vm, mut token) = deploy_uninitialized();
+    assert!(matches!(
+        token.initialize_with_allocations(
+            "Test Token".into(),
+            "TST".into(),
+            18,
+            vec![addr(1), Address::ZERO],
+            vec![U256::from(1u64), U256::from(2u64)],
+            addr(1),
+        ),
+        Err(ERC20Error::ZeroAddress(_))
+    ));
+}
+
+// ============================================================================
+// CAN_TRANSFER POLICY TESTS
+// ============================================================================
+
+#[test]
+fn test_can_transfer_clean() {
+    let (_vm, token) = deploy();
+    assert_eq!(ok(token.can_transfer(addr(1), addr(2), U256::from(10u64))), (true, 0));
+}
+
+#[test]
+fn test_can_transfer_each_gate_has_distinct_code() {
+    use stylus_erc20::*;
+    let amount = U256::from(10u64);
+
+    let (_vm, mut token) = deploy();
+    ok(token.pause());
+    assert_eq!(ok(token.can_transfer(addr(1), addr(2), amount)), (false, TRANSFER_REASON_PAUSED));
+
+    let (_vm, token) = deploy();
+    assert_eq!(
+        ok(token.can_transfer(addr(1), Address::ZERO, amount)),
+        (false, TRANSFER_REASON_ZERO_ADDRESS)
+    );
+
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(3), 100);
+    ok(token.set_blacklist_enabled(true));
+    ok(token.blacklist(addr(3)));
+    assert_eq!(
+        ok(token.can_transfer(addr(3), addr(2), amount)),
+        (false, TRANSFER_REASON_SENDER_BLACKLISTED)
+    );
+    assert_eq!(
+        ok(token.can_transfer(addr(1), addr(3), amount)),
+        (false, TRANSFER_REASON_RECIPIENT_BLACKLISTED)
+    );
+
+    let (_vm, mut token) = deploy();
+    ok(token.set_transfer_restrictions_enabled(true));
+    assert_eq!(
+        ok(token.can_transfer(addr(1), addr(2), amount)),
+        (false, TRANSFER_REASON_RESTRICTED)
+    );
+
+    // An unmocked hook answers with empty data, which counts as a rejection
+    let (_vm, mut token) = deploy();
+    ok(token.set_transfer_hook(addr(9)));
+    assert_eq!(
+        ok(token.can_transfer(addr(1), addr(2), amount)),
+        (false, TRANSFER_REASON_HOOK_REJECTED)
+    );
+
+    let (_vm, token) = deploy();
+    assert_eq!(
+        ok(token.can_transfer(addr(2), addr(1), amount)),
+        (false, TRANSFER_REASON_INSUFFICIENT_BALANCE)
+    );
+}
+
+#[test]
+fn test_can_transfer_matches_transfer_outcome() {
+    use stylus_erc20::TRANSFER_REASON_RESTRICTED;
+
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(2), 100);
+    ok(token.set_transfer_restrictions_enabled(true));
+
+    assert_eq!(
+        ok(token.can_transfer(addr(2), addr(3), U256::from(10u64))),
+        (false, TRANSFER_REASON_RESTRICTED)
+    );
+    vm.set_sender(addr(2));
+    assert!(matches!(
+        token.transfer(addr(3), U256::from(10u64)),
+        Err(ERC20Error::TransferRestricted(_))
+    ));
+}
+
+// ============================================================================
+// COMPLIANCE ROLE TESTS
+// ============================================================================
+
+#[test]
+fn test_compliance_role_constant() {
+    assert_eq!(COMPLIANCE_ROLE, alloy_primitives::keccak256("COMPLIANCE_ROLE"));
+    assert_ne!(COMPLIANCE_ROLE, ADMIN_ROLE);
+}
+
+#[test]
+fn test_compliance_role_can_blacklist() {
+    let (vm, mut token) = deploy();
+
+    // Owner holds the role from initialize
+    assert!(ok(token.has_role(COMPLIANCE_ROLE, addr(1))));
+    ok(token.grant_role(COMPLIANCE_ROLE, addr(2)));
+
+    vm.set_sender(addr(2));
+    ok(token.blacklist(addr(5)));
+    assert!(ok(token.is_blacklisted(addr(5))));
+    ok(token.unblacklist(addr(5)));
+    assert!(!ok(token.is_blacklisted(addr(5))));
+}
+
+#[test]
+fn test_random_account_cannot_blacklist() {
+    let (vm, mut token) = deploy();
+    ok(token.grant_role(COMPLIANCE_ROLE, addr(2)));
+
+    vm.set_sender(addr(9));
+    assert!(matches!(
+        token.blacklist(addr(5)),
+        Err(ERC20Error::AccessDenied(_))
+    ));
+}
+
+// ============================================================================
+// VESTING RELEASE DEFERRAL TESTS
+// ============================================================================
+
+/// Deploys a token with a 1_000 token schedule for addr(2) vesting from t=1_100 over 1_000s
+fn vesting_token() -> (TestVM, ERC20Token) {
+    let (vm, mut token) = deploy();
+    ok(token.create_vesting_schedule(addr(2), U256::from(1_000u64), U256::from(1_100u64), U256::from(1_000u64)));
+    (vm, token)
+}
+
+#[test]
+fn test_vesting_release_linear() {
+    let (vm, mut token) = vesting_token();
+    assert_eq!(ok(token.vesting_custody()), U256::from(1_000u64));
+
+    vm.set_block_timestamp(1_050);
+    assert_eq!(ok(token.release(addr(2))), U256::ZERO);
+    vm.set_block_timestamp(1_600);
+    assert_eq!(ok(token.release(addr(2))), U256::from(500u64));
+    vm.set_block_timestamp(6_000);
+    assert_eq!(ok(token.release(addr(2))), U256::from(500u64));
+
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(1_000u64));
+    assert_eq!(ok(token.vesting_custody()), U256::ZERO);
+}
+
+#[test]
+fn test_vesting_release_deferred_when_beneficiary_cannot_receive() {
+    use stylus_erc20::ReleaseDeferred;
+
+    let (vm, mut token) = vesting_token();
+    ok(token.set_blacklist_enabled(true));
+    ok(token.blacklist(addr(2)));
+
+    // Release does not revert; the amount is credited to pending
+    vm.set_block_timestamp(1_500);
+    assert_eq!(ok(token.release(addr(2))), U256::from(400u64));
+    assert_eq!(ok(token.pending_withdrawal(addr(2))), U256::from(400u64));
+    assert_eq!(ok(token.balance_of(addr(2))), U256::ZERO);
+    let deferred = events::<ReleaseDeferred>(&vm);
+    assert_eq!(deferred.len(), 1);
+    assert_eq!((deferred[0].beneficiary, deferred[0].amount), (addr(2), U256::from(400u64)));
+
+    // Tokens stay in custody and the schedule is still marked released
+    assert_eq!(ok(token.vesting_custody()), U256::from(1_000u64));
+    assert_eq!(ok(token.releasable_amount(addr(2))), U256::ZERO);
+
+    // Withdrawing while still unable to receive reverts
+    vm.set_sender(addr(2));
+    assert!(matches!(
+        token.withdraw_pending(),
+        Err(ERC20Error::AddressBlacklisted(_))
+    ));
+}
+
+#[test]
+fn test_vesting_deferred_amount_later_withdrawable() {
+    let (vm, mut token) = vesting_token();
+    ok(token.set_blacklist_enabled(true));
+    ok(token.blacklist(addr(2)));
+    vm.set_block_timestamp(1_500);
+    ok(token.release(addr(2)));
+
+    // Beneficiary becomes able to receive
+    ok(token.unblacklist(addr(2)));
+    vm.set_sender(addr(2));
+    assert_eq!(ok(token.withdraw_pending()), U256::from(400u64));
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(400u64));
+    assert_eq!(ok(token.pending_withdrawal(addr(2))), U256::ZERO);
+    assert!(matches!(
+        token.withdraw_pending(),
+        Err(ERC20Error::NothingToWithdraw(_))
+    ));
+
+    // Subsequent releases are delivered directly
+    vm.set_block_timestamp(2_100);
+    ok(token.release(addr(2)));
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(1_000u64));
+    assert_eq!(ok(token.vesting_custody()), U256::ZERO);
+}
+
+#[test]
+fn test_vesting_release_propagates_non_restriction_failure() {
+    let (vm, mut token) = vesting_token();
+
+    // An outflow limit on the custody address is not a restriction on the beneficiary
+    ok(token.set_account_outflow_limit(CONTRACT, U256::from(100u64), U256::from(10_000u64)));
+
+    vm.set_block_timestamp(1_500);
+    assert!(matches!(
+        token.release(addr(2)),
+        Err(ERC20Error::AccountOutflowExceeded(_))
+    ));
+    assert!(events::<stylus_erc20::ReleaseDeferred>(&vm).is_empty());
+}
+
+#[test]
+fn test_batch_release_not_blocked_by_bad_recipient() {
+    let (vm, mut token) = deploy();
+    ok(token.create_vesting_schedule(addr(2), U256::from(100u64), U256::from(1_000u64), U256::from(100u64)));
+    ok(token.create_vesting_schedule(addr(3), U256::from(100u64), U256::from(1_000u64), U256::from(100u64)));
+    ok(token.set_blacklist_enabled(true));
+    ok(token.blacklist(addr(3)));
+
+    vm.set_block_timestamp(1_100);
+    ok(token.batch_release(vec![addr(3), addr(2)]));
+
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(100u64));
+    assert_eq!(ok(token.pending_withdrawal(addr(3))), U256::from(100u64));
+}
+
+// ============================================================================
+// ALLOWANCE SNAPSHOT TESTS
+// ============================================================================
+
+#[test]
+fn test_allowance_snapshot_records_historical_value() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_snapshot_allowances_enabled(true));
+    ok(token.approve(addr(2), U256::from(100u64)));
+
+    let id = ok(token.snapshot());
+    ok(token.approve(addr(2), U256::from(500u64))); // approve during the snapshot
+    ok(token.approve(addr(2), U256::from(700u64))); // second change is not re-recorded
+    ok(token.finalize_snapshot());
+
+    assert_eq!(ok(token.allowance_of_at(addr(1), addr(2), id)), U256::from(100u64));
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::from(700u64));
+}
+
+#[test]
+fn test_allowance_snapshot_disabled_by_default() {
+    let (_vm, mut token) = deploy();
+    assert!(!ok(token.snapshot_allowances_enabled()));
+    ok(token.approve(addr(2), U256::from(100u64)));
+
+    let id = ok(token.snapshot());
+    ok(token.approve(addr(2), U256::from(500u64)));
+    ok(token.finalize_snapshot());
+
+    // Nothing is checkpointed; the view falls back to the current allowance
+    assert_eq!(ok(token.allowance_of_at(addr(1), addr(2), id)), U256::from(500u64));
+}
+
+// ============================================================================
+// SELF-TRANSFER TESTS
+// ============================================================================
+
+#[test]
+fn test_self_transfer_full_balance_is_noop() {
+    use stylus_erc20::Transfer;
+
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(2), 1_000);
+    vm.set_sender(addr(2));
+
+    ok(token.transfer(addr(2), U256::from(1_000u64)));
+
+    // Transfer(from, from, amount) is still emitted
+    let last = events::<Transfer>(&vm).pop().unwrap();
+    assert_eq!((last.from, last.to, last.amount), (addr(2), addr(2), U256::from(1_000u64)));
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(1_000u64));
+    assert_eq!(ok(token.total_supply()), U256::from(SUPPLY));
+}
+
+#[test]
+fn test_self_transfer_above_balance_reverts() {
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(2), 1_000);
+    vm.set_sender(addr(2));
+
+    assert!(matches!(
+        token.transfer(addr(2), U256::from(1_001u64)),
+        Err(ERC20Error::InsufficientBalance(_))
+    ));
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(1_000u64));
+}
+
+// ============================================================================
+// MINIMUM TRANSFER AMOUNT TESTS
+// ============================================================================
+
+#[test]
+fn test_min_transfer_below_minimum_reverts() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_min_transfer_amount(U256::from(1_000u64), true));
+
+    match token.transfer(addr(2), U256::from(1u64)) {
+        Err(ERC20Error::TransferBelowMinimum(e)) => {
+            assert_eq!((e.amount, e.min), (U256::from(1u64), U256::from(1_000u64)));
+        }
+        _ => panic!("expected TransferBelowMinimum"),
+    }
+}
+
+#[test]
+fn test_min_transfer_exact_minimum_succeeds() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_min_transfer_amount(U256::from(1_000u64), true));
+
+    ok(token.transfer(addr(2), U256::from(1_000u64)));
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(1_000u64));
+}
+
+#[test]
+fn test_min_transfer_zero_amount_allowed() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_min_transfer_amount(U256::from(1_000u64), true));
+
+    ok(token.transfer(addr(2), U256::ZERO));
+}
+
+#[test]
+fn test_min_transfer_whitelisted_sender_bypasses() {
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(2), 100);
+    ok(token.set_min_transfer_amount(U256::from(1_000u64), true));
+    ok(token.add_to_whitelist(addr(1)));
+
+    ok(token.transfer(addr(3), U256::from(1u64)));
+    vm.set_sender(addr(2));
+    assert!(matches!(
+        token.transfer(addr(3), U256::from(1u64)),
+        Err(ERC20Error::TransferBelowMinimum(_))
+    ));
+}
+
+#[test]
+fn test_min_transfer_disabled() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_min_transfer_amount(U256::from(1_000u64), false));
+
+    ok(token.transfer(addr(2), U256::from(1u64)));
+    assert!(!ok(token.min_transfer_enabled()));
+}
+
+#[test]
+fn test_min_transfer_reason_code() {
+    use stylus_erc20::TRANSFER_REASON_BELOW_MINIMUM;
+
+    assert_eq!(TRANSFER_REASON_BELOW_MINIMUM, 8);
+
+    let (_vm, mut token) = deploy();
+    ok(token.set_min_transfer_amount(U256::from(1_000u64), true));
+    assert_eq!(
+        ok(token.can_transfer(addr(1), addr(2), U256::from(1u64))),
+        (false, TRANSFER_REASON_BELOW_MINIMUM)
+    );
+}
+
+// ============================================================================
+// STRICT APPROVAL TESTS
+// ============================================================================
+
+#[test]
+fn test_strict_approve_nonzero_to_nonzero_reverts() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_strict_approvals_enabled(true));
+    ok(token.approve(addr(2), U256::from(100u64)));
+
+    match token.approve(addr(2), U256::from(200u64)) {
+        Err(ERC20Error::ApproveFromNonZero(e)) => {
+            assert_eq!((e.current_allowance, e.amount), (U256::from(100u64), U256::from(200u64)));
+        }
+        _ => panic!("expected ApproveFromNonZero"),
+    }
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::from(100u64));
+}
+
+#[test]
+fn test_strict_approve_nonzero_to_zero_allowed() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_strict_approvals_enabled(true));
+    ok(token.approve(addr(2), U256::from(100u64)));
+
+    ok(token.approve(addr(2), U256::ZERO));
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::ZERO);
+}
+
+#[test]
+fn test_strict_approve_zero_to_nonzero_allowed() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_strict_approvals_enabled(true));
+
+    ok(token.approve(addr(2), U256::from(200u64)));
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::from(200u64));
+}
+
+#[test]
+fn test_strict_approve_disabled_classic_behavior() {
+    let (_vm, mut token) = deploy();
+    assert!(!ok(token.strict_approvals_enabled()));
+    ok(token.approve(addr(2), U256::from(100u64)));
+
+    ok(token.approve(addr(2), U256::from(200u64)));
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::from(200u64));
+}
+
+// ============================================================================
+// OWNERSHIP RENOUNCED EVENT TESTS
+// ============================================================================
+
+#[test]
+fn test_renounce_emits_both_events() {
+    use stylus_erc20::{OwnershipRenounced, OwnershipTransferred};
+
+    let (vm, mut token) = deploy();
+    vm.set_block_timestamp(1_700_000_000);
+    ok(token.renounce_ownership());
+
+    assert_eq!(ok(token.owner()), Address::ZERO);
+
+    let transferred = events::<OwnershipTransferred>(&vm).pop().unwrap();
+    assert_eq!((transferred.previous_owner, transferred.new_owner), (addr(1), Address::ZERO));
+
+    let renounced = events::<OwnershipRenounced>(&vm);
+    assert_eq!(renounced.len(), 1);
+    assert_eq!(
+        (renounced[0].previous_owner, renounced[0].timestamp),
+        (addr(1), U256::from(1_700_000_000u64))
+    );
+}
+
+#[test]
+fn test_renounce_requires_owner() {
+    let (vm, mut token) = deploy();
+
+    vm.set_sender(addr(2));
+    assert!(matches!(
+        token.renounce_ownership(),
+        Err(ERC20Error::NotOwner(_))
+    ));
+    assert!(events::<stylus_erc20::OwnershipRenounced>(&vm).is_empty());
+}
+
+// ============================================================================
+// APPROVAL REGISTRY TESTS
+// ============================================================================
+
+/// Returns the enumerated spenders of `owner`, in registry order
+fn spenders(token: &ERC20Token, owner: Address) -> Vec<Address> {
+    let count = ok(token.spender_count(owner)).to::<u64>();
+    (0..count).map(|i| ok(token.spender_at(owner, U256::from(i)))).collect()
+}
+
+#[test]
+fn test_spender_registry_enumerates_active_approvals() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_track_approvals_enabled(true));
+
+    ok(token.approve(addr(2), U256::from(100u64)));
+    ok(token.approve(addr(3), U256::from(200u64)));
+    ok(token.approve(addr(4), U256::from(300u64)));
+    ok(token.approve(addr(3), U256::from(250u64))); // Updating does not duplicate
+
+    // Revoke one spender; the last entry is swapped into its slot
+    ok(token.approve(addr(2), U256::ZERO));
+
+    assert_eq!(spenders(&token, addr(1)), vec![addr(4), addr(3)]);
+}
+
+#[test]
+fn test_spender_registry_disabled_by_default() {
+    let (_vm, mut token) = deploy();
+    assert!(!ok(token.track_approvals_enabled()));
+
+    ok(token.approve(addr(2), U256::from(100u64)));
+    assert_eq!(ok(token.spender_count(addr(1))), U256::ZERO);
+}
+
+#[test]
+fn test_spender_registry_prunes_when_disabled() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_track_approvals_enabled(true));
+    ok(token.approve(addr(2), U256::from(100u64)));
+
+    ok(token.set_track_approvals_enabled(false));
+    ok(token.approve(addr(2), U256::ZERO));
+    assert!(spenders(&token, addr(1)).is_empty());
+}
+
+// ============================================================================
+// SAFE APPROVE TESTS
+// ============================================================================
+
+#[test]
+fn test_safe_approve_sets_final_allowance() {
+    use stylus_erc20::Approval;
+
+    let (vm, mut token) = deploy();
+    ok(token.approve(addr(2), U256::from(100u64)));
+
+    ok(token.safe_approve(addr(2), U256::from(500u64)));
+
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::from(500u64));
+    let amounts: Vec<U256> = events::<Approval>(&vm).iter().map(|e| e.amount).collect();
+    assert_eq!(amounts, vec![U256::from(100u64), U256::ZERO, U256::from(500u64)]);
+}
+
+#[test]
+fn test_safe_approve_satisfies_strict_mode() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_strict_approvals_enabled(true));
+    ok(token.approve(addr(2), U256::from(100u64)));
+
+    // The intermediate zero makes the change pass the approve-through-zero rule
+    assert!(matches!(
+        token.approve(addr(2), U256::from(500u64)),
+        Err(ERC20Error::ApproveFromNonZero(_))
+    ));
+    ok(token.safe_approve(addr(2), U256::from(500u64)));
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::from(500u64));
+}
+
+// ============================================================================
+// TIMESTAMP SOURCE TESTS (current_time helper)
+// ============================================================================
+
+#[test]
+fn test_ownership_timelock_is_seconds_based() {
+    let (vm, mut token) = deploy();
+    vm.set_block_timestamp(1_700_000_000);
+    ok(token.initiate_ownership_transfer(addr(2)));
+    vm.set_sender(addr(2));
+
+    // One second short of 48 hours: still locked
+    vm.set_block_timestamp(1_700_000_000 + 48 * 60 * 60 - 1);
+    assert!(matches!(
+        token.accept_ownership(),
+        Err(ERC20Error::OwnershipTransferNotYetUnlockable(_))
+    ));
+
+    // Exactly 48 hours later: unlocked
+    vm.set_block_timestamp(1_700_000_000 + 48 * 60 * 60);
+    ok(token.accept_ownership());
+    assert_eq!(ok(token.owner()), addr(2));
+}
+
+#[test]
+fn test_ownership_timelock_not_unlocked_by_block_count() {
+    let (vm, mut token) = deploy();
+    vm.set_block_timestamp(1_700_000_000);
+    ok(token.initiate_ownership_transfer(addr(2)));
+
+    // A few hundred blocks (~0.25s each on Arbitrum) is nowhere near 48 hours
+    vm.set_block_number(1 + 500);
+    vm.set_block_timestamp(1_700_000_000 + 500 / 4);
+    vm.set_sender(addr(2));
+    assert!(matches!(
+        token.accept_ownership(),
+        Err(ERC20Error::OwnershipTransferNotYetUnlockable(_))
+    ));
+}
+
+// ============================================================================
+// BATCH TRANSFER FROM TESTS
+// ============================================================================
+
+/// Deploys a token where addr(2..=4) hold 100, 200 and 300 tokens and have each
+/// approved their full balance to the relayer addr(8)
+fn sweep_fixture() -> (TestVM, ERC20Token) {
+    let (vm, mut token) = deploy();
+    for n in 2..=4u8 {
+        let amount = (n as u64 - 1) * 100;
+        fund(&vm, &mut token, addr(n), amount);
+        vm.set_sender(addr(n));
+        ok(token.approve(addr(8), U256::from(amount)));
+    }
+    vm.set_sender(addr(8));
+    (vm, token)
+}
+
+#[test]
+fn test_batch_transfer_from_sweeps_into_one_recipient() {
+    let (_vm, mut token) = sweep_fixture();
+    let vault = addr(9);
+
+    ok(token.batch_transfer_from(
+        vec![addr(2), addr(3), addr(4)],
+        vault,
+        vec![U256::from(100u64), U256::from(150u64), U256::from(300u64)],
+    ));
+
+    assert_eq!(ok(token.balance_of(vault)), U256::from(550u64));
+    assert_eq!(ok(token.balance_of(addr(3))), U256::from(50u64));
+    assert_eq!(ok(token.allowance(addr(3), addr(8))), U256::from(50u64));
+}
+
+#[test]
+fn test_batch_transfer_from_insufficient_allowance_reports_index() {
+    use stylus_erc20::InsufficientAllowance;
+    use stylus_sdk::alloy_sol_types::SolError;
+
+    let (_vm, mut token) = sweep_fixture();
+
+    let result = token.batch_transfer_from(
+        vec![addr(2), addr(3), addr(4)],
+        addr(9),
+        vec![U256::from(100u64), U256::from(201u64), U256::from(300u64)],
+    );
+
+    match result {
+        Err(ERC20Error::BatchItemFailed(e)) => {
+            assert_eq!(e.index, U256::from(1u64));
+            assert_eq!(e.reason[..4], InsufficientAllowance::SELECTOR);
+        }
+        _ => panic!("expected BatchItemFailed"),
+    }
+}
+
+#[test]
+fn test_batch_transfer_from_length_mismatch() {
+    let (_vm, mut token) = sweep_fixture();
+    assert!(matches!(
+        token.batch_transfer_from(vec![addr(2), addr(3)], addr(9), vec![U256::from(1u64)]),
+        Err(ERC20Error::BatchTransferFromLengthMismatch(_))
+    ));
+}
+
+#[test]
+fn test_batch_transfer_from_zero_recipient() {
+    let (_vm, mut token) = sweep_fixture();
+    assert!(matches!(
+        token.batch_transfer_from(vec![addr(2)], Address::ZERO, vec![U256::from(1u64)]),
+        Err(ERC20Error::ZeroAddress(_))
+    ));
+}
+
+// ============================================================================
+// PAUSE HISTORY TESTS
+// ============================================================================
+
+#[test]
+fn test_pause_history_records_cycles() {
+    let (vm, mut token) = deploy();
+    ok(token.set_guardian(addr(2)));
+
+    vm.set_block_timestamp(1_100);
+    ok(token.pause());
+    vm.set_block_timestamp(1_200);
+    ok(token.unpause());
+    vm.set_block_timestamp(1_300);
+    vm.set_sender(addr(2));
+    ok(token.guardian_pause());
+
+    assert_eq!(ok(token.pause_event_count()), U256::from(3u64));
+    assert_eq!(ok(token.pause_event_at(U256::ZERO)), (true, addr(1), U256::from(1_100u64)));
+    assert_eq!(ok(token.pause_event_at(U256::from(1u64))), (false, addr(1), U256::from(1_200u64)));
+    assert_eq!(ok(token.pause_event_at(U256::from(2u64))), (true, addr(2), U256::from(1_300u64)));
+    assert!(matches!(
+        token.pause_event_at(U256::from(3u64)),
+        Err(ERC20Error::PauseEventIndexOutOfBounds(_))
+    ));
+}
+
+#[test]
+fn test_pause_history_is_bounded() {
+    use stylus_erc20::PAUSE_HISTORY_SIZE;
+
+    let (vm, mut token) = deploy();
+    for i in 0..(PAUSE_HISTORY_SIZE + 10) {
+        vm.set_block_timestamp(10_000 + i);
+        if i % 2 == 0 {
+            ok(token.pause());
+        } else {
+            ok(token.unpause());
+        }
+    }
+
+    // Only the most recent entries are retained, oldest first
+    let size = U256::from(PAUSE_HISTORY_SIZE);
+    assert_eq!(ok(token.pause_event_count()), size);
+    assert_eq!(ok(token.pause_event_at(U256::ZERO)).2, U256::from(10_010u64));
+    assert_eq!(
+        ok(token.pause_event_at(size - U256::from(1u64))).2,
+        U256::from(10_000 + PAUSE_HISTORY_SIZE + 9)
+    );
+}
+
+// ============================================================================
+// TRANSFER_FROM CHECK ORDER TESTS
+// ============================================================================
+
+#[test]
+fn test_transfer_from_blacklist_reported_before_allowance() {
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(3), 1_000);
+    ok(token.set_blacklist_enabled(true));
+    ok(token.blacklist(addr(3)));
+
+    // No allowance either, but the blacklist is reported first
+    vm.set_sender(addr(4));
+    assert!(matches!(
+        token.transfer_from(addr(3), addr(2), U256::from(100u64)),
+        Err(ERC20Error::AddressBlacklisted(_))
+    ));
+}
+
+#[test]
+fn test_transfer_from_blacklisted_recipient_leaves_allowance_untouched() {
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(3), 1_000);
+    vm.set_sender(addr(3));
+    ok(token.approve(addr(4), U256::from(500u64)));
+
+    vm.set_sender(addr(1));
+    ok(token.set_blacklist_enabled(true));
+    ok(token.blacklist(addr(2)));
+
+    vm.set_sender(addr(4));
+    assert!(matches!(
+        token.transfer_from(addr(3), addr(2), U256::from(100u64)),
+        Err(ERC20Error::AddressBlacklisted(_))
+    ));
+    assert_eq!(ok(token.allowance(addr(3), addr(4))), U256::from(500u64));
+}
+
+#[test]
+fn test_transfer_from_zero_recipient_reported_first() {
+    let (vm, mut token) = deploy();
+    ok(token.set_blacklist_enabled(true));
+    ok(token.blacklist(addr(3)));
+
+    vm.set_sender(addr(4));
+    assert!(matches!(
+        token.transfer_from(addr(3), Address::ZERO, U256::from(100u64)),
+        Err(ERC20Error::ZeroAddress(_))
+    ));
+}
+
+// ============================================================================
+// UNIFIED PAUSE AUTHORIZATION TESTS
+// ============================================================================
+
+#[test]
+fn test_owner_can_pause_without_pauser_role() {
+    let (_vm, mut token) = deploy();
+    ok(token.revoke_role(PAUSER_ROLE, addr(1)));
+
+    ok(token.pause());
+    assert!(ok(token.paused()));
+}
+
+#[test]
+fn test_pauser_role_can_pause() {
+    let (vm, mut token) = deploy();
+    ok(token.grant_role(PAUSER_ROLE, addr(2)));
+
+    vm.set_sender(addr(2));
+    ok(token.pause());
+    assert!(ok(token.paused()));
+    ok(token.unpause());
+    assert!(!ok(token.paused()));
+}
+
+#[test]
+fn test_unauthorized_pause_rejected() {
+    let (vm, mut token) = deploy();
+    ok(token.grant_role(PAUSER_ROLE, addr(2)));
+
+    vm.set_sender(addr(3));
+    assert!(matches!(
+        token.pause(),
+        Err(ERC20Error::AccessDenied(_))
+    ));
+    assert!(!ok(token.paused()));
+}
+
+// ============================================================================
+// MULTIPLE GUARDIANS TESTS
+// ============================================================================
+
+#[test]
+fn test_any_added_guardian_can_pause() {
+    let (vm, mut token) = deploy();
+    ok(token.add_guardian(addr(2)));
+    ok(token.add_guardian(addr(3)));
+    assert_eq!(ok(token.guardian_count()), U256::from(2u64));
+    assert!(!ok(token.is_guardian(addr(4))));
+
+    vm.set_sender(addr(4));
+    assert!(matches!(
+        token.guardian_pause(),
+        Err(ERC20Error::AccessDenied(_))
+    ));
+
+    vm.set_sender(addr(3));
+    ok(token.guardian_pause());
+    assert!(ok(token.paused()));
+}
+
+#[test]
+fn test_removed_guardian_cannot_pause() {
+    let (vm, mut token) = deploy();
+    ok(token.add_guardian(addr(2)));
+    ok(token.add_guardian(addr(3)));
+    ok(token.remove_guardian(addr(2)));
+
+    assert!(!ok(token.is_guardian(addr(2))));
+    assert!(ok(token.is_guardian(addr(3))));
+    assert!(matches!(
+        token.remove_guardian(addr(2)),
+        Err(ERC20Error::NotGuardian(_))
+    ));
+
+    vm.set_sender(addr(2));
+    assert!(matches!(
+        token.guardian_pause(),
+        Err(ERC20Error::AccessDenied(_))
+    ));
+}
+
+#[test]
+fn test_duplicate_guardian_rejected() {
+    let (_vm, mut token) = deploy();
+    ok(token.add_guardian(addr(2)));
+    assert!(matches!(
+        token.add_guardian(addr(2)),
+        Err(ERC20Error::GuardianAlreadyAdded(_))
+    ));
+    assert!(matches!(
+        token.add_guardian(Address::ZERO),
+        Err(ERC20Error::ZeroAddress(_))
+    ));
+}
+
+// ============================================================================
+// EXPIRING ALLOWANCE TESTS
+// ============================================================================
+
+#[test]
+fn test_expiring_allowance_lapses() {
+    let (vm, mut token) = deploy();
+    ok(token.approve_with_expiry(addr(2), U256::from(500u64), U256::from(2_000u64)));
+    assert_eq!(ok(token.allowance_expiry(addr(1), addr(2))), U256::from(2_000u64));
+
+    vm.set_block_timestamp(1_999);
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::from(500u64));
+    vm.set_block_timestamp(2_000);
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::ZERO);
+
+    vm.set_sender(addr(2));
+    assert!(matches!(
+        token.transfer_from(addr(1), addr(3), U256::from(1u64)),
+        Err(ERC20Error::InsufficientAllowance(_))
+    ));
+}
+
+#[test]
+fn test_approve_with_past_expiry_rejected() {
+    let (_vm, mut token) = deploy();
+    assert!(matches!(
+        token.approve_with_expiry(addr(2), U256::from(500u64), U256::from(1_000u64)),
+        Err(ERC20Error::InvalidExpiry(_))
+    ));
+}
+
+#[test]
+fn test_revoke_expired_allowance_permissionless() {
+    let (vm, mut token) = deploy();
+    ok(token.approve_with_expiry(addr(2), U256::from(500u64), U256::from(2_000u64)));
+    vm.set_sender(addr(7));
+
+    // Cannot clean up before expiry
+    vm.set_block_timestamp(1_999);
+    assert!(matches!(
+        token.revoke_expired_allowance(addr(1), addr(2)),
+        Err(ERC20Error::AllowanceNotExpired(_))
+    ));
+
+    // Any keeper can zero it out afterwards
+    vm.set_block_timestamp(2_001);
+    ok(token.revoke_expired_allowance(addr(1), addr(2)));
+    assert_eq!(ok(token.allowance_expiry(addr(1), addr(2))), U256::ZERO);
+
+    // Once cleared there is nothing left to revoke
+    assert!(matches!(
+        token.revoke_expired_allowance(addr(1), addr(2)),
+        Err(ERC20Error::AllowanceNotExpired(_))
+    ));
+}
+
+#[test]
+fn test_non_expiring_allowance_cannot_be_revoked_by_keeper() {
+    let (vm, mut token) = deploy();
+    ok(token.approve(addr(2), U256::from(500u64)));
+
+    vm.set_block_timestamp(u64::MAX);
+    assert!(matches!(
+        token.revoke_expired_allowance(addr(1), addr(2)),
+        Err(ERC20Error::AllowanceNotExpired(_))
+    ));
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::from(500u64));
+}
+
+#[test]
+fn test_revoke_expired_prunes_spender_registry() {
+    let (vm, mut token) = deploy();
+    ok(token.set_track_approvals_enabled(true));
+    ok(token.approve_with_expiry(addr(2), U256::from(500u64), U256::from(2_000u64)));
+    assert_eq!(ok(token.spender_count(addr(1))), U256::from(1u64));
+
+    vm.set_block_timestamp(2_000);
+    ok(token.revoke_expired_allowance(addr(1), addr(2)));
+    assert_eq!(ok(token.spender_count(addr(1))), U256::ZERO);
+}
+
+// ============================================================================
+// TRANSFER CHECKED TESTS
+// ============================================================================
+
+#[test]
+fn test_transfer_checked_net_above_minimum() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_transfer_fee(U256::from(100u64), addr(9)));
+
+    // 1% fee: 1000 sent, 990 delivered
+    ok(token.transfer_checked(addr(2), U256::from(1_000u64), U256::from(990u64)));
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(990u64));
+}
+
+#[test]
+fn test_transfer_checked_net_below_minimum_reverts() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_transfer_fee(U256::from(100u64), addr(9)));
+
+    // 1% fee leaves the recipient 10 short of the requested minimum
+    match token.transfer_checked(addr(2), U256::from(1_000u64), U256::from(1_000u64)) {
+        Err(ERC20Error::BalanceCheckFailed(e)) => {
+            assert_eq!((e.account, e.balance, e.min_balance), (addr(2), U256::from(990u64), U256::from(1_000u64)));
+        }
+        _ => panic!("expected BalanceCheckFailed"),
+    }
+}
+
+#[test]
+fn test_transfer_checked_counts_existing_balance() {
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(2), 50);
+
+    ok(token.transfer_checked(addr(2), U256::from(100u64), U256::from(150u64)));
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(150u64));
+}
+
+// ============================================================================
+// KNOWN ROLES TESTS
+// ============================================================================
+
+#[test]
+fn test_known_roles_includes_builtins() {
+    let (_vm, token) = deploy();
+    let roles = ok(token.known_roles());
+
+    for role in [DEFAULT_ADMIN_ROLE, ADMIN_ROLE, MINTER_ROLE, PAUSER_ROLE, COMPLIANCE_ROLE] {
+        assert!(roles.contains(&role));
+    }
+    assert_eq!(roles.len(), 5);
+}
+
+#[test]
+fn test_known_roles_includes_registered_custom_role() {
+    let (_vm, mut token) = deploy();
+    let oracle_role = alloy_primitives::keccak256("ORACLE_ROLE");
+
+    ok(token.set_role_admin(oracle_role, ADMIN_ROLE));
+    ok(token.set_role_admin(oracle_role, ADMIN_ROLE)); // Re-setting the admin does not duplicate
+    ok(token.set_role_admin(MINTER_ROLE, ADMIN_ROLE)); // Built-ins are not re-registered
+
+    let roles = ok(token.known_roles());
+    assert_eq!(roles.len(), 6);
+    assert_eq!(roles[5], oracle_role);
+}
+
+// ============================================================================
+// SNAPSHOT MINT/BURN CHECKPOINT TESTS
+// ============================================================================
+
+#[test]
+fn test_mint_during_open_snapshot_preserves_history() {
+    let (_vm, mut token) = deploy();
+    let id = ok(token.snapshot());
+
+    ok(token.mint(addr(1), U256::from(500u64)));
+    ok(token.mint(addr(1), U256::from(500u64))); // Second change keeps the first checkpoint
+    ok(token.finalize_snapshot());
+
+    assert_eq!(ok(token.balance_of_at(addr(1), id)), U256::from(SUPPLY));
+    assert_eq!(ok(token.total_supply_at(id)), U256::from(SUPPLY));
+    assert_eq!(ok(token.balance_of(addr(1))), U256::from(SUPPLY + 1_000));
+}
+
+#[test]
+fn test_burn_during_open_snapshot_preserves_history() {
+    let (_vm, mut token) = deploy();
+    let id = ok(token.snapshot());
+
+    ok(token.burn(U256::from(400u64)));
+    ok(token.finalize_snapshot());
+
+    assert_eq!(ok(token.balance_of_at(addr(1), id)), U256::from(SUPPLY));
+    assert_eq!(ok(token.total_supply_at(id)), U256::from(SUPPLY));
+    assert_eq!(ok(token.total_supply()), U256::from(SUPPLY - 400));
+}
+
+#[test]
+fn test_snapshot_batch_does_not_overwrite_checkpoint() {
+    let (_vm, mut token) = deploy();
+    let id = ok(token.snapshot());
+
+    ok(token.mint(addr(1), U256::from(500u64)));
+    ok(token.snapshot_all(U256::ZERO, U256::from(1u64))); // Batch reaching the account later
+    ok(token.finalize_snapshot());
+
+    assert_eq!(ok(token.balance_of_at(addr(1), id)), U256::from(SUPPLY));
+}
+
+// ============================================================================
+// TRANSFER FEE SPLIT TESTS
+// ============================================================================
+
+#[test]
+fn test_fee_split_two_way() {
+    let (vm, mut token) = deploy();
+    let treasury = addr(7);
+    let rewards = addr(8);
+    // 3% total fee: 2% treasury, 1% rewards
+    ok(token.set_transfer_fee(U256::from(300u64), treasury));
+    ok(token.set_fee_splits(vec![treasury, rewards], vec![U256::from(200u64), U256::from(100u64)]));
+
+    ok(token.transfer(addr(2), U256::from(10_000u64)));
+
+    assert_eq!(ok(token.balance_of(addr(1))), U256::from(SUPPLY - 10_000)); // Sender debited once
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(9_700u64));
+    assert_eq!(ok(token.claimable_fees(treasury)), U256::from(200u64));
+    assert_eq!(ok(token.claimable_fees(rewards)), U256::from(100u64));
+
+    vm.set_sender(rewards);
+    assert_eq!(ok(token.claim_fees()), U256::from(100u64));
+    assert_eq!(ok(token.balance_of(rewards)), U256::from(100u64));
+}
+
+#[test]
+fn test_fee_split_of_near_max_transfer_conserves_amount() {
+    let (_vm, mut token) = near_max_supply_token();
+    let treasury = addr(7);
+    let rewards = addr(8);
+    ok(token.set_transfer_fee(U256::from(300u64), treasury));
+    ok(token.set_fee_splits(vec![treasury, rewards], vec![U256::from(200u64), U256::from(100u64)]));
+    let amount = ok(token.balance_of(addr(1)));
+    let hundred = U256::from(100u64);
+    let expected_fee = amount / hundred * U256::from(3u64) + amount % hundred * U256::from(3u64) / hundred;
+
+    ok(token.transfer(addr(2), amount));
+
+    assert_eq!(ok(token.balance_of(addr(2))), amount - expected_fee);
+    assert_eq!(ok(token.claimable_fees(rewards)), amount / hundred);
+    assert_eq!(ok(token.claimable_fees(treasury)), expected_fee - amount / hundred);
+}
+
+#[test]
+fn test_fee_split_sum_must_match_fee_bps() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_transfer_fee(U256::from(300u64), addr(7)));
+
+    assert!(matches!(
+        token.set_fee_splits(vec![addr(7), addr(8)], vec![U256::from(200u64), U256::from(50u64)]),
+        Err(ERC20Error::FeeSplitMismatch(_))
+    ));
+    assert!(matches!(
+        token.set_fee_splits(vec![addr(7)], vec![U256::from(200u64), U256::from(100u64)]),
+        Err(ERC20Error::FeeSplitLengthMismatch(_))
+    ));
+}
+
+#[test]
+fn test_fee_splits_cleared_with_empty_vectors() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_transfer_fee(U256::from(300u64), addr(7)));
+    ok(token.set_fee_splits(vec![addr(7), addr(8)], vec![U256::from(200u64), U256::from(100u64)]));
+    ok(token.set_fee_splits(vec![], vec![]));
+
+    // The whole fee goes to fee_recipient again
+    ok(token.transfer(addr(2), U256::from(10_000u64)));
+    assert_eq!(ok(token.claimable_fees(addr(7))), U256::from(300u64));
+    assert_eq!(ok(token.claimable_fees(addr(8))), U256::ZERO);
+}
+
+#[test]
+fn test_max_fee_bps() {
+    use stylus_erc20::MAX_FEE_BPS;
+    assert_eq!(MAX_FEE_BPS, 1_000); // 10%
+
+    let (_vm, mut token) = deploy();
+    assert!(matches!(
+        token.set_transfer_fee(U256::from(MAX_FEE_BPS + 1), addr(7)),
+        Err(ERC20Error::FeeTooHigh(_))
+    ));
+}
+
+#[test]
+fn test_custody_flows_are_not_charged_fees() {
+    let (vm, mut token) = deploy();
+    ok(token.set_transfer_fee(U256::from(100u64), addr(7)));
+    ok(token.transfer(addr(3), U256::from(10_000u64))); // Accrues a 100 fee
+
+    // Moving tokens into vesting custody is fee-free
+    ok(token.create_vesting_schedule(addr(2), U256::from(1_000u64), U256::from(1_100u64), U256::from(1_000u64)));
+    assert_eq!(ok(token.vesting_custody()), U256::from(1_000u64));
+    assert_eq!(ok(token.claimable_fees(addr(7))), U256::from(100u64));
+
+    // Releasing out of custody is fee-free too, so vesting never eats into fee custody
+    vm.set_block_timestamp(2_100);
+    assert_eq!(ok(token.release(addr(2))), U256::from(1_000u64));
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(1_000u64));
+    assert_eq!(ok(token.vesting_custody()), U256::ZERO);
+
+    vm.set_sender(addr(7));
+    assert_eq!(ok(token.claim_fees()), U256::from(100u64));
+    assert_eq!(ok(token.balance_of(addr(7))), U256::from(100u64));
+    assert_eq!(ok(token.balance_of(CONTRACT)), U256::ZERO);
+}
+
+// ============================================================================
+// SWEEP SELF TOKENS TESTS
+// ============================================================================
+
+#[test]
+fn test_sweep_self_tokens() {
+    let (_vm, mut token) = deploy();
+    ok(token.transfer(CONTRACT, U256::from(250u64))); // Sent to the contract by mistake
+
+    ok(token.sweep_self_tokens(addr(7), U256::from(250u64)));
+
+    assert_eq!(ok(token.balance_of(CONTRACT)), U256::ZERO);
+    assert_eq!(ok(token.balance_of(addr(7))), U256::from(250u64));
+}
+
+#[test]
+fn test_sweep_cannot_touch_vesting_custody() {
+    let (_vm, mut token) = deploy();
+    ok(token.create_vesting_schedule(addr(2), U256::from(1_000u64), U256::from(1_100u64), U256::from(1_000u64)));
+    ok(token.transfer(CONTRACT, U256::from(250u64)));
+
+    match token.sweep_self_tokens(addr(7), U256::from(251u64)) {
+        Err(ERC20Error::InsufficientBalance(e)) => {
+            assert_eq!(e.balance, U256::from(250u64));
+            assert_eq!(e.required, U256::from(251u64));
+        }
+        _ => panic!("expected InsufficientBalance"),
+    }
+}
+
+#[test]
+fn test_sweep_to_zero_address_rejected() {
+    let (_vm, mut token) = deploy();
+    ok(token.transfer(CONTRACT, U256::from(250u64)));
+
+    assert!(matches!(
+        token.sweep_self_tokens(Address::ZERO, U256::from(250u64)),
+        Err(ERC20Error::ZeroAddress(_))
+    ));
+}
+
+// ============================================================================
+// TRANSFER ALL / TRANSFER BPS TESTS
+// ============================================================================
+
+#[test]
+fn test_transfer_all_empties_balance() {
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(2), 1_234);
+
+    vm.set_sender(addr(2));
+    ok(token.transfer_all(addr(3)));
+
+    assert_eq!(ok(token.balance_of(addr(2))), U256::ZERO);
+    assert_eq!(ok(token.balance_of(addr(3))), U256::from(1_234u64));
+}
+
+#[test]
+fn test_transfer_half_balance() {
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(2), 1_000);
+
+    vm.set_sender(addr(2));
+    ok(token.transfer_bps(addr(3), U256::from(5_000u64)));
+
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(500u64));
+    assert_eq!(ok(token.balance_of(addr(3))), U256::from(500u64));
+}
+
+#[test]
+fn test_transfer_all_with_fee_on_transfer() {
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(2), 1_000);
+    ok(token.set_transfer_fee(U256::from(100u64), addr(7)));
+
+    // The full balance leaves the sender; the recipient receives it net of the 1% fee
+    vm.set_sender(addr(2));
+    ok(token.transfer_all(addr(3)));
+
+    assert_eq!(ok(token.balance_of(addr(2))), U256::ZERO);
+    assert_eq!(ok(token.balance_of(addr(3))), U256::from(990u64));
+}
+
+#[test]
+fn test_transfer_bps_above_100_percent_rejected() {
+    let (_vm, mut token) = deploy();
+    assert!(matches!(
+        token.transfer_bps(addr(3), U256::from(10_001u64)),
+        Err(ERC20Error::InvalidAmount(_))
+    ));
+}
+
+#[test]
+fn test_transfer_bps_of_near_max_balance_is_exact() {
+    let (_vm, mut token) = near_max_supply_token();
+    let balance = ok(token.balance_of(addr(1)));
+    
+    assert!(ok(token.transfer_bps(addr(3), U256::from(5_000u64))));
+    assert_eq!(ok(token.balance_of(addr(3))), balance / U256::from(2u64));
+    assert_eq!(ok(token.balance_of(addr(1))), balance - balance / U256::from(2u64));
+}
+
+// ============================================================================
+// MINT TO SELF GUARD TESTS
+// ============================================================================
+
+#[test]
+fn test_mint_to_self_rejected_by_default() {
+    let (_vm, mut token) = deploy();
+    assert!(!ok(token.allow_mint_to_self()));
+
+    assert!(matches!(
+        token.mint(CONTRACT, U256::from(100u64)),
+        Err(ERC20Error::CannotMintToSelf(_))
+    ));
+    ok(token.mint(addr(2), U256::from(100u64)));
+}
+
+#[test]
+fn test_mint_to_self_allowed_when_enabled() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_allow_mint_to_self(true));
+
+    ok(token.mint(CONTRACT, U256::from(100u64)));
+    assert_eq!(ok(token.balance_of(CONTRACT)), U256::from(100u64));
+}
+
+// ============================================================================
+// ALLOWANCE FREEZE TESTS
+// ============================================================================
+
+#[test]
+fn test_frozen_allowances_block_transfer_from_only() {
+    let (vm, mut token) = deploy();
+    ok(token.approve(addr(2), U256::from(100u64)));
+    ok(token.set_allowances_frozen(true));
+
+    ok(token.transfer(addr(3), U256::from(10u64))); // Direct transfers keep working
+
+    vm.set_sender(addr(2));
+    assert!(matches!(
+        token.transfer_from(addr(1), addr(2), U256::from(10u64)),
+        Err(ERC20Error::AllowancesFrozen(_))
+    ));
+}
+
+#[test]
+fn test_unfreezing_restores_transfer_from() {
+    let (vm, mut token) = deploy();
+    ok(token.approve(addr(2), U256::from(100u64)));
+    ok(token.set_allowances_frozen(true));
+    ok(token.set_allowances_frozen(false));
+
+    vm.set_sender(addr(2));
+    ok(token.transfer_from(addr(1), addr(2), U256::from(10u64)));
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::from(90u64));
+}
+
+#[test]
+fn test_freeze_authorization() {
+    let (vm, mut token) = deploy();
+    ok(token.grant_role(PAUSER_ROLE, addr(2)));
+    ok(token.add_guardian(addr(3)));
+
+    vm.set_sender(addr(2));
+    ok(token.set_allowances_frozen(true));
+    vm.set_sender(addr(3));
+    ok(token.set_allowances_frozen(false));
+
+    vm.set_sender(addr(4));
+    assert!(matches!(token.set_allowances_frozen(true), Err(ERC20Error::AccessDenied(_))));
+}
+
+#[test]
+fn test_freeze_rejects_paused_pauser_role() {
+    let (vm, mut token) = deploy();
+    ok(token.grant_role(PAUSER_ROLE, addr(2)));
+    ok(token.add_guardian(addr(3)));
+    ok(token.set_role_paused(PAUSER_ROLE, true));
+
+    vm.set_sender(addr(2));
+    match token.set_allowances_frozen(true) {
+        Err(ERC20Error::RolePaused(e)) => assert_eq!(e.role, PAUSER_ROLE),
+        _ => panic!("expected RolePaused"),
+    }
+
+    // Guardians and the owner are unaffected by the role pause
+    vm.set_sender(addr(3));
+    ok(token.set_allowances_frozen(true));
+    vm.set_sender(addr(1));
+    ok(token.set_allowances_frozen(false));
+}
+
+// ============================================================================
+// PER-ACCOUNT OUTFLOW LIMIT TESTS
+// ============================================================================
+
+fn outflow_limited_token(limit: u64) -> (TestVM, ERC20Token) {
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(2), 5_000);
+    ok(token.set_account_outflow_limit(addr(2), U256::from(limit), U256::from(86_400u64)));
+    vm.set_sender(addr(2));
+    (vm, token)
+}
+
+#[test]
+fn test_outflow_within_limit() {
+    let (_vm, mut token) = outflow_limited_token(1_000);
+    ok(token.transfer(addr(3), U256::from(600u64)));
+    ok(token.transfer(addr(3), U256::from(400u64)));
+
+    assert_eq!(ok(token.balance_of(addr(3))), U256::from(1_000u64));
+}
+
+#[test]
+fn test_outflow_over_limit_reverts() {
+    let (_vm, mut token) = outflow_limited_token(1_000);
+    ok(token.transfer(addr(3), U256::from(600u64)));
+
+    match token.transfer(addr(3), U256::from(401u64)) {
+        Err(ERC20Error::AccountOutflowExceeded(e)) => {
+            assert_eq!(e.account, addr(2));
+            assert_eq!(e.used, U256::from(1_001u64));
+            assert_eq!(e.limit, U256::from(1_000u64));
+        }
+        _ => panic!("expected AccountOutflowExceeded"),
+    }
+}
+
+#[test]
+fn test_can_transfer_reports_outflow_exceeded() {
+    use stylus_erc20::{TRANSFER_REASON_OK, TRANSFER_REASON_OUTFLOW_EXCEEDED};
+
+    let (_vm, mut token) = outflow_limited_token(1_000);
+    ok(token.transfer(addr(3), U256::from(600u64)));
+
+    assert_eq!(ok(token.can_transfer(addr(2), addr(3), U256::from(400u64))), (true, TRANSFER_REASON_OK));
+    assert_eq!(
+        ok(token.can_transfer(addr(2), addr(3), U256::from(401u64))),
+        (false, TRANSFER_REASON_OUTFLOW_EXCEEDED)
+    );
+    // Self-transfers never consume the outflow window
+    assert_eq!(ok(token.can_transfer(addr(2), addr(2), U256::from(401u64))), (true, TRANSFER_REASON_OK));
+}
+
+#[test]
+fn test_outflow_window_resets() {
+    let (vm, mut token) = outflow_limited_token(1_000);
+    ok(token.transfer(addr(3), U256::from(1_000u64)));
+    vm.set_block_timestamp(1_000 + 86_399);
+    assert!(matches!(
+        token.transfer(addr(3), U256::from(1u64)),
+        Err(ERC20Error::AccountOutflowExceeded(_))
+    ));
+
+    // A full day after the window started, usage resets
+    vm.set_block_timestamp(1_000 + 86_400);
+    ok(token.transfer(addr(3), U256::from(1_000u64)));
+}
+
+#[test]
+fn test_outflow_zero_limit_unlimited() {
+    let (_vm, mut token) = outflow_limited_token(0);
+    ok(token.transfer(addr(3), U256::from(5_000u64)));
+}
+
+// ============================================================================
+// SNAPSHOT METADATA TESTS
+// ============================================================================
+
+#[test]
+fn test_get_snapshot_lifecycle() {
+    let (_vm, mut token) = deploy();
+    let id = ok(token.snapshot());
+    assert_eq!(id, U256::from(1u64)); // Snapshot ids start at 1
+
+    assert_eq!(ok(token.get_snapshot(id)), (U256::from(1_000u64), U256::from(SUPPLY), false));
+
+    ok(token.finalize_snapshot());
+    assert_eq!(ok(token.get_snapshot(id)), (U256::from(1_000u64), U256::from(SUPPLY), true));
+}
+
+#[test]
+fn test_get_snapshot_never_started() {
+    let (_vm, token) = deploy();
+    assert!(matches!(token.get_snapshot(U256::ZERO), Err(ERC20Error::SnapshotNotFound(_))));
+    assert!(matches!(token.get_snapshot(U256::from(7u64)), Err(ERC20Error::SnapshotNotFound(_))));
+}
+
+// ============================================================================
+// TRANSFER DENY LIST TESTS
+// ============================================================================
+
+#[test]
+fn test_transfer_to_denied_address_reverts() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_transfer_deny(CONTRACT, true));
+
+    match token.transfer(CONTRACT, U256::from(100u64)) {
+        Err(ERC20Error::TransferDenied(e)) => assert_eq!(e.account, CONTRACT),
+        _ => panic!("expected TransferDenied"),
+    }
+    ok(token.transfer(addr(2), U256::from(100u64)));
+}
+
+#[test]
+fn test_transfer_deny_distinct_from_blacklist() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_blacklist_enabled(true));
+    ok(token.set_transfer_deny(addr(5), true));
+    ok(token.blacklist(addr(6)));
+
+    assert!(ok(token.is_transfer_denied(addr(5))));
+    assert!(!ok(token.is_transfer_denied(addr(6))));
+    assert!(matches!(token.transfer(addr(5), U256::from(1u64)), Err(ERC20Error::TransferDenied(_))));
+    assert!(matches!(token.transfer(addr(6), U256::from(1u64)), Err(ERC20Error::AddressBlacklisted(_))));
+}
+
+#[test]
+fn test_transfer_deny_reason_code() {
+    use stylus_erc20::TRANSFER_REASON_RECIPIENT_DENIED;
+
+    let (_vm, mut token) = deploy();
+    ok(token.set_transfer_deny(addr(5), true));
+
+    assert_eq!(
+        ok(token.can_transfer(addr(1), addr(5), U256::from(1u64))),
+        (false, TRANSFER_REASON_RECIPIENT_DENIED)
+    );
+}
+
+// ============================================================================
+// INFINITE ALLOWANCE TESTS
+// ============================================================================
+
+#[test]
+fn test_approve_max_is_infinite() {
+    let (vm, mut token) = deploy();
+    ok(token.approve_max(addr(2)));
+    assert!(ok(token.is_infinite_allowance(addr(1), addr(2))));
+
+    // Spending does not erode an infinite allowance
+    vm.set_sender(addr(2));
+    ok(token.transfer_from(addr(1), addr(3), U256::from(1_000u64)));
+    ok(token.transfer_from(addr(1), addr(3), U256::from(1_000u64)));
+
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::MAX);
+    assert_eq!(ok(token.balance_of(addr(3))), U256::from(2_000u64));
+}
+
+#[test]
+fn test_finite_allowance_not_infinite() {
+    let (vm, mut token) = deploy();
+    ok(token.approve(addr(2), U256::MAX - U256::from(1u64)));
+    assert!(!ok(token.is_infinite_allowance(addr(1), addr(2))));
+
+    vm.set_sender(addr(2));
+    ok(token.transfer_from(addr(1), addr(3), U256::from(1u64)));
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::MAX - U256::from(2u64));
+}
+
+// ============================================================================
+// MINTABLE HEADROOM TESTS
+// ============================================================================
+
+fn capped_token() -> (TestVM, ERC20Token) {
+    let (vm, mut token) = deploy();
+    ok(token.set_supply_cap_enabled(true));
+    ok(token.set_supply_cap(U256::from(SUPPLY + 1_000)));
+    (vm, token)
+}
+
+#[test]
+fn test_mintable_headroom_with_cap() {
+    let (_vm, mut token) = capped_token();
+    assert_eq!(ok(token.mintable_headroom()), U256::from(1_000u64));
+
+    ok(token.mint(addr(2), U256::from(400u64)));
+    assert_eq!(ok(token.mintable_headroom()), U256::from(600u64));
+
+    ok(token.mint(addr(2), U256::from(600u64)));
+    assert_eq!(ok(token.mintable_headroom()), U256::ZERO);
+}
+
+#[test]
+fn test_mintable_headroom_saturates() {
+    let (_vm, mut token) = capped_token();
+
+    // Supply pushed above the cap while it was disabled reports zero
+    ok(token.set_supply_cap_enabled(false));
+    ok(token.mint(addr(2), U256::from(1_500u64)));
+    ok(token.set_supply_cap_enabled(true));
+
+    assert_eq!(ok(token.mintable_headroom()), U256::ZERO);
+}
+
+#[test]
+fn test_mintable_headroom_cap_disabled() {
+    let (_vm, mut token) = capped_token();
+    ok(token.set_supply_cap_enabled(false));
+
+    assert_eq!(ok(token.mintable_headroom()), U256::MAX);
+}
+
+// ============================================================================
+// ROLE EXPIRY TESTS
+// ============================================================================
+
+fn expiring_minter() -> (TestVM, ERC20Token) {
+    let (vm, mut token) = deploy();
+    ok(token.grant_role_with_expiry(MINTER_ROLE, addr(2), U256::from(2_000u64)));
+    (vm, token)
+}
+
+#[test]
+fn test_role_usable_before_expiry() {
+    let (vm, mut token) = expiring_minter();
+    assert_eq!(ok(token.role_expiry(MINTER_ROLE, addr(2))), U256::from(2_000u64));
+
+    vm.set_sender(addr(2));
+    vm.set_block_timestamp(1_500);
+    ok(token.mint(addr(2), U256::from(1u64)));
+    vm.set_block_timestamp(2_000);
+    assert!(ok(token.has_role(MINTER_ROLE, addr(2))));
+    ok(token.mint(addr(2), U256::from(1u64)));
+}
+
+#[test]
+fn test_role_denied_after_expiry() {
+    let (vm, mut token) = expiring_minter();
+    vm.set_block_timestamp(2_001);
+    assert!(!ok(token.has_role(MINTER_ROLE, addr(2))));
+
+    vm.set_sender(addr(2));
+    assert!(matches!(token.mint(addr(2), U256::from(1u64)), Err(ERC20Error::AccessDenied(_))));
+}
+
+#[test]
+fn test_expiry_must_be_in_future() {
+    let (_vm, mut token) = deploy();
+    assert!(matches!(
+        token.grant_role_with_expiry(MINTER_ROLE, addr(2), U256::from(1_000u64)),
+        Err(ERC20Error::InvalidExpiry(_))
+    ));
+}
+
+#[test]
+fn test_permanent_role_never_expires() {
+    let (vm, mut token) = deploy();
+    ok(token.grant_role(MINTER_ROLE, addr(2)));
+    assert_eq!(ok(token.role_expiry(MINTER_ROLE, addr(2))), U256::ZERO);
+
+    vm.set_block_timestamp(u64::MAX);
+    assert!(ok(token.has_role(MINTER_ROLE, addr(2))));
+}
+
+#[test]
+fn test_purge_expired_role() {
+    let (vm, mut token) = expiring_minter();
+    vm.set_sender(addr(9)); // Callable by anyone
+    vm.set_block_timestamp(2_000);
+    assert!(matches!(
+        token.purge_expired_role(MINTER_ROLE, addr(2)),
+        Err(ERC20Error::RoleNotExpired(_))
+    ));
+
+    vm.set_block_timestamp(2_001);
+    ok(token.purge_expired_role(MINTER_ROLE, addr(2)));
+    assert_eq!(ok(token.role_expiry(MINTER_ROLE, addr(2))), U256::ZERO);
+    assert!(matches!(
+        token.purge_expired_role(MINTER_ROLE, addr(2)),
+        Err(ERC20Error::RoleNotExpired(_))
+    ));
+}
+
+// ============================================================================
+// BATCH VIEW TESTS
+// ============================================================================
+
+#[test]
+fn test_balances_of_matches_individual_lookups() {
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(2), 10);
+    fund(&vm, &mut token, addr(4), 30);
+    let accounts = vec![addr(2), addr(3), addr(4)];
+
+    let batch = ok(token.balances_of(accounts.clone()));
+
+    assert_eq!(batch, vec![U256::from(10u64), U256::ZERO, U256::from(30u64)]);
+    for (i, account) in accounts.iter().enumerate() {
+        assert_eq!(batch[i], ok(token.balance_of(*account)));
+    }
+}
+
+#[test]
+fn test_allowances_for_preserves_order() {
+    let (_vm, mut token) = deploy();
+    ok(token.approve(addr(5), U256::from(100u64)));
+    ok(token.approve(addr(6), U256::from(200u64)));
+
+    // Duplicate spenders are answered each time
+    assert_eq!(
+        ok(token.allowances_for(addr(1), vec![addr(5), addr(6), addr(5)])),
+        vec![U256::from(100u64), U256::from(200u64), U256::from(100u64)]
+    );
+}
+
+#[test]
+fn test_batch_views_empty_input() {
+    let (_vm, token) = deploy();
+    assert!(ok(token.balances_of(vec![])).is_empty());
+    assert!(ok(token.allowances_for(addr(1), vec![])).is_empty());
+}
+
+// ============================================================================
+// INITIALIZATION GUARD TESTS
+// ============================================================================
+
+#[test]
+fn test_mutations_before_initialize_revert() {
+    let (_vm, mut token) = deploy_uninitialized();
+
+    assert!(matches!(token.transfer(addr(2), U256::from(1u64)), Err(ERC20Error::NotInitialized(_))));
+    assert!(matches!(token.mint(addr(2), U256::from(1u64)), Err(ERC20Error::NotInitialized(_))));
+    assert!(matches!(token.pause(), Err(ERC20Error::NotInitialized(_))));
+
+    // No state was touched
+    assert_eq!(ok(token.total_supply()), U256::ZERO);
+    assert!(!ok(token.paused()));
+}
+
+#[test]
+fn test_mutations_after_initialize_succeed() {
+    let (_vm, mut token) = deploy();
+
+    ok(token.transfer(addr(2), U256::from(1u64)));
+    ok(token.mint(addr(2), U256::from(5u64)));
+    ok(token.pause());
+    assert_eq!(ok(token.total_supply()), U256::from(SUPPLY + 5));
+}
+
+// ============================================================================
+// FEE ROUNDING TESTS
+// ============================================================================
+
+/// Deploys a token charging `bps` to addr(7), returning the fee charged on a transfer of `amount`
+fn charged_fee(bps: u64, round_up: bool, amount: u64) -> U256 {
+    let (_vm, mut token) = deploy();
+    ok(token.set_transfer_fee(U256::from(bps), addr(7)));
+    ok(token.set_fee_rounding(round_up));
+
+    ok(token.transfer(addr(2), U256::from(amount)));
+
+    // Sender debited `amount`; recipient and fee custody together receive `amount`
+    let net = ok(token.balance_of(addr(2)));
+    let fee = ok(token.claimable_fees(addr(7)));
+    assert_eq!(net + fee, U256::from(amount));
+    fee
+}
+
+#[test]
+fn test_fee_round_down_dust_is_free() {
+    // 30 bps of 100 = 0.3
+    assert_eq!(charged_fee(30, false, 100), U256::ZERO);
+    // 30 bps of 1_050 = 3.15
+    assert_eq!(charged_fee(30, false, 1_050), U256::from(3u64));
+}
+
+#[test]
+fn test_fee_round_up_never_zero_on_nonzero_transfer() {
+    assert_eq!(charged_fee(30, true, 100), U256::from(1u64));
+    assert_eq!(charged_fee(1, true, 1), U256::from(1u64));
+    assert_eq!(charged_fee(30, true, 1_050), U256::from(4u64));
+}
+
+#[test]
+fn test_fee_exact_division_same_in_both_modes() {
+    assert_eq!(charged_fee(25, false, 10_000), U256::from(25u64));
+    assert_eq!(charged_fee(25, true, 10_000), U256::from(25u64));
+}
+
+#[test]
+fn test_fee_round_up_conserves_supply() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_transfer_fee(U256::from(1_000u64), addr(7)));
+    ok(token.set_fee_rounding(true));
+
+    ok(token.transfer(addr(2), U256::from(7u64)));
+
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(6u64));
+    assert_eq!(ok(token.total_supply()), U256::from(SUPPLY));
+}
+
+#[test]
+fn test_fee_split_remainder_to_first_recipient() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_transfer_fee(U256::from(900u64), addr(7)));
+    ok(token.set_fee_splits(
+        vec![addr(7), addr(8), addr(9)],
+        vec![U256::from(300u64), U256::from(300u64), U256::from(300u64)],
+    ));
+    ok(token.set_fee_rounding(true));
+
+    ok(token.transfer(addr(2), U256::from(1u64)));
+
+    // Shares never exceed the total fee, even when each would round up to 1
+    assert_eq!(ok(token.claimable_fees(addr(7))), U256::from(1u64));
+    assert_eq!(ok(token.claimable_fees(addr(8))), U256::ZERO);
+    assert_eq!(ok(token.claimable_fees(addr(9))), U256::ZERO);
+}
+
+// ============================================================================
+// DAI-STYLE PERMIT TESTS
+// ============================================================================
+
+#[test]
+fn test_dai_permit_typehash() {
+    use stylus_erc20::DAI_PERMIT_TYPEHASH;
+
+    assert_eq!(
+        DAI_PERMIT_TYPEHASH,
+        alloy_primitives::keccak256(
+            "Permit(address holder,address spender,uint256 nonce,uint256 expiry,bool allowed)"
+        )
+    );
+}
+
+fn dai_permit_hash(holder: Address, spender: Address, nonce: u64, expiry: u64, allowed: bool) -> FixedBytes<32> {
+    use stylus_erc20::DAI_PERMIT_TYPEHASH;
+
+    alloy_primitives::keccak256(
+        (DAI_PERMIT_TYPEHASH, holder, spender, U256::from(nonce), U256::from(expiry), allowed).abi_encode(),
+    )
+}
+
+#[test]
+fn test_dai_permit_enable_then_disable() {
+    let (vm, mut token) = deploy();
+
+    let (v, r, s) = mock_signature(&vm, &token, dai_permit_hash(addr(1), addr(2), 0, 0, true), addr(1));
+    ok(token.permit_allowed(addr(1), addr(2), U256::ZERO, U256::ZERO, true, v, r, s));
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::MAX);
+
+    let (v, r, s) = mock_signature(&vm, &token, dai_permit_hash(addr(1), addr(2), 1, 2_000, false), addr(1));
+    ok(token.permit_allowed(addr(1), addr(2), U256::from(1u64), U256::from(2_000u64), false, v, r, s));
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::ZERO);
+    assert_eq!(ok(token.nonces(addr(1))), U256::from(2u64));
+}
+
+#[test]
+fn test_dai_permit_rejects_replayed_nonce() {
+    let (vm, mut token) = deploy();
+    let (v, r, s) = mock_signature(&vm, &token, dai_permit_hash(addr(1), addr(2), 0, 0, true), addr(1));
+    ok(token.permit_allowed(addr(1), addr(2), U256::ZERO, U256::ZERO, true, v, r, s));
+
+    let (v, r, s) = mock_signature(&vm, &token, dai_permit_hash(addr(1), addr(2), 0, 0, false), addr(1));
+    assert!(matches!(
+        token.permit_allowed(addr(1), addr(2), U256::ZERO, U256::ZERO, false, v, r, s),
+        Err(ERC20Error::InvalidNonce(_))
+    ));
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::MAX);
+}
+
+#[test]
+fn test_dai_permit_expiry() {
+    let (vm, mut token) = deploy();
+
+    let (v, r, s) = mock_signature(&vm, &token, dai_permit_hash(addr(1), addr(2), 0, 500, true), addr(1));
+    assert!(matches!(
+        token.permit_allowed(addr(1), addr(2), U256::ZERO, U256::from(500u64), true, v, r, s),
+        Err(ERC20Error::PermitExpired(_))
+    ));
+
+    // Signed by someone other than the holder
+    let (v, r, s) = mock_signature(&vm, &token, dai_permit_hash(addr(1), addr(2), 0, 0, true), addr(2));
+    assert!(matches!(
+        token.permit_allowed(addr(1), addr(2), U256::ZERO, U256::ZERO, true, v, r, s),
+        Err(ERC20Error::InvalidSigner(_))
+    ));
+}
+
+// ============================================================================
+// BATCH SIZE LIMIT TESTS
+// ============================================================================
+
+#[test]
+fn test_default_max_batch_size() {
+    use stylus_erc20::DEFAULT_MAX_BATCH_SIZE;
+    assert_eq!(DEFAULT_MAX_BATCH_SIZE, 256);
+}
+
+#[test]
+fn test_batch_at_limit_succeeds() {
+    let (_vm, mut token) = deploy();
+    let max = stylus_erc20::DEFAULT_MAX_BATCH_SIZE as usize;
+
+    ok(token.batch_transfer(vec![addr(2); max], vec![U256::from(1u64); max]));
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(max as u64));
+}
+
+#[test]
+fn test_batch_over_limit_reverts_before_any_transfer() {
+    let (_vm, mut token) = deploy();
+    let max = stylus_erc20::DEFAULT_MAX_BATCH_SIZE as usize;
+
+    match token.batch_transfer(vec![addr(2); max + 1], vec![U256::from(1u64); max + 1]) {
+        Err(ERC20Error::BatchTooLarge(e)) => {
+            assert_eq!(e.len, U256::from(max + 1));
+            assert_eq!(e.max, U256::from(max));
+        }
+        _ => panic!("expected BatchTooLarge"),
+    }
+    assert_eq!(ok(token.balance_of(addr(2))), U256::ZERO);
+}
+
+#[test]
+fn test_custom_max_batch_size() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_max_batch_size(U256::from(2u64)));
+
+    assert!(matches!(
+        token.batch_transfer(vec![addr(2); 3], vec![U256::from(1u64); 3]),
+        Err(ERC20Error::BatchTooLarge(_))
+    ));
+}
+
+// ============================================================================
+// MINT CAP PRE-CHECK TESTS
+// ============================================================================
+
+#[test]
+fn test_would_exceed_cap() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_supply_cap_enabled(true));
+    ok(token.set_supply_cap(U256::from(SUPPLY + 100)));
+
+    assert!(!ok(token.would_exceed_cap(U256::from(100u64))));
+    assert!(ok(token.would_exceed_cap(U256::from(101u64))));
+}
+
+#[test]
+fn test_would_exceed_cap_disabled() {
+    let (_vm, token) = deploy();
+    assert!(!ok(token.would_exceed_cap(U256::MAX)));
+}
+
+#[test]
+fn test_cap_warning_observable_without_mint() {
+    use stylus_erc20::MintExceedsCap;
+
+    let (vm, mut token) = deploy();
+    ok(token.set_supply_cap_enabled(true));
+    ok(token.set_supply_cap(U256::from(SUPPLY + 100)));
+
+    assert!(ok(token.check_mint_cap(U256::from(500u64))));
+
+    // The warning is logged while supply is untouched
+    let warnings = events::<MintExceedsCap>(&vm);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].minter, addr(1));
+    assert_eq!(warnings[0].amount, U256::from(500u64));
+    assert_eq!(warnings[0].current_supply, U256::from(SUPPLY));
+    assert_eq!(warnings[0].cap, U256::from(SUPPLY + 100));
+    assert_eq!(ok(token.total_supply()), U256::from(SUPPLY));
+
+    // A mint within the cap logs no warning
+    assert!(!ok(token.check_mint_cap(U256::from(50u64))));
+    assert_eq!(events::<MintExceedsCap>(&vm).len(), 1);
+}
+
+#[test]
+fn test_check_mint_cap_requires_minter() {
+    let (vm, mut token) = deploy();
+    vm.set_sender(addr(2));
+    assert!(matches!(token.check_mint_cap(U256::from(1u64)), Err(ERC20Error::AccessDenied(_))));
+}
+
+// ============================================================================
+// PRIVILEGED ADDRESS TESTS
+// ============================================================================
+
+/// Router addr(9) is privileged; user addr(2) faces a minimum, outflow limit, fee and
+/// burn-on-dead. Both hold 10_000 and addr(2) is left as sender
+fn policy_token() -> (TestVM, ERC20Token) {
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(2), 10_000);
+    fund(&vm, &mut token, addr(9), 10_000);
+    ok(token.set_privileged(addr(9), true));
+    ok(token.set_min_transfer_amount(U256::from(100u64), true));
+    ok(token.set_account_outflow_limit(addr(2), U256::from(1_000u64), U256::from(86_400u64)));
+    ok(token.set_account_outflow_limit(addr(9), U256::from(1_000u64), U256::from(86_400u64)));
+    ok(token.set_transfer_fee(U256::from(100u64), addr(7)));
+    ok(token.set_burn_on_dead_transfer(true));
+    vm.set_sender(addr(2));
+    (vm, token)
+}
+
+#[test]
+fn test_privileged_bypasses_minimum() {
+    let (_vm, mut token) = policy_token();
+
+    assert!(matches!(
+        token.transfer(addr(3), U256::from(10u64)),
+        Err(ERC20Error::TransferBelowMinimum(_))
+    ));
+    ok(token.transfer(addr(9), U256::from(10u64)));
+}
+
+#[test]
+fn test_privileged_bypasses_fee() {
+    let (vm, mut token) = policy_token();
+
+    ok(token.transfer(addr(3), U256::from(500u64)));
+    assert_eq!(ok(token.balance_of(addr(3))), U256::from(495u64));
+
+    vm.set_sender(addr(9));
+    ok(token.transfer(addr(4), U256::from(500u64)));
+    assert_eq!(ok(token.balance_of(addr(4))), U256::from(500u64));
+}
+
+#[test]
+fn test_privileged_bypasses_outflow_limit() {
+    let (vm, mut token) = policy_token();
+
+    assert!(matches!(
+        token.transfer(addr(3), U256::from(5_000u64)),
+        Err(ERC20Error::AccountOutflowExceeded(_))
+    ));
+
+    vm.set_sender(addr(9));
+    ok(token.transfer(addr(3), U256::from(5_000u64)));
+}
+
+#[test]
+fn test_privileged_bypasses_burn_on_dead_transfer() {
+    use stylus_erc20::DEAD_ADDRESS;
+
+    let (vm, mut token) = policy_token();
+
+    ok(token.transfer(DEAD_ADDRESS, U256::from(200u64)));
+    assert_eq!(ok(token.total_supply()), U256::from(SUPPLY - 200));
+    assert_eq!(ok(token.balance_of(DEAD_ADDRESS)), U256::ZERO);
+
+    vm.set_sender(addr(9));
+    ok(token.transfer(DEAD_ADDRESS, U256::from(200u64)));
+    assert_eq!(ok(token.balance_of(DEAD_ADDRESS)), U256::from(200u64));
+}
+
+#[test]
+fn test_privileged_still_blacklisted() {
+    let (vm, mut token) = policy_token();
+    vm.set_sender(addr(1));
+    ok(token.set_blacklist_enabled(true));
+    ok(token.blacklist(addr(9)));
+
+    vm.set_sender(addr(9));
+    assert!(matches!(
+        token.transfer(addr(2), U256::from(500u64)),
+        Err(ERC20Error::AddressBlacklisted(_))
+    ));
+}
+
+// ============================================================================
+// DEFAULT ADMIN OWNERSHIP COUPLING TESTS
+// ============================================================================
+
+#[test]
+fn test_initial_owner_holds_default_admin() {
+    let (_vm, token) = deploy();
+    assert_eq!(DEFAULT_ADMIN_ROLE, FixedBytes::<32>::ZERO);
+    assert!(ok(token.has_role(DEFAULT_ADMIN_ROLE, addr(1))));
+}
+
+#[test]
+fn test_default_admin_moves_with_instant_transfer() {
+    let (_vm, mut token) = deploy();
+    ok(token.transfer_ownership(addr(2)));
+
+    assert_eq!(ok(token.owner()), addr(2));
+    assert!(ok(token.has_role(DEFAULT_ADMIN_ROLE, addr(2))));
+    assert!(!ok(token.has_role(DEFAULT_ADMIN_ROLE, addr(1))));
+}
+
+#[test]
+fn test_default_admin_moves_with_timelocked_transfer() {
+    let (vm, mut token) = deploy();
+    ok(token.initiate_ownership_transfer(addr(2)));
+
+    vm.set_sender(addr(2));
+    vm.set_block_timestamp(1_000 + 48 * 3_600);
+    ok(token.accept_ownership());
+
+    assert_eq!(ok(token.owner()), addr(2));
+    assert!(ok(token.has_role(DEFAULT_ADMIN_ROLE, addr(2))));
+    assert!(!ok(token.has_role(DEFAULT_ADMIN_ROLE, addr(1))));
+}
+
+#[test]
+fn test_renounce_drops_default_admin() {
+    let (_vm, mut token) = deploy();
+    ok(token.renounce_ownership());
+
+    assert_eq!(ok(token.owner()), Address::ZERO);
+    assert!(!ok(token.has_role(DEFAULT_ADMIN_ROLE, addr(1))));
+}
+
+// ============================================================================
+// CAN MINT TESTS
+// ============================================================================
+
+/// Minter addr(2) with a 500-per-day rate limit and 1_000 of cap headroom
+fn mint_gate_token() -> (TestVM, ERC20Token) {
+    let (vm, mut token) = deploy();
+    ok(token.grant_role(MINTER_ROLE, addr(2)));
+    ok(token.set_minting_limits(U256::from(500u64), U256::from(86_400u64)));
+    ok(token.set_supply_cap_enabled(true));
+    ok(token.set_supply_cap(U256::from(SUPPLY + 1_000)));
+    (vm, token)
+}
+
+#[test]
+fn test_can_mint_success() {
+    use stylus_erc20::MINT_REASON_OK;
+
+    let (vm, mut token) = mint_gate_token();
+    assert_eq!(ok(token.can_mint(addr(2), U256::from(500u64))), (true, MINT_REASON_OK));
+
+    // The view does not consume the budget
+    vm.set_sender(addr(2));
+    ok(token.mint(addr(2), U256::from(500u64)));
+}
+
+#[test]
+fn test_can_mint_no_role() {
+    use stylus_erc20::MINT_REASON_NO_ROLE;
+
+    let (_vm, token) = mint_gate_token();
+    assert_eq!(ok(token.can_mint(addr(3), U256::from(1u64))), (false, MINT_REASON_NO_ROLE));
+}
+
+#[test]
+fn test_can_mint_paused() {
+    use stylus_erc20::MINT_REASON_PAUSED;
+
+    let (_vm, mut token) = mint_gate_token();
+    ok(token.pause());
+    assert_eq!(ok(token.can_mint(addr(2), U256::from(1u64))), (false, MINT_REASON_PAUSED));
+}
+
+#[test]
+fn test_can_mint_rate_limited() {
+    use stylus_erc20::MINT_REASON_RATE_LIMITED;
+
+    let (_vm, token) = mint_gate_token();
+    assert_eq!(ok(token.can_mint(addr(2), U256::from(501u64))), (false, MINT_REASON_RATE_LIMITED));
+}
+
+#[test]
+fn test_can_mint_cap_exceeded() {
+    use stylus_erc20::MINT_REASON_CAP_EXCEEDED;
+
+    let (_vm, mut token) = mint_gate_token();
+    ok(token.set_supply_cap(U256::from(SUPPLY + 100)));
+    assert_eq!(ok(token.can_mint(addr(2), U256::from(101u64))), (false, MINT_REASON_CAP_EXCEEDED));
+}
+
+// ============================================================================
+// DEFAULT ROLE ADMIN FALLBACK TESTS
+// ============================================================================
+
+#[test]
+fn test_unconfigured_role_managed_by_owner() {
+    let (vm, mut token) = deploy();
+    let oracle_role = alloy_primitives::keccak256("ORACLE_ROLE");
+    assert_eq!(ok(token.get_role_admin(oracle_role)), DEFAULT_ADMIN_ROLE);
+
+    // The owner manages it even without holding DEFAULT_ADMIN_ROLE
+    ok(token.renounce_role(DEFAULT_ADMIN_ROLE));
+    ok(token.grant_role(oracle_role, addr(3)));
+    assert!(ok(token.has_role(oracle_role, addr(3))));
+
+    vm.set_sender(addr(2));
+    assert!(matches!(token.grant_role(oracle_role, addr(4)), Err(ERC20Error::AccessDenied(_))));
+}
+
+#[test]
+fn test_unconfigured_role_managed_by_default_admin() {
+    let (vm, mut token) = deploy();
+    let oracle_role = alloy_primitives::keccak256("ORACLE_ROLE");
+    ok(token.grant_role(DEFAULT_ADMIN_ROLE, addr(2)));
+
+    vm.set_sender(addr(2));
+    ok(token.grant_role(oracle_role, addr(3)));
+    assert!(ok(token.has_role(oracle_role, addr(3))));
+}
+
+#[test]
+fn test_configured_role_requires_its_admin() {
+    let (vm, mut token) = deploy();
+    let oracle_role = alloy_primitives::keccak256("ORACLE_ROLE");
+    ok(token.set_role_admin(oracle_role, ADMIN_ROLE));
+    ok(token.grant_role(ADMIN_ROLE, addr(2)));
+
+    // Owner fallback only applies to DEFAULT_ADMIN_ROLE-administered roles
+    ok(token.renounce_role(ADMIN_ROLE));
+    assert!(matches!(token.grant_role(oracle_role, addr(3)), Err(ERC20Error::AccessDenied(_))));
+
+    vm.set_sender(addr(2));
+    ok(token.grant_role(oracle_role, addr(3)));
+}
+
+// ============================================================================
+// PAST TOTAL SUPPLY TESTS
+// ============================================================================
+
+#[test]
+fn test_past_total_supply_across_blocks() {
+    let (vm, mut token) = deploy(); // SUPPLY minted at block 1
+    vm.set_block_number(10);
+    ok(token.mint(addr(1), U256::from(1_000u64)));
+    vm.set_block_number(20);
+    ok(token.mint(addr(1), U256::from(500u64)));
+    vm.set_block_number(30);
+    ok(token.burn(U256::from(200u64)));
+    vm.set_block_number(40);
+
+    assert_eq!(ok(token.get_past_total_supply(U256::ZERO)), U256::ZERO);
+    assert_eq!(ok(token.get_past_total_supply(U256::from(5u64))), U256::from(SUPPLY));
+    assert_eq!(ok(token.get_past_total_supply(U256::from(10u64))), U256::from(SUPPLY + 1_000));
+    assert_eq!(ok(token.get_past_total_supply(U256::from(19u64))), U256::from(SUPPLY + 1_000));
+    assert_eq!(ok(token.get_past_total_supply(U256::from(20u64))), U256::from(SUPPLY + 1_500));
+    assert_eq!(ok(token.get_past_total_supply(U256::from(35u64))), U256::from(SUPPLY + 1_300));
+}
+
+#[test]
+fn test_past_total_supply_same_block_updates_collapse() {
+    let (vm, mut token) = deploy();
+    vm.set_block_number(10);
+    ok(token.mint(addr(1), U256::from(100u64)));
+    ok(token.mint(addr(1), U256::from(50u64)));
+    ok(token.burn(U256::from(30u64)));
+    vm.set_block_number(11);
+
+    assert_eq!(ok(token.get_past_total_supply(U256::from(9u64))), U256::from(SUPPLY));
+    assert_eq!(ok(token.get_past_total_supply(U256::from(10u64))), U256::from(SUPPLY + 120));
+}
+
+#[test]
+fn test_past_total_supply_rejects_current_block() {
+    let (vm, mut token) = deploy();
+    vm.set_block_number(10);
+    ok(token.mint(addr(1), U256::from(100u64)));
+
+    assert!(matches!(token.get_past_total_supply(U256::from(10u64)), Err(ERC20Error::FutureLookup(_))));
+    assert!(matches!(token.get_past_total_supply(U256::from(11u64)), Err(ERC20Error::FutureLookup(_))));
+}
+
+// ============================================================================
+// FEE PULL PAYMENT TESTS
+// ============================================================================
+
+fn fee_token() -> (TestVM, ERC20Token) {
+    let (vm, mut token) = deploy();
+    ok(token.set_transfer_fee(U256::from(100u64), addr(7)));
+    (vm, token)
+}
+
+#[test]
+fn test_fees_accumulate_across_transfers() {
+    let (_vm, mut token) = fee_token();
+
+    for _ in 0..5 {
+        ok(token.transfer(addr(2), U256::from(300u64)));
+    }
+
+    assert_eq!(ok(token.claimable_fees(addr(7))), U256::from(15u64));
+    assert_eq!(ok(token.balance_of(CONTRACT)), U256::from(15u64));
+    // Recipient balance untouched until claimed
+    assert_eq!(ok(token.balance_of(addr(7))), U256::ZERO);
+}
+
+#[test]
+fn test_claim_fees_in_one_call() {
+    let (vm, mut token) = fee_token();
+    ok(token.transfer(addr(2), U256::from(1_000u64)));
+    ok(token.transfer(addr(2), U256::from(2_000u64)));
+
+    vm.set_sender(addr(7));
+    assert_eq!(ok(token.claim_fees()), U256::from(30u64));
+    assert_eq!(ok(token.balance_of(addr(7))), U256::from(30u64));
+    assert_eq!(ok(token.claimable_fees(addr(7))), U256::ZERO);
+    assert_eq!(ok(token.balance_of(CONTRACT)), U256::ZERO);
+
+    assert!(matches!(token.claim_fees(), Err(ERC20Error::NothingToWithdraw(_))));
+}
+
+#[test]
+fn test_fee_custody_not_sweepable() {
+    let (_vm, mut token) = fee_token();
+    ok(token.transfer(addr(2), U256::from(5_000u64))); // Accrues a 50 fee
+    ok(token.transfer(CONTRACT, U256::from(5u64))); // Stray tokens, not charged a fee
+
+    assert!(matches!(
+        token.sweep_self_tokens(addr(8), U256::from(6u64)),
+        Err(ERC20Error::InsufficientBalance(_))
+    ));
+    ok(token.sweep_self_tokens(addr(8), U256::from(5u64)));
+}
+
+// ============================================================================
+// INIT SUMMARY TESTS
+// ============================================================================
+
+#[test]
+fn test_init_summary_matches_inputs() {
+    let (_vm, token) = deploy();
+    assert_eq!(
+        ok(token.init_summary()),
+        (addr(1), U256::from(SUPPLY), 18, U256::from(1_000u64))
+    );
+}
+
+#[test]
+fn test_init_summary_zero_supply() {
+    let (vm, mut token) = deploy_uninitialized();
+    vm.set_block_timestamp(1_700_000_000);
+    ok(token.initialize("Test Token".into(), "TST".into(), 6, U256::ZERO, addr(2)));
+
+    assert_eq!(
+        ok(token.init_summary()),
+        (addr(2), U256::ZERO, 6, U256::from(1_700_000_000u64))
+    );
+}
+
+#[test]
+fn test_init_summary_ignores_display_decimals() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_display_decimals(6));
+
+    let (_, _, decimals, _) = ok(token.init_summary());
+    assert_eq!(decimals, 18);
+}
+
+// ============================================================================
+// DECOMMISSION TESTS
+// ============================================================================
+
+#[test]
+fn test_decommission_requires_renounced_minting() {
+    let (_vm, mut token) = deploy();
+    assert!(matches!(token.decommission(), Err(ERC20Error::MintingNotRenounced(_))));
+}
+
+#[test]
+fn test_decommissioned_only_burns() {
+    let (_vm, mut token) = deploy();
+    ok(token.renounce_minting());
+    ok(token.decommission());
+
+    assert!(matches!(token.mint(addr(1), U256::from(1u64)), Err(ERC20Error::TokenDecommissioned(_))));
+    assert!(matches!(token.transfer(addr(2), U256::from(1u64)), Err(ERC20Error::TokenDecommissioned(_))));
+    assert!(matches!(token.transfer(addr(2), U256::ZERO), Err(ERC20Error::TokenDecommissioned(_))));
+    assert!(matches!(
+        token.transfer_from(addr(1), addr(2), U256::ZERO),
+        Err(ERC20Error::TokenDecommissioned(_))
+    ));
+
+    ok(token.burn(U256::from(40u64)));
+    assert_eq!(ok(token.total_supply()), U256::from(SUPPLY - 40));
+}
+
+#[test]
+fn test_decommission_irreversible() {
+    let (_vm, mut token) = deploy();
+    ok(token.renounce_minting());
+    ok(token.decommission());
+
+    // No path clears the flag; a second call reverts
+    assert!(matches!(token.decommission(), Err(ERC20Error::TokenDecommissioned(_))));
+    assert!(ok(token.decommissioned()));
+}
+
+// ============================================================================
+// APPROVE RACE DETECTION TESTS
+// ============================================================================
+
+#[test]
+fn test_approve_would_race_zero_allowance() {
+    let (_vm, token) = deploy();
+    assert!(!ok(token.approve_would_race(addr(1), addr(2))));
+}
+
+#[test]
+fn test_approve_would_race_nonzero_allowance() {
+    let (_vm, mut token) = deploy();
+    ok(token.approve(addr(2), U256::from(1u64)));
+    ok(token.approve_max(addr(3)));
+
+    assert!(ok(token.approve_would_race(addr(1), addr(2))));
+    assert!(ok(token.approve_would_race(addr(1), addr(3))));
+}
+
+#[test]
+fn test_approve_would_race_after_reset_to_zero() {
+    let (_vm, mut token) = deploy();
+    ok(token.approve(addr(2), U256::from(500u64)));
+    assert!(ok(token.approve_would_race(addr(1), addr(2))));
+
+    // Going through zero clears the race risk
+    ok(token.approve(addr(2), U256::ZERO));
+    assert!(!ok(token.approve_would_race(addr(1), addr(2))));
+}
+
+#[test]
+fn test_approve_would_race_ignores_expired_allowance() {
+    let (vm, mut token) = deploy();
+    ok(token.approve_with_expiry(addr(2), U256::from(500u64), U256::from(2_000u64)));
+    assert!(ok(token.approve_would_race(addr(1), addr(2))));
+
+    vm.set_block_timestamp(2_000);
+    assert!(!ok(token.approve_would_race(addr(1), addr(2))));
+}
+
+// ============================================================================
+// CONFIG CHANGED EVENT TESTS
+// ============================================================================
+
+#[test]
+fn test_config_keys_match_keccak() {
+    use stylus_erc20::{
+        CONFIG_BLACKLIST_ENABLED, CONFIG_FEE_BPS, CONFIG_MIN_TRANSFER_AMOUNT,
+        CONFIG_MIN_TRANSFER_ENABLED, CONFIG_SUPPLY_CAP, CONFIG_SUPPLY_CAP_ENABLED,
+        CONFIG_TRANSFER_RESTRICTIONS_ENABLED,
+    };
+
+    assert_eq!(CONFIG_SUPPLY_CAP, alloy_primitives::keccak256("SUPPLY_CAP"));
+    assert_eq!(CONFIG_SUPPLY_CAP_ENABLED, alloy_primitives::keccak256("SUPPLY_CAP_ENABLED"));
+    assert_eq!(CONFIG_BLACKLIST_ENABLED, alloy_primitives::keccak256("BLACKLIST_ENABLED"));
+    assert_eq!(
+        CONFIG_TRANSFER_RESTRICTIONS_ENABLED,
+        alloy_primitives::keccak256("TRANSFER_RESTRICTIONS_ENABLED")
+    );
+    assert_eq!(CONFIG_MIN_TRANSFER_AMOUNT, alloy_primitives::keccak256("MIN_TRANSFER_AMOUNT"));
+    assert_eq!(CONFIG_MIN_TRANSFER_ENABLED, alloy_primitives::keccak256("MIN_TRANSFER_ENABLED"));
+    assert_eq!(CONFIG_FEE_BPS, alloy_primitives::keccak256("FEE_BPS"));
+}
+
+#[test]
+fn test_config_keys_unique() {
+    use stylus_erc20::*;
+
+    let keys = [
+        CONFIG_SUPPLY_CAP,
+        CONFIG_SUPPLY_CAP_ENABLED,
+        CONFIG_BLACKLIST_ENABLED,
+        CONFIG_TRANSFER_RESTRICTIONS_ENABLED,
+        CONFIG_MIN_TRANSFER_AMOUNT,
+        CONFIG_MIN_TRANSFER_ENABLED,
+        CONFIG_FEE_BPS,
+        CONFIG_FEE_ROUND_UP,
+        CONFIG_BURN_ON_DEAD_TRANSFER,
+        CONFIG_STRICT_APPROVALS_ENABLED,
+        CONFIG_TRACK_APPROVALS_ENABLED,
+        CONFIG_SNAPSHOT_ALLOWANCES_ENABLED,
+        CONFIG_MAX_BATCH_SIZE,
+        CONFIG_ALLOW_MINT_TO_SELF,
+    ];
+    for (i, a) in keys.iter().enumerate() {
+        for b in keys.iter().skip(i + 1) {
+            assert_ne!(a, b);
+        }
+    }
+}
+
+#[test]
+fn test_toggle_emits_config_changed() {
+    use stylus_erc20::{ConfigChanged, CONFIG_BLACKLIST_ENABLED, CONFIG_SUPPLY_CAP_ENABLED};
+
+    let (vm, mut token) = deploy();
+    ok(token.set_blacklist_enabled(true));
+    ok(token.set_supply_cap_enabled(true));
+    ok(token.set_blacklist_enabled(false));
+
+    let changes: Vec<_> = events::<ConfigChanged>(&vm)
+        .into_iter()
+        .map(|e| (e.key, e.old_value, e.new_value, e.operator))
+        .collect();
+    assert_eq!(
+        changes,
+        vec![
+            (CONFIG_BLACKLIST_ENABLED, U256::ZERO, U256::from(1u64), addr(1)),
+            (CONFIG_SUPPLY_CAP_ENABLED, U256::ZERO, U256::from(1u64), addr(1)),
+            (CONFIG_BLACKLIST_ENABLED, U256::from(1u64), U256::ZERO, addr(1)),
+        ]
+    );
+}
+
+#[test]
+fn test_numeric_setter_emits_old_and_new() {
+    use stylus_erc20::{ConfigChanged, CONFIG_FEE_BPS};
+
+    let (vm, mut token) = deploy();
+    ok(token.set_transfer_fee(U256::from(25u64), addr(7)));
+    ok(token.set_transfer_fee(U256::from(50u64), addr(7)));
+
+    let changes: Vec<_> = events::<ConfigChanged>(&vm)
+        .into_iter()
+        .filter(|e| e.key == CONFIG_FEE_BPS)
+        .map(|e| (e.old_value, e.new_value))
+        .collect();
+    assert_eq!(
+        changes,
+        vec![(U256::ZERO, U256::from(25u64)), (U256::from(25u64), U256::from(50u64))]
+    );
+}
+
+// ============================================================================
+// HANDOVER TESTS
+// ============================================================================
+
+const CORE_ROLES: [FixedBytes<32>; 4] = [ADMIN_ROLE, MINTER_ROLE, PAUSER_ROLE, COMPLIANCE_ROLE];
+
+#[test]
+fn test_handover_moves_all_roles() {
+    let (vm, mut token) = deploy();
+    ok(token.handover(addr(2)));
+    assert!(ok(token.pending_handover()));
+
+    vm.set_sender(addr(2));
+    vm.set_block_timestamp(1_000 + 48 * 60 * 60);
+    ok(token.accept_ownership());
+
+    assert_eq!(ok(token.owner()), addr(2));
+    assert!(!ok(token.pending_handover()));
+    assert!(ok(token.has_role(DEFAULT_ADMIN_ROLE, addr(2))));
+    assert!(!ok(token.has_role(DEFAULT_ADMIN_ROLE, addr(1))));
+    for role in CORE_ROLES {
+        assert!(ok(token.has_role(role, addr(2))));
+        assert!(!ok(token.has_role(role, addr(1))));
+    }
+}
+
+#[test]
+fn test_handover_respects_timelock() {
+    let (vm, mut token) = deploy();
+    ok(token.set_ownership_transfer_delay(U256::from(100u64)));
+    ok(token.handover(addr(2)));
+
+    vm.set_sender(addr(2));
+    vm.set_block_timestamp(1_050);
+    assert!(matches!(
+        token.accept_ownership(),
+        Err(ERC20Error::OwnershipTransferNotYetUnlockable(_))
+    ));
+
+    // Nothing moved before acceptance
+    assert_eq!(ok(token.owner()), addr(1));
+    assert!(ok(token.has_role(MINTER_ROLE, addr(1))));
+    assert!(!ok(token.has_role(MINTER_ROLE, addr(2))));
+}
+
+#[test]
+fn test_plain_transfer_keeps_core_roles() {
+    let (vm, mut token) = deploy();
+    ok(token.initiate_ownership_transfer(addr(2)));
+
+    vm.set_sender(addr(2));
+    vm.set_block_timestamp(1_000 + 48 * 60 * 60);
+    ok(token.accept_ownership());
+
+    // Only DEFAULT_ADMIN_ROLE follows ownership outside a handover
+    assert!(ok(token.has_role(MINTER_ROLE, addr(1))));
+    assert!(!ok(token.has_role(MINTER_ROLE, addr(2))));
+}
+
+// ============================================================================
+// RESUMABLE BATCH TRANSFER TESTS
+// ============================================================================
+
+fn numbered_recipients(count: u8) -> (Vec<Address>, Vec<U256>) {
+    let recipients = (0..count).map(|i| addr(10 + i)).collect();
+    let amounts = (1..=count as u64).map(U256::from).collect();
+    (recipients, amounts)
+}
+
+#[test]
+fn test_resumable_batch_across_two_calls() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_max_batch_size(U256::from(6u64)));
+    let (recipients, amounts) = numbered_recipients(10);
+
+    let next = ok(token.batch_transfer_resumable(recipients.clone(), amounts.clone(), U256::ZERO));
+    assert_eq!(next, U256::from(6u64));
+    // Completed transfers are kept
+    assert_eq!(ok(token.balance_of(recipients[5])), U256::from(6u64));
+    assert_eq!(ok(token.balance_of(recipients[6])), U256::ZERO);
+
+    let next = ok(token.batch_transfer_resumable(recipients.clone(), amounts.clone(), next));
+    assert_eq!(next, U256::from(10u64));
+    assert_eq!(ok(token.balances_of(recipients)), amounts);
+}
+
+#[test]
+fn test_resumable_batch_finished_and_out_of_bounds() {
+    let (_vm, mut token) = deploy();
+    let (recipients, amounts) = numbered_recipients(3);
+
+    assert_eq!(
+        ok(token.batch_transfer_resumable(recipients.clone(), amounts.clone(), U256::from(3u64))),
+        U256::from(3u64)
+    );
+    assert!(matches!(
+        token.batch_transfer_resumable(recipients, amounts, U256::from(4u64)),
+        Err(ERC20Error::BatchIndexOutOfBounds(_))
+    ));
+}
+
+#[test]
+fn test_resumable_batch_rejects_zero_recipient() {
+    use stylus_erc20::ZeroAddress;
+    use stylus_sdk::alloy_sol_types::SolError;
+
+    let (_vm, mut token) = deploy();
+
+    match token.batch_transfer_resumable(
+        vec![addr(2), Address::ZERO, addr(3)],
+        vec![U256::from(1u64); 3],
+        U256::ZERO,
+    ) {
+        Err(ERC20Error::BatchItemFailed(e)) => {
+            assert_eq!(e.index, U256::from(1u64));
+            assert_eq!(e.reason[..4], ZeroAddress::SELECTOR);
+        }
+        _ => panic!("expected BatchItemFailed"),
+    }
+}
+
+// ============================================================================
+// COMMITTED SUPPLY TESTS
+// ============================================================================
+
+fn vesting_committed_token() -> (TestVM, ERC20Token) {
+    let (vm, mut token) = deploy();
+    ok(token.create_vesting_schedule(addr(2), U256::from(400u64), U256::from(1_100u64), U256::from(1_000u64)));
+    (vm, token)
+}
+
+#[test]
+fn test_committed_supply_includes_vesting_liabilities() {
+    let (_vm, token) = vesting_committed_token();
+    assert_eq!(ok(token.committed_supply()), U256::from(SUPPLY + 400));
+}
+
+#[test]
+fn test_cap_below_supply_rejected() {
+    let (_vm, mut token) = vesting_committed_token();
+
+    match token.set_supply_cap(U256::from(SUPPLY - 1)) {
+        Err(ERC20Error::SupplyCapExceeded(e)) => {
+            assert_eq!(e.current_supply, U256::from(SUPPLY));
+            assert_eq!(e.cap, U256::from(SUPPLY - 1));
+        }
+        _ => panic!("expected SupplyCapExceeded"),
+    }
+}
+
+#[test]
+fn test_cap_below_committed_with_vesting_rejected() {
+    let (_vm, mut token) = vesting_committed_token();
+
+    // Unreleased vesting stays committed on top of total supply
+    match token.set_supply_cap(U256::from(SUPPLY + 399)) {
+        Err(ERC20Error::CannotSetCapBelowCommitted(e)) => {
+            assert_eq!(e.cap, U256::from(SUPPLY + 399));
+            assert_eq!(e.committed, U256::from(SUPPLY + 400));
+        }
+        _ => panic!("expected CannotSetCapBelowCommitted"),
+    }
+}
+
+#[test]
+fn test_cap_at_committed_accepted() {
+    let (_vm, mut token) = vesting_committed_token();
+
+    ok(token.set_supply_cap(U256::from(SUPPLY + 400)));
+    assert_eq!(ok(token.supply_cap()), U256::from(SUPPLY + 400));
+    assert_eq!(ok(token.vesting_custody()), U256::from(400u64));
+}
+
+// ============================================================================
+// SUPPLY RECONCILIATION TESTS
+// ============================================================================
+
+#[test]
+fn test_full_holder_set_sums_to_supply() {
+    let (vm, mut token) = deploy_with_supply(1_000);
+    fund(&vm, &mut token, addr(2), 200);
+    fund(&vm, &mut token, addr(3), 300);
+    fund(&vm, &mut token, addr(4), 400);
+
+    let (summed, total) = ok(token.verify_supply_subset(vec![addr(1), addr(2), addr(3), addr(4)]));
+    assert_eq!(summed, total);
+    assert_eq!(total, U256::from(1_000u64));
+}
+
+#[test]
+fn test_partial_holder_set_sums_below_supply() {
+    let (vm, mut token) = deploy_with_supply(1_000);
+    fund(&vm, &mut token, addr(2), 250);
+    fund(&vm, &mut token, addr(3), 250);
+    fund(&vm, &mut token, addr(4), 250);
+
+    let (summed, total) = ok(token.verify_supply_subset(vec![addr(1), addr(2)]));
+    assert_eq!(summed, U256::from(500u64));
+    assert!(summed < total);
+}
+
+// ============================================================================
+// PER-TRANSACTION MINT CAP TESTS
+// ============================================================================
+
+fn per_tx_capped_token(max_per_tx: u64, period_limit: u64) -> (TestVM, ERC20Token) {
+    let (vm, mut token) = deploy();
+    ok(token.set_max_mint_per_tx(U256::from(max_per_tx)));
+    ok(token.set_minting_limits(U256::from(period_limit), U256::from(86_400u64)));
+    (vm, token)
+}
+
+#[test]
+fn test_mint_at_per_tx_cap_succeeds() {
+    let (_vm, mut token) = per_tx_capped_token(100, 250);
+
+    ok(token.mint(addr(2), U256::from(100u64)));
+    ok(token.mint(addr(2), U256::from(100u64)));
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(200u64));
+}
+
+#[test]
+fn test_mint_over_per_tx_cap_reverts() {
+    let (_vm, mut token) = per_tx_capped_token(100, 1_000);
+
+    match token.mint(addr(2), U256::from(101u64)) {
+        Err(ERC20Error::MintExceedsPerTxLimit(e)) => {
+            assert_eq!(e.amount, U256::from(101u64));
+            assert_eq!(e.max, U256::from(100u64));
+        }
+        _ => panic!("expected MintExceedsPerTxLimit"),
+    }
+}
+
+#[test]
+fn test_per_tx_cap_composes_with_rate_window() {
+    let (_vm, mut token) = per_tx_capped_token(100, 150);
+
+    ok(token.mint(addr(2), U256::from(100u64)));
+    // Within the per-tx cap but over the window
+    assert!(matches!(
+        token.mint(addr(2), U256::from(100u64)),
+        Err(ERC20Error::MintRateLimitExceeded(_))
+    ));
+}
+
+#[test]
+fn test_per_tx_cap_zero_is_unlimited() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_max_mint_per_tx(U256::ZERO));
+    ok(token.mint(addr(2), U256::from(1_000_000u64)));
+}
+
+// ============================================================================
+// REVOKE APPROVAL TESTS
+// ============================================================================
+
+#[test]
+fn test_revoke_nonzero_allowance() {
+    use stylus_erc20::Approval;
+
+    let (vm, mut token) = deploy();
+    ok(token.set_track_approvals_enabled(true));
+    ok(token.approve(addr(2), U256::from(500u64)));
+
+    ok(token.revoke_approval(addr(2)));
+
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::ZERO);
+    assert!(spenders(&token, addr(1)).is_empty());
+    let approval = events::<Approval>(&vm).pop().unwrap();
+    assert_eq!((approval.owner, approval.spender, approval.amount), (addr(1), addr(2), U256::ZERO));
+}
+
+#[test]
+fn test_revoke_already_zero_allowance_is_safe() {
+    use stylus_erc20::Approval;
+
+    let (vm, mut token) = deploy();
+    ok(token.set_track_approvals_enabled(true));
+
+    ok(token.revoke_approval(addr(3)));
+
+    assert_eq!(ok(token.allowance(addr(1), addr(3))), U256::ZERO);
+    assert!(spenders(&token, addr(1)).is_empty());
+    let approval = events::<Approval>(&vm).pop().unwrap();
+    assert_eq!((approval.owner, approval.spender, approval.amount), (addr(1), addr(3), U256::ZERO));
+}
+
+#[test]
+fn test_revoke_clears_expiry() {
+    let (_vm, mut token) = deploy();
+    ok(token.approve_with_expiry(addr(2), U256::from(500u64), U256::from(2_000u64)));
+
+    ok(token.revoke_approval(addr(2)));
+    assert_eq!(ok(token.allowance_expiry(addr(1), addr(2))), U256::ZERO);
+}
+
+// ============================================================================
+// FULL INITIALIZATION TESTS
+// ============================================================================
+
+#[test]
+fn test_initialize_full_guardian_can_pause_immediately() {
+    use stylus_erc20::{EmergencyAdminChanged, GuardianUpdated};
+
+    let (vm, mut token) = deploy_uninitialized();
+    ok(token.initialize_full("Test Token".into(), "TST".into(), 18, U256::from(SUPPLY), addr(1), addr(7), addr(8)));
+
+    assert_eq!(events::<GuardianUpdated>(&vm).len(), 1);
+    assert_eq!(events::<EmergencyAdminChanged>(&vm).len(), 1);
+    assert_eq!(ok(token.emergency_admin()), addr(8));
+    assert!(ok(token.is_guardian(addr(7))));
+
+    vm.set_sender(addr(7));
+    ok(token.guardian_pause());
+    assert!(ok(token.paused()));
+}
+
+#[test]
+fn test_initialize_full_zero_addresses_skip_wiring() {
+    use stylus_erc20::{EmergencyAdminChanged, GuardianUpdated};
+
+    let (vm, mut token) = deploy_uninitialized();
+    ok(token.initialize_full("Test Token".into(), "TST".into(), 18, U256::from(SUPPLY), addr(1), Address::ZERO, Address::ZERO));
+
+    assert!(events::<GuardianUpdated>(&vm).is_empty());
+    assert!(events::<EmergencyAdminChanged>(&vm).is_empty());
+    assert_eq!(ok(token.emergency_admin()), Address::ZERO);
+    assert!(matches!(
+        token.initialize_full("Test Token".into(), "TST".into(), 18, U256::ZERO, addr(1), addr(7), Address::ZERO),
+        Err(ERC20Error::AlreadyInitialized(_))
+    ));
+
+    vm.set_sender(addr(7));
+    assert!(matches!(token.guardian_pause(), Err(ERC20Error::AccessDenied(_))));
+}
+
+// ============================================================================
+// CRITICAL ADDRESS BLACKLIST GUARD TESTS
+// ============================================================================
+
+/// Owner addr(1), guardian addr(2), emergency admin addr(3), no fee recipient
+fn critical_token() -> (TestVM, ERC20Token) {
+    let (vm, mut token) = deploy_uninitialized();
+    ok(token.initialize_full("Test Token".into(), "TST".into(), 18, U256::from(SUPPLY), addr(1), addr(2), addr(3)));
+    ok(token.set_blacklist_enabled(true));
+    (vm, token)
+}
+
+#[test]
+fn test_blacklist_owner_reverts() {
+    let (_vm, mut token) = critical_token();
+
+    for account in [addr(1), addr(2), addr(3)] {
+        assert!(ok(token.is_critical_address(account)));
+        assert!(matches!(token.blacklist(account), Err(ERC20Error::CannotBlacklistCritical(_))));
+        assert!(!ok(token.is_blacklisted(account)));
+    }
+}
+
+#[test]
+fn test_blacklist_added_guardian_reverts() {
+    let (_vm, mut token) = critical_token();
+    ok(token.add_guardian(addr(7)));
+
+    assert!(ok(token.is_critical_address(addr(7))));
+    assert!(matches!(token.blacklist(addr(7)), Err(ERC20Error::CannotBlacklistCritical(_))));
+}
+
+#[test]
+fn test_blacklist_normal_address_succeeds() {
+    let (_vm, mut token) = critical_token();
+
+    ok(token.blacklist(addr(9)));
+    assert!(ok(token.is_blacklisted(addr(9))));
+}
+
+#[test]
+fn test_unset_fee_recipient_not_critical() {
+    let (_vm, mut token) = critical_token();
+    assert!(!ok(token.is_critical_address(Address::ZERO)));
+
+    ok(token.set_transfer_fee(U256::from(100u64), addr(4)));
+    assert!(ok(token.is_critical_address(addr(4))));
+}
+
+// ============================================================================
+// SNAPSHOT REWARD DISTRIBUTION TESTS
+// ============================================================================
+
+/// Finalized snapshot of addr(1..=3) holding `balances`, changed afterwards
+fn reward_snapshot(balances: [u64; 3]) -> (TestVM, ERC20Token, U256) {
+    let (vm, mut token) = deploy_with_supply(balances.iter().sum());
+    fund(&vm, &mut token, addr(2), balances[1]);
+    fund(&vm, &mut token, addr(3), balances[2]);
+    let id = ok(token.snapshot());
+    ok(token.snapshot_all(U256::ZERO, U256::from(3u64)));
+    ok(token.finalize_snapshot());
+
+    // Later movements do not change the snapshot shares
+    fund(&vm, &mut token, addr(3), balances[0]);
+    (vm, token, id)
+}
+
+#[test]
+fn test_snapshot_rewards_proportional() {
+    let (_vm, token, id) = reward_snapshot([500, 300, 200]);
+    let reward = U256::from(10_000u64);
+
+    let shares: Vec<U256> = [addr(1), addr(2), addr(3)]
+        .into_iter()
+        .map(|account| ok(token.claimable_at_snapshot(account, id, reward)))
+        .collect();
+    assert_eq!(shares, vec![U256::from(5_000u64), U256::from(3_000u64), U256::from(2_000u64)]);
+}
+
+#[test]
+fn test_snapshot_rewards_sum_to_total_minus_dust() {
+    let (_vm, token, id) = reward_snapshot([1, 1, 1]);
+    let reward = U256::from(100u64);
+
+    let paid = [addr(1), addr(2), addr(3)]
+        .into_iter()
+        .fold(U256::ZERO, |acc, account| acc + ok(token.claimable_at_snapshot(account, id, reward)));
+
+    // Rounding down leaves at most one unit of dust per holder
+    assert!(paid <= reward);
+    assert!(reward - paid < U256::from(3u64));
+}
+
+#[test]
+fn test_snapshot_rewards_empty_snapshot() {
+    let (_vm, mut token) = deploy_with_supply(0);
+    let id = ok(token.snapshot());
+    ok(token.finalize_snapshot());
+
+    assert_eq!(ok(token.claimable_at_snapshot(addr(1), id, U256::from(100u64))), U256::ZERO);
+}
+
+// ============================================================================
+// ZERO-AMOUNT TRANSFER POLICY TESTS
+// ============================================================================
+
+#[test]
+fn test_zero_transfer_succeeds_by_default() {
+    use stylus_erc20::Transfer;
+
+    let (vm, mut token) = deploy();
+    assert!(ok(token.transfer(addr(2), U256::ZERO)));
+
+    let transfer = events::<Transfer>(&vm).pop().unwrap();
+    assert_eq!((transfer.from, transfer.to, transfer.amount), (addr(1), addr(2), U256::ZERO));
+}
+
+#[test]
+fn test_zero_transfer_reverts_when_rejected() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_reject_zero_transfers(true));
+
+    assert!(matches!(token.transfer(addr(2), U256::ZERO), Err(ERC20Error::InvalidAmount(_))));
+}
+
+#[test]
+fn test_zero_amount_reason_code() {
+    use stylus_erc20::{TRANSFER_REASON_OK, TRANSFER_REASON_ZERO_AMOUNT};
+
+    let (_vm, mut token) = deploy();
+    assert_eq!(ok(token.can_transfer(addr(1), addr(2), U256::ZERO)), (true, TRANSFER_REASON_OK));
+
+    ok(token.set_reject_zero_transfers(true));
+    assert_eq!(
+        ok(token.can_transfer(addr(1), addr(2), U256::ZERO)),
+        (false, TRANSFER_REASON_ZERO_AMOUNT)
+    );
+}
+
+// ============================================================================
+// CAPPED MINT TESTS
+// ============================================================================
+
+/// Token whose supply cap leaves `headroom` above the initial supply
+fn headroom_token(headroom: u64) -> (TestVM, ERC20Token) {
+    let (vm, mut token) = deploy();
+    ok(token.set_supply_cap_enabled(true));
+    ok(token.set_supply_cap(U256::from(SUPPLY + headroom)));
+    (vm, token)
+}
+
+#[test]
+fn test_mint_capped_under_cap_mints_full_amount() {
+    let (_vm, mut token) = headroom_token(1_000);
+    assert_eq!(ok(token.mint_capped(addr(2), U256::from(300u64))), U256::from(300u64));
+    assert_eq!(ok(token.total_supply()), U256::from(SUPPLY + 300));
+}
+
+#[test]
+fn test_mint_capped_overshoot_returns_clipped_amount() {
+    let (_vm, mut token) = headroom_token(100);
+    assert_eq!(ok(token.mint_capped(addr(2), U256::from(300u64))), U256::from(100u64));
+    assert_eq!(ok(token.total_supply()), U256::from(SUPPLY + 100));
+}
+
+#[test]
+fn test_mint_capped_at_cap_mints_zero() {
+    let (_vm, mut token) = headroom_token(0);
+    assert_eq!(ok(token.mint_capped(addr(2), U256::from(1u64))), U256::ZERO);
+    assert_eq!(ok(token.total_supply()), U256::from(SUPPLY));
+}
+
+#[test]
+fn test_mint_capped_without_cap() {
+    let (_vm, mut token) = deploy();
+    assert_eq!(ok(token.mint_capped(addr(2), U256::from(500u64))), U256::from(500u64));
+}
+
+// ============================================================================
+// PACKED CONFIG FLAGS TESTS
+// ============================================================================
+
+#[test]
+fn test_packed_flags_default_to_off() {
+    let (_vm, token) = deploy();
+    
+    assert!(!ok(token.paused()));
+    assert!(!ok(token.supply_cap_enabled()));
+    assert!(!ok(token.blacklist_enabled()));
+    assert!(!ok(token.transfer_restrictions_enabled()));
+    assert!(!ok(token.strict_approvals_enabled()));
+    assert!(!ok(token.track_approvals_enabled()));
+    assert!(!ok(token.snapshot_allowances_enabled()));
+    assert!(!ok(token.min_transfer_enabled()));
+}
+
+#[test]
+fn test_packed_flags_round_trip_through_setters() {
+    let (_vm, mut token) = deploy();
+    
+    ok(token.set_supply_cap_enabled(true));
+    ok(token.set_blacklist_enabled(true));
+    ok(token.set_transfer_restrictions_enabled(true));
+    ok(token.set_strict_approvals_enabled(true));
+    ok(token.set_track_approvals_enabled(true));
+    ok(token.set_snapshot_allowances_enabled(true));
+    ok(token.pause());
+    
+    assert!(ok(token.paused()));
+    assert!(ok(token.supply_cap_enabled()));
+    assert!(ok(token.blacklist_enabled()));
+    assert!(ok(token.transfer_restrictions_enabled()));
+    assert!(ok(token.strict_approvals_enabled()));
+    assert!(ok(token.track_approvals_enabled()));
+    assert!(ok(token.snapshot_allowances_enabled()));
+}
+
+#[test]
+fn test_packed_flag_write_leaves_other_flags_untouched() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_blacklist_enabled(true));
+    ok(token.set_track_approvals_enabled(true));
+    
+    ok(token.set_blacklist_enabled(false));
+    
+    assert!(!ok(token.blacklist_enabled()));
+    assert!(ok(token.track_approvals_enabled()));
+    assert!(!ok(token.supply_cap_enabled()));
+    assert!(!ok(token.paused()));
+}
+
+#[test]
+fn test_packed_pause_flag_still_gates_transfers() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_blacklist_enabled(true));
+    ok(token.pause());
+    
+    assert!(matches!(token.transfer(addr(2), U256::from(1u64)), Err(ERC20Error::ContractPaused(_))));
+    
+    ok(token.unpause());
+    assert!(ok(token.transfer(addr(2), U256::from(1u64))));
+    assert!(ok(token.blacklist_enabled()));
+}
+
+#[test]
+fn test_packed_flags_survive_reinitialize() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_supply_cap_enabled(true));
+    ok(token.set_transfer_restrictions_enabled(true));
+    
+    ok(token.reinitialize(U256::from(2u64)));
+    
+    assert!(ok(token.supply_cap_enabled()));
+    assert!(ok(token.transfer_restrictions_enabled()));
+    assert!(!ok(token.blacklist_enabled()));
+    assert!(!ok(token.paused()));
+}
+
+// ============================================================================
+// RENOUNCED OWNERSHIP GUARD TESTS
+// ============================================================================
+
+#[test]
+fn test_owner_functions_after_renounce_report_renounced() {
+    let (_vm, mut token) = deploy();
+    ok(token.renounce_ownership());
+    
+    assert!(matches!(token.initiate_ownership_transfer(addr(2)), Err(ERC20Error::OwnerRenounced(_))));
+    assert!(matches!(token.transfer_ownership(addr(2)), Err(ERC20Error::OwnerRenounced(_))));
+    assert!(matches!(token.set_max_batch_size(U256::from(10u64)), Err(ERC20Error::OwnerRenounced(_))));
+}
+
+#[test]
+fn test_renounced_error_is_the_same_for_every_caller() {
+    let (vm, mut token) = deploy();
+    ok(token.renounce_ownership());
+    
+    vm.set_sender(addr(2));
+    assert!(matches!(token.initiate_ownership_transfer(addr(3)), Err(ERC20Error::OwnerRenounced(_))));
+    
+    vm.set_sender(Address::ZERO);
+    assert!(matches!(token.initiate_ownership_transfer(addr(3)), Err(ERC20Error::OwnerRenounced(_))));
+}
+
+#[test]
+fn test_owner_functions_before_renounce_report_not_owner() {
+    let (vm, mut token) = deploy();
+    
+    vm.set_sender(addr(2));
+    match token.initiate_ownership_transfer(addr(3)) {
+        Err(ERC20Error::NotOwner(e)) => {
+            assert_eq!(e.caller, addr(2));
+            assert_eq!(e.owner, addr(1));
+        }
+        _ => panic!("expected NotOwner"),
+    }
+}
+
+// ============================================================================
+// WHITELIST MODE TESTS
+// ============================================================================
+
+fn whitelist_token(mode: u8, listed: &[Address]) -> (TestVM, ERC20Token) {
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(3), 1_000);
+    fund(&vm, &mut token, addr(4), 1_000);
+    for account in listed {
+        ok(token.add_to_whitelist(*account));
+    }
+    ok(token.set_whitelist_mode(mode));
+    ok(token.set_transfer_restrictions_enabled(true));
+    (vm, token)
+}
+
+#[test]
+fn test_whitelist_mode_defaults_to_either_party() {
+    let (_vm, token) = deploy();
+    assert_eq!(ok(token.whitelist_mode()), stylus_erc20::WHITELIST_MODE_EITHER_PARTY);
+}
+
+#[test]
+fn test_whitelist_mode_rejects_unknown_value() {
+    let (_vm, mut token) = deploy();
+    match token.set_whitelist_mode(3) {
+        Err(ERC20Error::InvalidWhitelistMode(e)) => assert_eq!(e.mode, 3),
+        _ => panic!("expected InvalidWhitelistMode"),
+    }
+}
+
+#[test]
+fn test_whitelist_either_party_accepts_one_listed_side() {
+    let (vm, mut token) = whitelist_token(stylus_erc20::WHITELIST_MODE_EITHER_PARTY, &[addr(2)]);
+    
+    vm.set_sender(addr(3));
+    assert!(ok(token.transfer(addr(2), U256::from(10u64))));
+    assert!(matches!(token.transfer(addr(4), U256::from(10u64)), Err(ERC20Error::TransferRestricted(_))));
+}
+
+#[test]
+fn test_whitelist_sender_only_blocks_pull_from_unlisted_sender() {
+    // addr(2) is whitelisted but cannot pull from the non-whitelisted addr(3)
+    let (vm, mut token) = whitelist_token(stylus_erc20::WHITELIST_MODE_SENDER_ONLY, &[addr(2)]);
+    vm.set_sender(addr(3));
+    ok(token.approve(addr(2), U256::from(100u64)));
+    
+    vm.set_sender(addr(2));
+    match token.transfer_from(addr(3), addr(2), U256::from(10u64)) {
+        Err(ERC20Error::TransferRestricted(e)) => {
+            assert_eq!(e.from, addr(3));
+            assert_eq!(e.to, addr(2));
+        }
+        _ => panic!("expected TransferRestricted"),
+    }
+}
+
+#[test]
+fn test_whitelist_sender_only_lets_listed_sender_pay_anyone() {
+    let (vm, mut token) = whitelist_token(stylus_erc20::WHITELIST_MODE_SENDER_ONLY, &[addr(3)]);
+    
+    vm.set_sender(addr(3));
+    assert!(ok(token.transfer(addr(4), U256::from(10u64))));
+    assert_eq!(ok(token.balance_of(addr(4))), U256::from(1_010u64));
+}
+
+#[test]
+fn test_whitelist_both_parties_requires_both() {
+    let (vm, mut token) = whitelist_token(stylus_erc20::WHITELIST_MODE_BOTH_PARTIES, &[addr(2), addr(3)]);
+    
+    vm.set_sender(addr(3));
+    assert!(ok(token.transfer(addr(2), U256::from(10u64))));
+    assert!(matches!(token.transfer(addr(4), U256::from(10u64)), Err(ERC20Error::TransferRestricted(_))));
+    
+    vm.set_sender(addr(4));
+    assert!(matches!(token.transfer(addr(2), U256::from(10u64)), Err(ERC20Error::TransferRestricted(_))));
+}
+
+// ============================================================================
+// ROLE HASH VIEW TESTS
+// ============================================================================
+
+#[test]
+fn test_role_hash_matches_builtin_roles() {
+    let (_vm, token) = deploy();
+    
+    assert_eq!(ok(token.role_hash("MINTER_ROLE".to_string())), MINTER_ROLE);
+    assert_eq!(ok(token.role_hash("PAUSER_ROLE".to_string())), PAUSER_ROLE);
+    assert_eq!(ok(token.role_hash("ADMIN_ROLE".to_string())), ADMIN_ROLE);
+    assert_eq!(ok(token.role_hash("COMPLIANCE_ROLE".to_string())), COMPLIANCE_ROLE);
+}
+
+#[test]
+fn test_role_hash_minter_expected_value() {
+    let (_vm, token) = deploy();
+    assert_eq!(
+        ok(token.role_hash("MINTER_ROLE".to_string())),
+        fixed_bytes!("9f2df0fed2c77648de5860a4cc508cd0818c85b8b8a1ab4ceeef8d981c8956a6")
+    );
+}
+
+#[test]
+fn test_role_hash_is_case_sensitive() {
+    let (_vm, token) = deploy();
+    assert_ne!(ok(token.role_hash("minter_role".to_string())), MINTER_ROLE);
+}
+
+#[test]
+fn test_role_hash_works_before_initialize() {
+    let (_vm, token) = deploy_uninitialized();
+    assert_eq!(ok(token.role_hash("PAUSER_ROLE".to_string())), PAUSER_ROLE);
+}
+
+// ============================================================================
+// ALLOWANCE MIGRATION TESTS
+// ============================================================================
+
+#[test]
+fn test_seed_allowances_during_migration() {
+    use stylus_erc20::Approval;
+    let (vm, mut token) = deploy();
+    assert!(ok(token.migration_active()));
+    
+    ok(token.seed_allowances(
+        vec![addr(1), addr(2)],
+        vec![addr(3), addr(3)],
+        vec![U256::from(10u64), U256::from(20u64)],
+    ));
+    
+    assert_eq!(ok(token.allowance(addr(1), addr(3))), U256::from(10u64));
+    assert_eq!(ok(token.allowance(addr(2), addr(3))), U256::from(20u64));
+    
+    let approvals = events::<Approval>(&vm);
+    assert_eq!(approvals.len(), 2);
+    assert_eq!(approvals[1].owner, addr(2));
+    assert_eq!(approvals[1].spender, addr(3));
+    assert_eq!(approvals[1].amount, U256::from(20u64));
+}
+
+#[test]
+fn test_seed_allowances_rejects_length_mismatch() {
+    let (_vm, mut token) = deploy();
+    
+    let result = token.seed_allowances(
+        vec![addr(1), addr(2)],
+        vec![addr(3)],
+        vec![U256::from(10u64), U256::from(20u64)],
+    );
+    assert!(matches!(result, Err(ERC20Error::SeedAllowancesLengthMismatch(_))));
+}
+
+#[test]
+fn test_seed_allowances_is_owner_only() {
+    let (vm, mut token) = deploy();
+    
+    vm.set_sender(addr(2));
+    let result = token.seed_allowances(vec![addr(2)], vec![addr(3)], vec![U256::from(10u64)]);
+    assert!(matches!(result, Err(ERC20Error::NotOwner(_))));
+}
+
+#[test]
+fn test_seed_allowances_reverts_after_migration_ends() {
+    let (_vm, mut token) = deploy();
+    ok(token.end_migration());
+    assert!(!ok(token.migration_active()));
+    
+    let result = token.seed_allowances(vec![addr(1)], vec![addr(3)], vec![U256::from(10u64)]);
+    assert!(matches!(result, Err(ERC20Error::MigrationClosed(_))));
+    assert!(matches!(token.end_migration(), Err(ERC20Error::MigrationClosed(_))));
+}
+
+// ============================================================================
+// MINTED REASON EVENT TESTS
+// ============================================================================
+
+#[test]
+fn test_initialize_emits_initial_minted_reason() {
+    use stylus_erc20::{Minted, MINT_SOURCE_INITIAL};
+    let (vm, _token) = deploy();
+    
+    let minted = events::<Minted>(&vm);
+    assert_eq!(minted.len(), 1);
+    assert_eq!(minted[0].to, addr(1));
+    assert_eq!(minted[0].amount, U256::from(SUPPLY));
+    assert_eq!(minted[0].reason, MINT_SOURCE_INITIAL);
+    assert_eq!(minted[0].operator, addr(1));
+}
+
+#[test]
+fn test_initialize_with_allocations_emits_initial_reason_per_recipient() {
+    use stylus_erc20::{Minted, MINT_SOURCE_INITIAL};
+    let (vm, mut token) = deploy_uninitialized();
+    ok(token.initialize_with_allocations(
+        "Test Token".into(),
+        "TST".into(),
+        18,
+        vec![addr(2), addr(3)],
+        vec![U256::from(600u64), U256::from(400u64)],
+        addr(1),
+    ));
+    
+    let minted = events::<Minted>(&vm);
+    assert_eq!(minted.len(), 2);
+    assert!(minted.iter().all(|e| e.reason == MINT_SOURCE_INITIAL));
+    assert_eq!(minted[1].to, addr(3));
+    assert_eq!(minted[1].amount, U256::from(400u64));
+}
+
+#[test]
+fn test_mint_emits_manual_reason_with_operator() {
+    use stylus_erc20::{Minted, MINT_SOURCE_MANUAL};
+    let (vm, mut token) = deploy();
+    ok(token.grant_role(MINTER_ROLE, addr(2)));
+    
+    vm.set_sender(addr(2));
+    ok(token.mint(addr(3), U256::from(50u64)));
+    
+    let minted = events::<Minted>(&vm);
+    let last = minted.last().unwrap();
+    assert_eq!(last.to, addr(3));
+    assert_eq!(last.amount, U256::from(50u64));
+    assert_eq!(last.reason, MINT_SOURCE_MANUAL);
+    assert_eq!(last.operator, addr(2));
+}
+
+#[test]
+fn test_mint_capped_emits_manual_reason() {
+    use stylus_erc20::{Minted, MINT_SOURCE_MANUAL};
+    let (vm, mut token) = deploy();
+    
+    ok(token.mint_capped(addr(3), U256::from(25u64)));
+    
+    let minted = events::<Minted>(&vm);
+    assert_eq!(minted.len(), 2);
+    assert_eq!(minted[1].reason, MINT_SOURCE_MANUAL);
+    assert_eq!(minted[1].amount, U256::from(25u64));
+}
+
+// ============================================================================
+// EXPIRED ALLOWANCE MUTATOR TESTS
+// ============================================================================
+
+/// Owner addr(1) approves addr(2) for 500 until t=2_000, then the clock moves past it
+fn expired_allowance_token() -> (TestVM, ERC20Token) {
+    let (vm, mut token) = deploy();
+    ok(token.approve_with_expiry(addr(2), U256::from(500u64), U256::from(2_000u64)));
+    vm.set_block_timestamp(2_000);
+    (vm, token)
+}
+
+#[test]
+fn test_decrease_expired_allowance_reverts() {
+    let (_vm, mut token) = expired_allowance_token();
+    
+    match token.decrease_allowance(addr(2), U256::from(100u64)) {
+        Err(ERC20Error::InsufficientAllowance(e)) => {
+            assert_eq!(e.allowance, U256::ZERO);
+            assert_eq!(e.required, U256::from(100u64));
+        }
+        _ => panic!("expected InsufficientAllowance"),
+    }
+}
+
+#[test]
+fn test_decrease_live_allowance_keeps_expiry() {
+    let (vm, mut token) = expired_allowance_token();
+    vm.set_block_timestamp(1_999);
+    
+    ok(token.decrease_allowance(addr(2), U256::from(100u64)));
+    
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::from(400u64));
+    assert_eq!(ok(token.allowance_expiry(addr(1), addr(2))), U256::from(2_000u64));
+}
+
+#[test]
+fn test_increase_expired_allowance_starts_from_zero_and_clears_expiry() {
+    let (vm, mut token) = expired_allowance_token();
+    
+    ok(token.increase_allowance(addr(2), U256::from(50u64)));
+    
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::from(50u64));
+    assert_eq!(ok(token.allowance_expiry(addr(1), addr(2))), U256::ZERO);
+    
+    // No longer lapses
+    vm.set_block_timestamp(1_000_000);
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::from(50u64));
+}
+
+#[test]
+fn test_increase_live_allowance_keeps_expiry() {
+    let (vm, mut token) = expired_allowance_token();
+    vm.set_block_timestamp(1_999);
+    
+    ok(token.increase_allowance(addr(2), U256::from(50u64)));
+    
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::from(550u64));
+    assert_eq!(ok(token.allowance_expiry(addr(1), addr(2))), U256::from(2_000u64));
+}
+
+// ============================================================================
+// PER-ROLE PAUSE TESTS
+// ============================================================================
+
+/// addr(2) holds MINTER_ROLE and PAUSER_ROLE
+fn role_pause_token() -> (TestVM, ERC20Token) {
+    let (vm, mut token) = deploy();
+    ok(token.grant_role(MINTER_ROLE, addr(2)));
+    ok(token.grant_role(PAUSER_ROLE, addr(2)));
+    (vm, token)
+}
+
+#[test]
+fn test_paused_minter_role_blocks_minters() {
+    let (vm, mut token) = role_pause_token();
+    ok(token.set_role_paused(MINTER_ROLE, true));
+    assert!(ok(token.is_role_paused(MINTER_ROLE)));
+    
+    vm.set_sender(addr(2));
+    match token.mint(addr(3), U256::from(10u64)) {
+        Err(ERC20Error::RolePaused(e)) => assert_eq!(e.role, MINTER_ROLE),
+        _ => panic!("expected RolePaused"),
+    }
+    // The role itself is kept
+    assert!(ok(token.has_role(MINTER_ROLE, addr(2))));
+    assert_eq!(ok(token.can_mint(addr(2), U256::from(10u64))), (false, stylus_erc20::MINT_REASON_ROLE_PAUSED));
+}
+
+#[test]
+fn test_role_pause_does_not_affect_owner() {
+    let (_vm, mut token) = role_pause_token();
+    ok(token.set_role_paused(MINTER_ROLE, true));
+    
+    assert!(ok(token.mint(addr(3), U256::from(10u64))));
+    assert_eq!(ok(token.balance_of(addr(3))), U256::from(10u64));
+}
+
+#[test]
+fn test_unpausing_role_restores_minting() {
+    let (vm, mut token) = role_pause_token();
+    ok(token.set_role_paused(MINTER_ROLE, true));
+    ok(token.set_role_paused(MINTER_ROLE, false));
+    
+    vm.set_sender(addr(2));
+    assert!(ok(token.mint(addr(3), U256::from(10u64))));
+}
+
+#[test]
+fn test_role_pause_is_scoped_to_one_role() {
+    let (vm, mut token) = role_pause_token();
+    ok(token.set_role_paused(MINTER_ROLE, true));
+    
+    vm.set_sender(addr(2));
+    assert!(ok(token.pause_with_role()));
+    assert!(ok(token.paused()));
+    
+    // Non-holders still get AccessDenied, not RolePaused
+    vm.set_sender(addr(3));
+    assert!(matches!(token.mint(addr(3), U256::from(10u64)), Err(ERC20Error::AccessDenied(_))));
+}
+
+#[test]
+fn test_set_role_paused_requires_admin() {
+    let (vm, mut token) = role_pause_token();
+    
+    vm.set_sender(addr(2));
+    match token.set_role_paused(MINTER_ROLE, true) {
+        Err(ERC20Error::AccessDenied(e)) => assert_eq!(e.role, DEFAULT_ADMIN_ROLE),
+        _ => panic!("expected AccessDenied"),
+    }
+}
+
+// ============================================================================
+// INCREASE ALLOWANCE WITH EXPIRY TESTS
+// ============================================================================
+
+#[test]
+fn test_increase_with_expiry_updates_amount_and_expiry() {
+    let (vm, mut token) = deploy();
+    ok(token.approve_with_expiry(addr(2), U256::from(100u64), U256::from(2_000u64)));
+    
+    ok(token.increase_allowance_with_expiry(addr(2), U256::from(50u64), U256::from(5_000u64)));
+    
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::from(150u64));
+    assert_eq!(ok(token.allowance_expiry(addr(1), addr(2))), U256::from(5_000u64));
+    vm.set_block_timestamp(4_000);
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::from(150u64));
+}
+
+#[test]
+fn test_increase_with_expiry_on_lapsed_allowance_starts_from_zero() {
+    let (vm, mut token) = deploy();
+    ok(token.approve_with_expiry(addr(2), U256::from(100u64), U256::from(2_000u64)));
+    vm.set_block_timestamp(2_500);
+    
+    ok(token.increase_allowance_with_expiry(addr(2), U256::from(50u64), U256::from(3_000u64)));
+    
+    assert_eq!(ok(token.allowance(addr(1), addr(2))), U256::from(50u64));
+    assert_eq!(ok(token.allowance_expiry(addr(1), addr(2))), U256::from(3_000u64));
+}
+
+#[test]
+fn test_increase_with_expiry_rejects_past_expiry() {
+    let (_vm, mut token) = deploy();
+    ok(token.approve(addr(2), U256::from(100u64)));
+    
+    match token.increase_allowance_with_expiry(addr(2), U256::from(50u64), U256::from(1_000u64)) {
+        Err(ERC20Error::InvalidExpiry(e)) => {
+            assert_eq!(e.expiry, U256::from(1_000u64));
+            assert_eq!(e.current_time, U256::from(1_000u64));
+        }
+        _ => panic!("expected InvalidExpiry"),
+    }
+}
+
+#[test]
+fn test_increase_with_expiry_emits_approval() {
+    use stylus_erc20::Approval;
+    let (vm, mut token) = deploy();
+    
+    ok(token.increase_allowance_with_expiry(addr(2), U256::from(70u64), U256::from(5_000u64)));
+    
+    let approvals = events::<Approval>(&vm);
+    assert_eq!(approvals.last().unwrap().amount, U256::from(70u64));
+}
+
+// ============================================================================
+// STATE STATS TESTS
+// ============================================================================
+
+#[test]
+fn test_state_stats_after_deploy() {
+    let (_vm, token) = deploy();
+    
+    let (holders, snapshots, blacklisted, _roles) = ok(token.state_stats());
+    assert_eq!(holders, U256::from(1u64));
+    assert_eq!(snapshots, U256::ZERO);
+    assert_eq!(blacklisted, U256::ZERO);
+}
+
+#[test]
+fn test_state_stats_track_each_structure() {
+    let (vm, mut token) = deploy();
+    let (_, _, _, initial_roles) = ok(token.state_stats());
+    
+    fund(&vm, &mut token, addr(2), 100);
+    ok(token.snapshot());
+    ok(token.blacklist(addr(3)));
+    ok(token.grant_role(MINTER_ROLE, addr(2)));
+    ok(token.grant_role(PAUSER_ROLE, addr(2)));
+    
+    assert_eq!(
+        ok(token.state_stats()),
+        (U256::from(2u64), U256::from(1u64), U256::from(1u64), initial_roles + U256::from(2u64))
+    );
+    
+    // Unblacklisting and revoking shrink the counters again
+    ok(token.unblacklist(addr(3)));
+    ok(token.revoke_role(PAUSER_ROLE, addr(2)));
+    let (_, _, blacklisted, roles) = ok(token.state_stats());
+    assert_eq!(blacklisted, U256::ZERO);
+    assert_eq!(roles, initial_roles + U256::from(1u64));
+}
+
+#[test]
+fn test_role_entries_not_double_counted() {
+    let (vm, mut token) = deploy();
+    let (_, _, _, initial_roles) = ok(token.state_stats());
+    
+    ok(token.grant_role_with_expiry(MINTER_ROLE, addr(2), U256::from(2_000u64)));
+    vm.set_block_timestamp(3_000);
+    // Renewing the expired grant rewrites the same entry
+    ok(token.grant_role(MINTER_ROLE, addr(2)));
+    
+    assert_eq!(ok(token.state_stats()).3, initial_roles + U256::from(1u64));
+}
+
+// ============================================================================
+// RECEIVER ACKNOWLEDGEMENT TESTS
+// ============================================================================
+
+/// Receiver acknowledgement on, with addr(9) deployed as a contract
+fn receiver_ack_token() -> (TestVM, ERC20Token) {
+    let (vm, mut token) = deploy();
+    vm.set_code(addr(9), vec![0x60, 0x00, 0x60, 0x00, 0xf3]);
+    ok(token.set_require_receiver_ack(true));
+    (vm, token)
+}
+
+#[test]
+fn test_receiver_ack_blocks_unregistered_contract() {
+    let (_vm, mut token) = receiver_ack_token();
+    
+    match token.transfer(addr(9), U256::from(10u64)) {
+        Err(ERC20Error::TransferToNonAcceptingContract(e)) => assert_eq!(e.to, addr(9)),
+        _ => panic!("expected TransferToNonAcceptingContract"),
+    }
+    assert_eq!(
+        ok(token.can_transfer(addr(1), addr(9), U256::from(10u64))),
+        (false, stylus_erc20::TRANSFER_REASON_RECIPIENT_NOT_ACCEPTING)
+    );
+}
+
+#[test]
+fn test_receiver_ack_allows_registered_contract() {
+    let (vm, mut token) = receiver_ack_token();
+    
+    vm.set_sender(addr(9));
+    ok(token.register_as_receiver());
+    assert!(ok(token.accepts_token(addr(9))));
+    
+    vm.set_sender(addr(1));
+    assert!(ok(token.transfer(addr(9), U256::from(10u64))));
+    assert_eq!(ok(token.balance_of(addr(9))), U256::from(10u64));
+}
+
+#[test]
+fn test_receiver_ack_always_allows_eoa() {
+    let (_vm, mut token) = receiver_ack_token();
+    assert!(ok(token.transfer(addr(2), U256::from(10u64))));
+}
+
+#[test]
+fn test_receiver_ack_disabled_allows_contracts() {
+    let (_vm, mut token) = receiver_ack_token();
+    ok(token.set_require_receiver_ack(false));
+    
+    assert!(!ok(token.require_receiver_ack()));
+    assert!(ok(token.transfer(addr(9), U256::from(10u64))));
+}
+
+// ============================================================================
+// SCHEDULED RELEASE TESTS
+// ============================================================================
+
+/// The contract holds 1_000 unreserved tokens of its own
+fn release_token() -> (TestVM, ERC20Token) {
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, CONTRACT, 1_000);
+    (vm, token)
+}
+
+#[test]
+fn test_scheduled_release_early_execute_reverts() {
+    let (vm, mut token) = release_token();
+    let id = ok(token.scheduled_release(addr(2), U256::from(400u64), U256::from(5_000u64)));
+    
+    vm.set_block_timestamp(4_999);
+    match token.execute_release(id) {
+        Err(ERC20Error::ReleaseNotReady(e)) => {
+            assert_eq!(e.release_time, U256::from(5_000u64));
+            assert_eq!(e.current_time, U256::from(4_999u64));
+        }
+        _ => panic!("expected ReleaseNotReady"),
+    }
+    assert_eq!(ok(token.balance_of(CONTRACT)), U256::from(1_000u64));
+}
+
+#[test]
+fn test_scheduled_release_executes_after_release_time() {
+    let (vm, mut token) = release_token();
+    let id = ok(token.scheduled_release(addr(2), U256::from(400u64), U256::from(5_000u64)));
+    
+    // Anyone can execute a due release
+    vm.set_block_timestamp(5_000);
+    vm.set_sender(addr(5));
+    ok(token.execute_release(id));
+    
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(400u64));
+    assert_eq!(ok(token.balance_of(CONTRACT)), U256::from(600u64));
+    assert_eq!(ok(token.get_scheduled_release(id)), (addr(2), U256::from(400u64), U256::from(5_000u64), true));
+    
+    // Each release executes once
+    assert!(matches!(token.execute_release(id), Err(ERC20Error::ReleaseAlreadyExecuted(_))));
+    assert!(matches!(token.execute_release(id + U256::from(1u64)), Err(ERC20Error::ReleaseNotFound(_))));
+}
+
+#[test]
+fn test_scheduled_release_cannot_overcommit_or_touch_reserved() {
+    let (_vm, mut token) = release_token();
+    // 300 of the contract's 1_300 tokens are held for vesting
+    ok(token.create_vesting_schedule(addr(4), U256::from(300u64), U256::from(1_000u64), U256::from(100u64)));
+    ok(token.scheduled_release(addr(2), U256::from(800u64), U256::from(5_000u64)));
+    
+    // Only 200 left once vesting custody and the first release are set aside
+    match token.scheduled_release(addr(3), U256::from(201u64), U256::from(5_000u64)) {
+        Err(ERC20Error::InsufficientBalance(e)) => assert_eq!(e.balance, U256::from(200u64)),
+        _ => panic!("expected InsufficientBalance"),
+    }
+    assert_eq!(ok(token.scheduled_release(addr(3), U256::from(200u64), U256::from(5_000u64))), U256::from(1u64));
+    assert_eq!(ok(token.release_count()), U256::from(2u64));
+}
+
+#[test]
+fn test_scheduled_release_is_owner_only() {
+    let (vm, mut token) = release_token();
+    
+    vm.set_sender(addr(2));
+    let result = token.scheduled_release(addr(2), U256::from(1u64), U256::from(5_000u64));
+    assert!(matches!(result, Err(ERC20Error::NotOwner(_))));
+}
+
+// ============================================================================
+// ACCOUNT STATUS TESTS
+// ============================================================================
+
+/// addr(2) has used 250 of a 1_000/hour outflow limit, is blacklisted and denied;
+/// addr(3) is scheduled for blacklisting at t=5_000
+fn restricted_status_token() -> (TestVM, ERC20Token) {
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(2), 5_000);
+    ok(token.set_account_outflow_limit(addr(2), U256::from(1_000u64), U256::from(3_600u64)));
+    vm.set_sender(addr(2));
+    ok(token.transfer(addr(4), U256::from(250u64)));
+    
+    vm.set_sender(addr(1));
+    ok(token.set_blacklist_enabled(true));
+    ok(token.blacklist(addr(2)));
+    ok(token.blacklist_with_delay(addr(3), U256::from(4_000u64)));
+    ok(token.set_transfer_deny(addr(2), true));
+    (vm, token)
+}
+
+#[test]
+fn test_account_status_clean_account() {
+    let (_vm, token) = restricted_status_token();
+    assert_eq!(ok(token.account_status(addr(1))), (false, false, U256::ZERO, U256::ZERO));
+}
+
+#[test]
+fn test_account_status_restricted_account() {
+    let (_vm, token) = restricted_status_token();
+    assert_eq!(ok(token.account_status(addr(2))), (true, true, U256::ZERO, U256::from(250u64)));
+}
+
+#[test]
+fn test_account_status_outflow_resets_after_window() {
+    let (vm, token) = restricted_status_token();
+    vm.set_block_timestamp(4_600);
+    assert_eq!(ok(token.account_status(addr(2))).3, U256::ZERO);
+}
+
+#[test]
+fn test_account_status_scheduled_blacklist_not_yet_effective() {
+    let (vm, token) = restricted_status_token();
+    
+    vm.set_block_timestamp(4_999);
+    assert!(!ok(token.account_status(addr(3))).0);
+    vm.set_block_timestamp(5_000);
+    assert!(ok(token.account_status(addr(3))).0);
+}
+
+#[test]
+fn test_account_status_ignores_blacklist_while_disabled() {
+    let (_vm, mut token) = restricted_status_token();
+    ok(token.set_blacklist_enabled(false));
+    
+    assert!(!ok(token.account_status(addr(2))).0);
+}
+
+// ============================================================================
+// SUPPLY AND BALANCE OVERFLOW TESTS
+// ============================================================================
+
+/// Total supply is 10 below U256::MAX (initialize bounds the initial supply, so mint the rest)
+fn near_max_supply_token() -> (TestVM, ERC20Token) {
+    let (vm, mut token) = deploy();
+    ok(token.mint(addr(1), U256::MAX - U256::from(10u64) - U256::from(SUPPLY)));
+    (vm, token)
+}
+
+#[test]
+fn test_mint_near_max_supply_reports_supply_overflow() {
+    let (_vm, mut token) = near_max_supply_token();
+    
+    match token.mint(addr(2), U256::from(11u64)) {
+        Err(ERC20Error::SupplyOverflow(e)) => {
+            assert_eq!(e.total_supply, U256::MAX - U256::from(10u64));
+            assert_eq!(e.amount, U256::from(11u64));
+        }
+        _ => panic!("expected SupplyOverflow"),
+    }
+}
+
+#[test]
+fn test_mint_up_to_max_supply_succeeds() {
+    let (_vm, mut token) = near_max_supply_token();
+    
+    ok(token.mint(addr(2), U256::from(10u64)));
+    
+    assert_eq!(ok(token.total_supply()), U256::MAX);
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(10u64));
+}
+
+#[test]
+fn test_supply_overflow_takes_precedence_over_balance_overflow() {
+    // The owner's balance would overflow too, but the supply is reported
+    let (_vm, mut token) = near_max_supply_token();
+    ok(token.mint(addr(2), U256::from(10u64)));
+    
+    assert!(matches!(token.mint(addr(1), U256::from(1u64)), Err(ERC20Error::SupplyOverflow(_))));
+}
+
+// ============================================================================
+// SETTLEMENT TESTS
+// ============================================================================
+
+/// addr(1..=3) each hold 100 and approved the relayer addr(9) for 50
+fn settlement_token() -> (TestVM, ERC20Token) {
+    let (vm, mut token) = deploy_with_supply(300);
+    fund(&vm, &mut token, addr(2), 100);
+    fund(&vm, &mut token, addr(3), 100);
+    for n in 1..=3u8 {
+        vm.set_sender(addr(n));
+        ok(token.approve(addr(9), U256::from(50u64)));
+    }
+    vm.set_sender(addr(9));
+    (vm, token)
+}
+
+#[test]
+fn test_settle_three_pairs() {
+    let (_vm, mut token) = settlement_token();
+    
+    ok(token.settle(
+        vec![addr(1), addr(2), addr(3)],
+        vec![addr(2), addr(3), addr(1)],
+        vec![U256::from(10u64), U256::from(20u64), U256::from(30u64)],
+    ));
+    
+    assert_eq!(ok(token.balance_of(addr(1))), U256::from(120u64));
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(90u64));
+    assert_eq!(ok(token.balance_of(addr(3))), U256::from(90u64));
+    assert_eq!(ok(token.allowance(addr(3), addr(9))), U256::from(20u64));
+}
+
+#[test]
+fn test_settle_length_mismatch() {
+    let (_vm, mut token) = settlement_token();
+    
+    let result = token.settle(vec![addr(1), addr(2)], vec![addr(3)], vec![U256::from(1u64), U256::from(1u64)]);
+    assert!(matches!(result, Err(ERC20Error::SettleLengthMismatch(_))));
+}
+
+#[test]
+fn test_settle_insufficient_allowance_reports_leg() {
+    use stylus_erc20::InsufficientAllowance;
+    use stylus_sdk::alloy_sol_types::SolError;
+    
+    let (_vm, mut token) = settlement_token();
+    
+    let result = token.settle(
+        vec![addr(1), addr(2)],
+        vec![addr(3), addr(3)],
+        vec![U256::from(10u64), U256::from(60u64)],
+    );
+    
+    match result {
+        Err(ERC20Error::BatchItemFailed(e)) => {
+            assert_eq!(e.index, U256::from(1u64));
+            assert_eq!(e.reason[..4], InsufficientAllowance::SELECTOR);
+        }
+        _ => panic!("expected BatchItemFailed"),
+    }
+}
+
+#[test]
+fn test_settle_rejects_zero_recipient() {
+    use stylus_erc20::ZeroAddress;
+    use stylus_sdk::alloy_sol_types::SolError;
+    
+    let (_vm, mut token) = settlement_token();
+    
+    match token.settle(vec![addr(1)], vec![Address::ZERO], vec![U256::from(1u64)]) {
+        Err(ERC20Error::BatchItemFailed(e)) => {
+            assert_eq!(e.index, U256::ZERO);
+            assert_eq!(e.reason[..4], ZeroAddress::SELECTOR);
+        }
+        _ => panic!("expected BatchItemFailed"),
+    }
+}
+
+// ============================================================================
+// MINT ALLOCATION CAP GUARDRAIL TESTS
+// ============================================================================
+
+/// Supply of 1_000 under an enabled cap of 10_000; addr(2) is a minter
+fn allocation_token() -> (TestVM, ERC20Token) {
+    let (vm, mut token) = deploy_with_supply(1_000);
+    ok(token.set_supply_cap(U256::from(10_000u64)));
+    ok(token.set_supply_cap_enabled(true));
+    ok(token.grant_role(MINTER_ROLE, addr(2)));
+    (vm, token)
+}
+
+#[test]
+fn test_cap_below_outstanding_allocations_reverts() {
+    let (_vm, mut token) = allocation_token();
+    ok(token.set_mint_allocation(addr(2), U256::from(3_000u64)));
+    ok(token.set_mint_allocation(addr(3), U256::from(2_000u64)));
+    assert_eq!(ok(token.total_allocated_mint()), U256::from(5_000u64));
+    
+    match token.set_supply_cap(U256::from(5_999u64)) {
+        Err(ERC20Error::CannotSetCapBelowCommitted(e)) => {
+            assert_eq!(e.cap, U256::from(5_999u64));
+            assert_eq!(e.committed, U256::from(6_000u64));
+        }
+        _ => panic!("expected CannotSetCapBelowCommitted"),
+    }
+    ok(token.set_supply_cap(U256::from(6_000u64)));
+}
+
+#[test]
+fn test_minting_draws_down_allocation() {
+    let (vm, mut token) = allocation_token();
+    ok(token.set_mint_allocation(addr(2), U256::from(3_000u64)));
+    
+    vm.set_sender(addr(2));
+    ok(token.mint(addr(4), U256::from(1_000u64)));
+    
+    assert_eq!(ok(token.mint_allocation(addr(2))), U256::from(2_000u64));
+    assert_eq!(ok(token.total_allocated_mint()), U256::from(2_000u64));
+    
+    // Supply grew by what the allocation shrank, so the committed total is unchanged
+    vm.set_sender(addr(1));
+    assert!(matches!(token.set_supply_cap(U256::from(3_999u64)), Err(ERC20Error::CannotSetCapBelowCommitted(_))));
+    ok(token.set_supply_cap(U256::from(4_000u64)));
+}
+
+#[test]
+fn test_allocation_cannot_exceed_cap_headroom() {
+    let (_vm, mut token) = allocation_token();
+    
+    match token.set_mint_allocation(addr(2), U256::from(9_001u64)) {
+        Err(ERC20Error::AllocationExceedsCap(e)) => {
+            assert_eq!(e.committed, U256::from(10_001u64));
+            assert_eq!(e.cap, U256::from(10_000u64));
+        }
+        _ => panic!("expected AllocationExceedsCap"),
+    }
+    ok(token.set_mint_allocation(addr(2), U256::from(9_000u64)));
+    // Replacing an allocation only counts the new amount
+    ok(token.set_mint_allocation(addr(2), U256::from(8_000u64)));
+    assert_eq!(ok(token.total_allocated_mint()), U256::from(8_000u64));
+}
+
+#[test]
+fn test_allocation_headroom_excludes_vesting_custody() {
+    let (_vm, mut token) = allocation_token();
+    // 400 of the supply moves into vesting custody and still counts as committed
+    ok(token.create_vesting_schedule(addr(5), U256::from(400u64), U256::from(1_100u64), U256::from(1_000u64)));
+    
+    assert!(matches!(
+        token.set_mint_allocation(addr(2), U256::from(8_601u64)),
+        Err(ERC20Error::AllocationExceedsCap(_))
+    ));
+    ok(token.set_mint_allocation(addr(2), U256::from(8_600u64)));
+}
+
+// ============================================================================
+// GUARDIAN PAUSE WINDOW TESTS
+// ============================================================================
+
+/// addr(7) is a guardian
+fn guardian_token() -> (TestVM, ERC20Token) {
+    let (vm, mut token) = deploy();
+    ok(token.add_guardian(addr(7)));
+    (vm, token)
+}
+
+#[test]
+fn test_guardian_pause_blocks_then_auto_lifts() {
+    use stylus_erc20::GUARDIAN_PAUSE_DURATION;
+    let (vm, mut token) = guardian_token();
+    vm.set_sender(addr(7));
+    ok(token.guardian_pause());
+    let lifts_at = 1_000 + GUARDIAN_PAUSE_DURATION;
+    
+    vm.set_block_timestamp(lifts_at - 1);
+    vm.set_sender(addr(1));
+    assert!(ok(token.paused()));
+    assert_eq!(ok(token.guardian_pause_expiry()), U256::from(lifts_at));
+    assert!(matches!(token.transfer(addr(2), U256::from(1u64)), Err(ERC20Error::ContractPaused(_))));
+    
+    // Transfers resume without an explicit unpause
+    vm.set_block_timestamp(lifts_at);
+    assert!(!ok(token.paused()));
+    assert_eq!(ok(token.guardian_pause_expiry()), U256::ZERO);
+    assert!(ok(token.transfer(addr(2), U256::from(1u64))));
+}
+
+#[test]
+fn test_guardian_pause_uses_configured_window() {
+    let (vm, mut token) = guardian_token();
+    ok(token.set_guardian_pause_max_duration(U256::from(3_600u64)));
+    assert_eq!(ok(token.guardian_pause_max_duration()), U256::from(3_600u64));
+    
+    vm.set_sender(addr(7));
+    ok(token.guardian_pause());
+    
+    vm.set_block_timestamp(4_599);
+    assert!(ok(token.paused()));
+    vm.set_block_timestamp(4_600);
+    assert!(!ok(token.paused()));
+}
+
+#[test]
+fn test_guardian_pause_window_zero_restores_default() {
+    let (_vm, mut token) = guardian_token();
+    ok(token.set_guardian_pause_max_duration(U256::from(3_600u64)));
+    ok(token.set_guardian_pause_max_duration(U256::ZERO));
+    
+    assert_eq!(ok(token.guardian_pause_max_duration()), U256::from(stylus_erc20::GUARDIAN_PAUSE_DURATION));
+}
+
+#[test]
+fn test_owner_repause_outlasts_guardian_window() {
+    use stylus_erc20::GUARDIAN_PAUSE_DURATION;
+    let (vm, mut token) = guardian_token();
+    vm.set_sender(addr(7));
+    ok(token.guardian_pause());
+    
+    vm.set_block_timestamp(2_000);
+    vm.set_sender(addr(1));
+    ok(token.pause());
+    assert_eq!(ok(token.guardian_pause_expiry()), U256::ZERO);
+    
+    vm.set_block_timestamp(1_000 + GUARDIAN_PAUSE_DURATION + 1);
+    assert!(ok(token.paused()));
+}
+
+#[test]
+fn test_guardian_pause_requires_guardian() {
+    let (vm, mut token) = guardian_token();
+    
+    vm.set_sender(addr(2));
+    assert!(matches!(token.guardian_pause(), Err(ERC20Error::AccessDenied(_))));
+}
+
+// ============================================================================
+// CANONICAL TRANSFER PATH TESTS
+// ============================================================================
+
+/// Blacklist, whitelist, deny list and a minimum amount all enabled:
+/// addr(5) is blacklisted, addr(6) is denied, and addr(1), addr(5), addr(6) are whitelisted
+fn canonical_policy_token() -> (TestVM, ERC20Token) {
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, addr(3), 1_000);
+    fund(&vm, &mut token, addr(5), 1_000);
+    ok(token.set_blacklist_enabled(true));
+    ok(token.blacklist(addr(5)));
+    for account in [addr(1), addr(5), addr(6)] {
+        ok(token.add_to_whitelist(account));
+    }
+    ok(token.set_transfer_restrictions_enabled(true));
+    ok(token.set_transfer_deny(addr(6), true));
+    ok(token.set_min_transfer_amount(U256::from(10u64), true));
+    (vm, token)
+}
+
+fn error_selector(result: Result<bool, ERC20Error>) -> Option<Vec<u8>> {
+    result.err().map(|e| Vec::<u8>::from(e)[..4].to_vec())
+}
+
+#[test]
+fn test_transfer_enforces_every_enabled_feature_like_with_checks() {
+    let cases = [
+        (addr(5), addr(2), 100u64),
+        (addr(3), addr(4), 100),
+        (addr(1), addr(6), 100),
+        (addr(1), addr(2), 5),
+        (addr(1), addr(2), 100),
+    ];
+    
+    for (from, to, amount) in cases {
+        let (vm, mut canonical) = canonical_policy_token();
+        vm.set_sender(from);
+        let expected = error_selector(canonical.transfer(to, U256::from(amount)));
+        
+        let (vm, mut alias) = canonical_policy_token();
+        vm.set_sender(from);
+        assert_eq!(error_selector(alias.transfer_with_checks(to, U256::from(amount))), expected);
+    }
+}
+
+#[test]
+fn test_transfer_reports_each_policy_error() {
+    let (vm, mut token) = canonical_policy_token();
+    
+    vm.set_sender(addr(5));
+    assert!(matches!(token.transfer(addr(2), U256::from(100u64)), Err(ERC20Error::AddressBlacklisted(_))));
+    vm.set_sender(addr(3));
+    assert!(matches!(token.transfer(addr(4), U256::from(100u64)), Err(ERC20Error::TransferRestricted(_))));
+    vm.set_sender(addr(1));
+    assert!(matches!(token.transfer(addr(6), U256::from(100u64)), Err(ERC20Error::TransferDenied(_))));
+    assert!(matches!(token.transfer(addr(2), U256::from(5u64)), Err(ERC20Error::TransferBelowMinimum(_))));
+    assert!(ok(token.transfer(addr(2), U256::from(100u64))));
+}
+
+#[test]
+fn test_batch_transfer_rejects_zero_recipient() {
+    use stylus_erc20::ZeroAddress;
+    use stylus_sdk::alloy_sol_types::SolError;
+    
+    let (_vm, mut token) = deploy();
+    
+    match token.batch_transfer(vec![addr(2), Address::ZERO], vec![U256::from(1u64), U256::from(1u64)]) {
+        Err(ERC20Error::BatchItemFailed(e)) => {
+            assert_eq!(e.index, U256::from(1u64));
+            assert_eq!(e.reason[..4], ZeroAddress::SELECTOR);
+        }
+        _ => panic!("expected BatchItemFailed"),
+    }
+}
+
+#[test]
+fn test_every_transfer_path_stops_while_paused() {
+    let (vm, mut token) = deploy();
+    fund(&vm, &mut token, CONTRACT, 500);
+    ok(token.pause());
+    
+    assert!(matches!(token.sweep_self_tokens(addr(2), U256::from(100u64)), Err(ERC20Error::ContractPaused(_))));
+    assert_eq!(
+        ok(token.can_transfer(CONTRACT, addr(2), U256::from(100u64))),
+        (false, stylus_erc20::TRANSFER_REASON_PAUSED)
+    );
+}
+
+#[test]
+fn test_transfer_unrestricted_by_default() {
+    let (_vm, mut token) = deploy();
+    assert!(ok(token.transfer(addr(2), U256::from(1u64))));
+}
+
+// ============================================================================
+// META-TRANSACTION TESTS
+// ============================================================================
+
+#[test]
+fn test_meta_tx_typehash_and_selectors() {
+    use stylus_erc20::{APPROVE_SELECTOR, META_TX_TYPEHASH, TRANSFER_SELECTOR};
+    
+    assert_eq!(
+        META_TX_TYPEHASH,
+        alloy_primitives::keccak256("MetaTx(address from,bytes data,uint256 nonce,uint256 deadline)")
+    );
+    assert_eq!(TRANSFER_SELECTOR, alloy_primitives::keccak256("transfer(address,uint256)")[..4]);
+    assert_eq!(APPROVE_SELECTOR, alloy_primitives::keccak256("approve(address,uint256)")[..4]);
+}
+
+/// ABI-encoded `selector(target, amount)` call data
+fn meta_call(selector: [u8; 4], target: Address, amount: u64) -> alloy_primitives::Bytes {
+    [&selector[..], &(target, U256::from(amount)).abi_encode()[..]].concat().into()
+}
+
+fn meta_tx_hash(from: Address, call_data: &alloy_primitives::Bytes, nonce: U256, deadline: U256) -> FixedBytes<32> {
+    use stylus_erc20::META_TX_TYPEHASH;
+    
+    alloy_primitives::keccak256(
+        (META_TX_TYPEHASH, from, alloy_primitives::keccak256(call_data), nonce, deadline).abi_encode(),
+    )
+}
+
+#[test]
+fn test_meta_tx_relayed_transfer() {
+    use stylus_erc20::{APPROVE_SELECTOR, TRANSFER_SELECTOR};
+    let (vm, mut token) = deploy();
+    let deadline = U256::from(2_000u64);
+    
+    // The relayer addr(9) submits the owner's signed transfer; the tokens move from the owner
+    let call = meta_call(TRANSFER_SELECTOR, addr(2), 40);
+    let (v, r, s) = mock_signature(&vm, &token, meta_tx_hash(addr(1), &call, U256::ZERO, deadline), addr(1));
+    vm.set_sender(addr(9));
+    ok(token.execute_meta_tx(addr(1), call, U256::ZERO, deadline, v, r, s));
+    
+    assert_eq!(ok(token.balance_of(addr(1))), U256::from(SUPPLY - 40));
+    assert_eq!(ok(token.balance_of(addr(2))), U256::from(40u64));
+    assert_eq!(ok(token.balance_of(addr(9))), U256::ZERO);
+    assert_eq!(ok(token.nonces(addr(1))), U256::from(1u64));
+    
+    let approve = meta_call(APPROVE_SELECTOR, addr(3), 5);
+    let (v, r, s) = mock_signature(&vm, &token, meta_tx_hash(addr(1), &approve, U256::from(1u64), deadline), addr(1));
+    ok(token.execute_meta_tx(addr(1), approve, U256::from(1u64), deadline, v, r, s));
+    assert_eq!(ok(token.allowance(addr(1), addr(3))), U256::from(5u64));
+}
+
+#[test]
+fn test_meta_tx_rejects_replayed_nonce() {
+    use stylus_erc20::TRANSFER_SELECTOR;
+    let (vm, mut token) = deploy();
+    let deadline = U256::from(2_000u64);
+    let call = meta_call(TRANSFER_SELECTOR, addr(2), 40);
+    let (v, r, s) = mock_signature(&vm, &token, meta_tx_hash(addr(1), &call, U256::ZERO, deadline), addr(1));
+    
+    vm.set_sender(addr(9));
+    ok(token.execute_meta_tx(addr(1), call.clone(), U256::ZERO, deadline, v, r, s));
+    
+    match token.execute_meta_tx(addr(1), call, U256::ZERO, deadline, v, r, s) {
+        Err(ERC20Error::InvalidNonce(e)) => {
+            assert_eq!(e.expected, U256::from(1u64));
+            assert_eq!(e.provided, U256::ZERO);
+        }
+        _ => panic!("expected InvalidNonce"),
+    }
+}
+
+#[test]
+fn test_meta_tx_rejects_expired_signature() {
+    use stylus_erc20::TRANSFER_SELECTOR;
+    let (vm, mut token) = deploy();
+    let deadline = U256::from(999u64);
+    let call = meta_call(TRANSFER_SELECTOR, addr(2), 1);
+    let (v, r, s) = mock_signature(&vm, &token, meta_tx_hash(addr(1), &call, U256::ZERO, deadline), addr(1));
+    
+    vm.set_sender(addr(9));
+    assert!(matches!(
+        token.execute_meta_tx(addr(1), call, U256::ZERO, deadline, v, r, s),
+        Err(ERC20Error::MetaTxExpired(_))
+    ));
+}
+
+#[test]
+fn test_meta_tx_rejects_forged_signer() {
+    use stylus_erc20::TRANSFER_SELECTOR;
+    let (vm, mut token) = deploy();
+    let deadline = U256::from(2_000u64);
+    let call = meta_call(TRANSFER_SELECTOR, addr(2), 1);
+    // The signature recovers to addr(9), not to `from`
+    let (v, r, s) = mock_signature(&vm, &token, meta_tx_hash(addr(1), &call, U256::ZERO, deadline), addr(9));
+    
+    vm.set_sender(addr(9));
+    match token.execute_meta_tx(addr(1), call, U256::ZERO, deadline, v, r, s) {
+        Err(ERC20Error::InvalidMetaTxSigner(e)) => {
+            assert_eq!(e.signer, addr(9));
+            assert_eq!(e.from, addr(1));
+        }
+        _ => panic!("expected InvalidMetaTxSigner"),
+    }
+    assert_eq!(ok(token.nonces(addr(1))), U256::ZERO);
+}
+
+// ============================================================================
+// BURNED REASON EVENT TESTS
+// ============================================================================
+
+#[test]
+fn test_burned_reason_self_burn_vs_burn_from() {
+    use stylus_erc20::{Burned, BURN_REASON_BURN_FROM, BURN_REASON_SELF};
+    let (vm, mut token) = deploy_with_supply(100);
+    ok(token.approve(addr(2), U256::from(30u64)));
+    
+    ok(token.burn(U256::from(10u64)));
+    vm.set_sender(addr(2));
+    ok(token.burn_from(addr(1), U256::from(30u64)));
+    
+    let burned = events::<Burned>(&vm);
+    assert_eq!(burned.len(), 2);
+    assert_eq!((burned[0].from, burned[0].amount, burned[0].reason, burned[0].operator), (addr(1), U256::from(10u64), BURN_REASON_SELF, addr(1)));
+    assert_eq!((burned[1].from, burned[1].amount, burned[1].reason, burned[1].operator), (addr(1), U256::from(30u64), BURN_REASON_BURN_FROM, addr(2)));
+    assert_eq!(ok(token.total_burned()), U256::from(40u64));
+    assert_eq!(ok(token.total_supply()), U256::from(60u64));
+}
+
+#[test]
+fn test_burned_reason_dead_address_transfer() {
+    use stylus_erc20::{Burned, BURN_REASON_DEAD_ADDRESS, DEAD_ADDRESS};
+    let (vm, mut token) = deploy_with_supply(100);
+    ok(token.set_burn_on_dead_transfer(true));
+    
+    ok(token.transfer(DEAD_ADDRESS, U256::from(25u64)));
+    
+    let burned = events::<Burned>(&vm);
+    assert_eq!(burned.len(), 1);
+    assert_eq!(burned[0].reason, BURN_REASON_DEAD_ADDRESS);
+    assert_eq!(burned[0].amount, U256::from(25u64));
+    assert_eq!(ok(token.total_burned()), U256::from(25u64));
+}
+
+#[test]
+fn test_failed_burn_reverts_with_insufficient_balance() {
+    let (vm, mut token) = deploy_with_supply(100);
+    
+    vm.set_sender(addr(2));
+    assert!(matches!(token.burn(U256::from(1u64)), Err(ERC20Error::InsufficientBalance(_))));
+}
+
+// ============================================================================
+// INITIALIZE METADATA BOUNDS TESTS
+// ============================================================================
+
+fn init(decimals: u8, initial_supply: U256) -> Result<(), ERC20Error> {
+    let (_vm, mut token) = deploy_uninitialized();
+    token.initialize("Test Token".into(), "TST".into(), decimals, initial_supply, addr(1))
+}
+
+#[test]
+fn test_initialize_decimals_bounds() {
+    match init(0, U256::ZERO) {
+        Err(ERC20Error::InvalidMetadata(e)) => assert_eq!(e.decimals, 0),
+        _ => panic!("expected InvalidMetadata"),
+    }
+    assert!(matches!(init(37, U256::ZERO), Err(ERC20Error::InvalidMetadata(_))));
+    assert!(init(18, U256::ZERO).is_ok());
+    assert!(init(36, U256::ZERO).is_ok());
+}
+
+#[test]
+fn test_initialize_accepts_reasonable_supply() {
+    // 1 billion tokens with 18 decimals
+    let supply = U256::from(1_000_000_000u64) * U256::from(10u64).pow(U256::from(18u64));
+    assert!(init(18, supply).is_ok());
+    
+    // Exactly the bound is still accepted
+    let max = U256::from(stylus_erc20::MAX_INITIAL_SUPPLY_WHOLE_TOKENS) * U256::from(1_000_000u64);
+    assert!(init(6, max).is_ok());
+}
+
+#[test]
+fn test_initialize_rejects_double_scaled_supply() {
+    // A deploy script scaling 1 billion tokens by 10^18 twice
+    let scale = U256::from(10u64).pow(U256::from(18u64));
+    let supply = U256::from(1_000_000_000u64) * scale * scale;
+    match init(18, supply) {
+        Err(ERC20Error::InitialSupplyTooLarge(e)) => {
+            assert_eq!(e.initial_supply, supply);
+            assert_eq!(e.max_supply, U256::from(stylus_erc20::MAX_INITIAL_SUPPLY_WHOLE_TOKENS) * scale);
+        }
+        _ => panic!("expected InitialSupplyTooLarge"),
+    }
+    assert!(matches!(init(18, U256::MAX), Err(ERC20Error::InitialSupplyTooLarge(_))));
+}
+
+#[test]
+fn test_initialize_with_allocations_bounds_the_sum() {
+    let (_vm, mut token) = deploy_uninitialized();
+    let half = U256::from(stylus_erc20::MAX_INITIAL_SUPPLY_WHOLE_TOKENS) * U256::from(1_000_000u64) / U256::from(2u64);
+    
+    let result = token.initialize_with_allocations(
+        "Test Token".into(),
+        "TST".into(),
+        6,
+        vec![addr(2), addr(3)],
+        vec![half, half + U256::from(1u64)],
+        addr(1),
+    );
+    assert!(matches!(result, Err(ERC20Error::InitialSupplyTooLarge(_))));
+}
+
+// ============================================================================
+// PENDING CHANGES AGGREGATE TESTS
+// ============================================================================
+
+#[test]
+fn test_pending_changes_nothing_pending() {
+    let (_vm, token) = deploy();
+    assert_eq!(ok(token.pending_changes()), (Address::ZERO, U256::ZERO, Address::ZERO, false));
+}
+
+#[test]
+fn test_pending_changes_guardian_and_supply_cap_apply_immediately() {
+    let (_vm, mut token) = deploy();
+    ok(token.set_guardian(addr(5)));
+    ok(token.set_supply_cap(U256::from(SUPPLY + 1_000)));
+    
+    // Neither change is two-step, so nothing is reported as pending
+    assert_eq!(ok(token.pending_changes()), (Address::ZERO, U256::ZERO, Address::ZERO, false));
+}
+
+#[test]
+fn test_pending_changes_reflects_ownership_transfer() {
+    let (_vm, mut token) = deploy();
+    ok(token.initiate_ownership_transfer(addr(7)));
+    
+    assert_eq!(ok(token.pending_changes()), (addr(7), U256::from(1_000u64 + 172_800), Address::ZERO, false));
+    
+    // Cancelling clears the aggregate again
+    ok(token.cancel_ownership_transfer());
+    assert_eq!(ok(token.pending_changes()), (Address::ZERO, U256::ZERO, Address::ZERO, false));
+}
+
+#[test]
+fn test_pending_changes_cleared_after_accept() {
+    let (vm, mut token) = deploy();
+    ok(token.initiate_ownership_transfer(addr(7)));
+    
+    vm.set_block_timestamp(1_000 + 172_800);
+    vm.set_sender(addr(7));
+    ok(token.accept_ownership());
+    
+    assert_eq!(ok(token.pending_changes()), (Address::ZERO, U256::ZERO, Address::ZERO, false));
+}
+